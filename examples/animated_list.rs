@@ -0,0 +1,30 @@
+use noxkit::prelude::*;
+
+// New items are added at the top and the ones below slide down to make room, instead of
+// jumping. Each row is keyed by its item, so its position survives the rebuild
+fn main() -> Result<(), AppError> {
+    let items = create_signal(vec![1, 2, 3]);
+
+    let app = App::with_render_fn(move || {
+        let mut rows: Vec<Box<dyn View>> = Vec::new();
+        let add = items.clone();
+        rows.push(Box::new(Button::new("Add to top", move || {
+            add.update(|items| {
+                let next = items.iter().max().copied().unwrap_or(0) + 1;
+                items.insert(0, next);
+            });
+        })));
+        let remove = items.clone();
+        rows.push(Box::new(Button::new("Remove last", move || {
+            remove.update(|items| {
+                items.pop();
+            });
+        })));
+        for item in items.get() {
+            let card = Button::new(format!("Item {}", item), || {}).color([0.3, 0.3, 0.35, 1.0]);
+            rows.push(Box::new(Animated::new(card).key(item.to_string())));
+        }
+        Box::new(Column::new(rows))
+    });
+    app.run()
+}
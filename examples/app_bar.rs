@@ -0,0 +1,22 @@
+use noxkit::prelude::*;
+
+fn main() -> Result<(), AppError> {
+    let app_view = view! {
+        Column {
+            padding: EdgeInsets::all(0.0),
+            AppBar("Inbox: 1,024 unread messages from everyone you have ever met") {
+                leading: Button("☰", || println!("Menu")),
+                action: Button("Search", || println!("Search")),
+                action: Button("Archive", || println!("Archive")),
+                action: Button("More", || println!("More")),
+            },
+            Column {
+                Text("Resize the window to see the title truncate."),
+                Button("Compose", || println!("Compose"))
+            }
+        }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
@@ -0,0 +1,16 @@
+use noxkit::prelude::*;
+
+fn main() -> Result<(), AppError> {
+    let app_view = view! {
+        Column {
+            Text("Resize the window: the player stays 16:9, with bars where it doesn't fill."),
+            AspectRatio(16.0 / 9.0) {
+                color: [0.05, 0.05, 0.05, 1.0],
+                RoundedRect([0.25, 0.32, 0.71, 1.0], 12.0)
+            }
+        }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
@@ -0,0 +1,22 @@
+use noxkit::prelude::*;
+
+fn main() -> Result<(), AppError> {
+    let app_view = view! {
+        Column {
+            padding: EdgeInsets::all(0.0),
+            AppBar("Inbox") {
+                leading: Button("☰", || println!("Menu")),
+                action: Badge::new(Button("Mail", || println!("Mail"))).count(5),
+                action: Badge::new(Button("Alerts", || println!("Alerts"))).count(128),
+                action: Badge::new(Button("Chat", || println!("Chat"))),
+            },
+            Column {
+                Text("Mail shows a count, Alerts caps at 99+ and Chat shows a dot."),
+                Badge::new(Button("Hidden badge", || println!("Nothing new"))).count(3).visible(false)
+            }
+        }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
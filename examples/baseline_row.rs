@@ -0,0 +1,22 @@
+use noxkit::prelude::*;
+use taffy::prelude::AlignItems;
+
+// A large reading and its small unit sit on one baseline, like on a speedometer. The
+// centered row below shows where they would end up otherwise
+fn main() -> Result<(), AppError> {
+    let reading = |align_items| -> Box<dyn View> {
+        Box::new(Row::new(vec![
+            Box::new(Text::new("128").size(72.0)),
+            Box::new(Text::new("km/h").size(18.0).color([0.7, 0.7, 0.7, 1.0])),
+        ])
+        .align_items(align_items))
+    };
+
+    let app = App::new(Box::new(Column::new(vec![
+        Box::new(Text::new("Baseline")),
+        reading(AlignItems::Baseline),
+        Box::new(Text::new("Centered")),
+        reading(AlignItems::Center),
+    ])));
+    app.run()
+}
@@ -0,0 +1,55 @@
+use noxkit::prelude::*;
+use noxkit::layout::{LayoutContext, LayoutError};
+use noxkit::render::RenderContext;
+use noxkit::view::Geometry;
+
+/// A "recording" light that blinks on the same clock as text carets.
+struct BlinkingDot {
+    node_id: Option<taffy::prelude::NodeId>,
+}
+
+impl BlinkingDot {
+    fn new() -> Self {
+        Self { node_id: None }
+    }
+}
+
+impl View for BlinkingDot {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<taffy::prelude::NodeId, LayoutError> {
+        use taffy::prelude::*;
+        let node = ctx.taffy.new_leaf(Style {
+            size: Size { width: length(12.0), height: length(12.0) },
+            ..Default::default()
+        })?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        let color = if ctx.blink_phase() { [0.9, 0.2, 0.2, 1.0] } else { [0.3, 0.1, 0.1, 1.0] };
+        ctx.render_queue.push_circle(my_geo, color);
+    }
+
+    fn render<'rp>(&'rp self, _: &'rp RenderContext, _: &mut wgpu::RenderPass<'rp>, _: Geometry) {}
+    fn handle_event(&mut self, _: &Event, _: &LayoutContext, _: Geometry) {}
+}
+
+fn main() -> Result<(), AppError> {
+    let app_view = view! {
+        Column {
+            Row {
+                BlinkingDot::new(),
+                Text("Recording notes")
+            },
+            Text("Title"),
+            TextInput("", |_| {}).placeholder("Meeting notes"),
+            Text("Notes"),
+            TextInput("", |_| {}).multiline(4),
+            Text("The caret and the light blink together, and the app only redraws when they flip.")
+        }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
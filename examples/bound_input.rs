@@ -0,0 +1,20 @@
+use noxkit::prelude::*;
+
+// The field and the buttons share one signal: typing writes to it, and the buttons set it,
+// which the field picks up on the next frame
+fn main() -> Result<(), AppError> {
+    let name = create_signal("Ada Lovelace".to_string());
+
+    let clear = name.clone();
+    let fill = name.clone();
+    let app = App::new(Box::new(view! {
+        Column {
+            TextInput::bind(name.clone()).placeholder("Your name"),
+            Row {
+                Button("Clear", move || clear.set(String::new())),
+                Button("Fill in", move || fill.set("Grace Hopper".to_string()))
+            }
+        }
+    }));
+    app.run()
+}
@@ -0,0 +1,22 @@
+use noxkit::prelude::*;
+
+// The settings screen built in plain Rust rather than with `view!`: sections are added
+// in a loop and the sign-out button only when signed in
+fn main() -> Result<(), AppError> {
+    let signed_in = true;
+    let sections = [("Display", ["Brightness", "Night mode"]), ("Sound", ["Volume", "Alerts"])];
+
+    let mut settings = Column::default().child(Text::new("Settings").relative_size(1.5));
+    for (title, options) in sections {
+        settings = settings
+            .child(Text::new(title).color([0.6, 0.6, 0.6, 1.0]))
+            .child(Row::default().children(options.map(Chip::new)));
+    }
+    if signed_in {
+        let commands = Commands::new();
+        settings = settings.child(Button::new("Sign out", move || commands.exit()));
+    }
+
+    let app = App::new(Box::new(settings));
+    app.run()
+}
@@ -0,0 +1,24 @@
+use noxkit::prelude::*;
+
+// Hover and press each button: every color gets its own lighter and darker shade
+fn main() -> Result<(), AppError> {
+    let red = Color::rgba(0.8, 0.2, 0.2, 1.0);
+    let app_view = view! {
+        Column {
+            Row {
+                Button("Default", || println!("Default")),
+                Button("Delete", || println!("Delete")) { color: red.into() },
+                Button("Confirm", || println!("Confirm")) { color: [0.18, 0.6, 0.3, 1.0] }
+            },
+            Text("Derived shades of the red base color:"),
+            Row {
+                RoundedRect(red.darken(0.1).into(), 6.0),
+                RoundedRect(red.into(), 6.0),
+                RoundedRect(red.lighten(0.1).into(), 6.0)
+            }
+        }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
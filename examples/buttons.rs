@@ -0,0 +1,19 @@
+use noxkit::prelude::*;
+
+fn main() -> Result<(), AppError> {
+    let app_view = view! {
+        Column {
+            Text("Button gallery") { size: 24.0 },
+            Button("Pill", || println!("Pill")) { radius: 100.0 },
+            Button("Rounded", || println!("Rounded")),
+            Button("Square", || println!("Square")) { radius: 0.0 },
+            Button("Compact", || println!("Compact")) {
+                radius: 12.0,
+                padding: EdgeInsets::symmetric(8.0, 4.0),
+            }
+        }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
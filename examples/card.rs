@@ -0,0 +1,78 @@
+use noxkit::prelude::*;
+use noxkit::layout::{LayoutContext, LayoutError};
+use noxkit::render::RenderContext;
+use noxkit::view::Geometry;
+
+/// A user-defined component: a title above its children.
+struct Card {
+    column: Column,
+}
+
+impl Card {
+    /// `view!` builds `Card("Title") { ... }` as `Card::new("Title")` plus a `child` call per child.
+    fn new(title: &str) -> Self {
+        Self { column: Column::default().child(Text(title)) }
+    }
+
+    fn child(mut self, child: impl IntoViews) -> Self {
+        self.column = self.column.child(child);
+        self
+    }
+}
+
+impl View for Card {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<taffy::prelude::NodeId, LayoutError> {
+        self.column.layout(ctx)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        self.column.prepare(ctx, layout_ctx, geometry);
+    }
+
+    fn collect_text_areas<'a>(&'a self, layout_ctx: &LayoutContext, geometry: Geometry, areas: &mut Vec<glyphon::TextArea<'a>>) {
+        self.column.collect_text_areas(layout_ctx, geometry, areas);
+    }
+
+    fn render<'rp>(&'rp self, ctx: &'rp RenderContext, render_pass: &mut wgpu::RenderPass<'rp>, geometry: Geometry) {
+        self.column.render(ctx, render_pass, geometry);
+    }
+
+    fn handle_event(&mut self, event: &Event, layout_ctx: &LayoutContext, geometry: Geometry) {
+        self.column.handle_event(event, layout_ctx, geometry);
+    }
+}
+
+/// A component function: props first, children last.
+fn section(label: &str, children: Vec<Box<dyn View>>) -> impl View + use<> {
+    let mut items: Vec<Box<dyn View>> = vec![Box::new(Text(label))];
+    items.extend(children);
+    Column::new(items)
+}
+
+/// Two sibling buttons, spliced into whichever container uses them.
+fn actions() -> Fragment {
+    view! {
+        Fragment {
+            Button("Save", || println!("Save")),
+            Button("Cancel", || println!("Cancel"))
+        }
+    }
+}
+
+fn main() -> Result<(), AppError> {
+    let app_view = view! {
+        Column {
+            Card("Profile") {
+                Text("Ada Lovelace"),
+                Button("Edit", || println!("Edit profile"))
+            },
+            section("Settings") {
+                Button("Reset", || println!("Reset settings"))
+            },
+            actions()
+        }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
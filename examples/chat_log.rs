@@ -0,0 +1,25 @@
+use noxkit::prelude::*;
+
+fn main() -> Result<(), AppError> {
+    let messages = create_signal(vec!["Welcome to the chat".to_string()]);
+
+    let app = App::with_render_fn(move || {
+        // Newest first, so the latest message sits at the bottom of the reversed column
+        let log: Vec<Box<dyn View>> = messages.get().iter().rev()
+            .map(|message| Box::new(Text::new(message.clone())) as Box<dyn View>)
+            .collect();
+        let messages = messages.clone();
+        Box::new(view! {
+            Column {
+                ScrollView::new(vec![Box::new(Column::new(log).reverse(true))]),
+                Button("Send", move || {
+                    messages.update(|log| {
+                        let n = log.len();
+                        log.push(format!("Message {}", n));
+                    });
+                })
+            }
+        })
+    });
+    app.run()
+}
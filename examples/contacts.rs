@@ -0,0 +1,45 @@
+use noxkit::prelude::*;
+
+/// A generated stand-in for a profile photo: a diagonal gradient between two colors.
+fn photo(from: [u8; 3], to: [u8; 3]) -> (u32, u32, Vec<u8>) {
+    let (width, height) = (96, 64);
+    let mut rgba = Vec::with_capacity((width * height * 4) as usize);
+    for y in 0..height {
+        for x in 0..width {
+            let t = (x + y) as f32 / (width + height - 2) as f32;
+            for channel in 0..3 {
+                rgba.push((from[channel] as f32 + (to[channel] as f32 - from[channel] as f32) * t) as u8);
+            }
+            rgba.push(255);
+        }
+    }
+    (width, height, rgba)
+}
+
+fn contact(name: &str, initials: &str, photo: Option<(u32, u32, Vec<u8>)>) -> Row {
+    let avatar = match photo {
+        Some((width, height, rgba)) => Avatar(initials).image(width, height, rgba),
+        None => Avatar(initials),
+    };
+    view! {
+        Row {
+            avatar,
+            Text(name)
+        }
+    }
+}
+
+fn main() -> Result<(), AppError> {
+    let app_view = view! {
+        Column {
+            Text("Contacts").size(24.0),
+            contact("Ada Lovelace", "AL", Some(photo([236, 72, 153], [99, 102, 241]))),
+            contact("Grace Hopper", "GH", None),
+            contact("Alan Turing", "AT", Some(photo([16, 185, 129], [59, 130, 246]))),
+            contact("John Doe", "JD", None)
+        }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
@@ -1,20 +1,21 @@
 use noxkit::prelude::*;
 
-fn main() {
+fn main() -> Result<(), AppError> {
     let state = create_signal(0);
 
-    let my_view = view! {
+    // Rebuilt whenever `state` changes, so the label always shows the current count
+    let app = App::with_render_fn(move || Box::new(view! {
         Column {
-            Text("Counter:"),
-            Text(state.get().to_string()),
+            let count = state.get();
+            let state = state.clone();
+            let label = format!("Counter: {} ({})", count, if count % 2 == 0 { "even" } else { "odd" });
+            Text(label),
             Button("Increment", move || {
                 state.update(|n| *n += 1);
             }),
             RoundedRect([0.0, 0.5, 1.0, 1.0], 20.0),
             Circle([1.0, 0.8, 0.0, 1.0])
         }
-    };
-
-    let app = App::new(Box::new(my_view));
-    app.run();
+    }));
+    app.run()
 }
@@ -0,0 +1,45 @@
+use noxkit::prelude::*;
+
+fn content() -> Column {
+    view! {
+        Column {
+            Text("Dashboard") { size: 24.0 },
+            Text("Resize the window past 1024px to switch layouts.")
+        }
+    }
+}
+
+fn nav_buttons() -> Fragment {
+    view! {
+        Fragment {
+            Button("Home", || println!("Home")),
+            Button("Reports", || println!("Reports")),
+            Button("Settings", || println!("Settings"))
+        }
+    }
+}
+
+fn main() -> Result<(), AppError> {
+    // Desktop: a sidebar next to the content
+    let desktop = view! {
+        Row {
+            Column { nav_buttons() },
+            content()
+        }
+    };
+    // Mobile: the content above a bottom navigation bar
+    let mobile = view! {
+        Column {
+            padding: EdgeInsets::all(0.0),
+            content(),
+            Row { nav_buttons() }
+        }
+    };
+
+    let app_view = view! {
+        Responsive(mobile) { desktop: desktop }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
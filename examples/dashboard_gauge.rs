@@ -0,0 +1,21 @@
+use noxkit::prelude::*;
+
+fn main() -> Result<(), AppError> {
+    let app_view = view! {
+        Column {
+            Text("Circular progress fills clockwise from 12 o'clock"),
+            Row {
+                CircularProgress(0.0),
+                CircularProgress(0.5),
+                CircularProgress(1.0)
+            },
+            Row {
+                CircularProgress(0.25) { size: 96.0, thickness: 10.0 },
+                CircularProgress(0.75) { size: 96.0, thickness: 10.0, color: [0.2, 0.7, 0.4, 1.0], track_color: [0.2, 0.7, 0.4, 0.2] }
+            }
+        }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
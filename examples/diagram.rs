@@ -0,0 +1,27 @@
+use noxkit::prelude::*;
+
+fn main() -> Result<(), AppError> {
+    let diagram = view! {
+        Column {
+            Row {
+                Button("Input", || println!("Input")),
+                RoundedRect([0.25, 0.32, 0.71, 1.0], 8.0),
+                Button("Process", || println!("Process")),
+                RoundedRect([0.25, 0.32, 0.71, 1.0], 8.0),
+                Button("Output", || println!("Output"))
+            },
+            Text("Drag to pan, scroll or pinch to zoom around the pointer.")
+        }
+    };
+
+    let app_view = view! {
+        Column {
+            padding: EdgeInsets::all(0.0),
+            Text("Pipeline"),
+            Viewport(diagram)
+        }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
@@ -0,0 +1,34 @@
+use noxkit::prelude::*;
+use std::io::Read;
+use std::path::Path;
+
+/// Reads the width and height from a PNG's IHDR chunk.
+fn png_size(path: &Path) -> Option<(u32, u32)> {
+    let mut header = [0u8; 24];
+    std::fs::File::open(path).ok()?.read_exact(&mut header).ok()?;
+    if header[..8] != *b"\x89PNG\r\n\x1a\n" || header[12..16] != *b"IHDR" {
+        return None;
+    }
+    let width = u32::from_be_bytes(header[16..20].try_into().unwrap());
+    let height = u32::from_be_bytes(header[20..24].try_into().unwrap());
+    Some((width, height))
+}
+
+fn main() -> Result<(), AppError> {
+    let app_view = view! {
+        Column {
+            Text("Drop PNG files on the box"),
+            DropZone(RoundedRect([0.12, 0.12, 0.16, 1.0], 12.0), |paths| {
+                for path in paths {
+                    match png_size(&path) {
+                        Some((width, height)) => println!("{}: {}x{} PNG", path.display(), width, height),
+                        None => println!("{}: not a PNG", path.display()),
+                    }
+                }
+            }).radius(12.0)
+        }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
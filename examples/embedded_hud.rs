@@ -0,0 +1,119 @@
+use std::sync::Arc;
+use noxkit::prelude::*;
+use noxkit::Renderer;
+use winit::application::ApplicationHandler;
+use winit::event::{ElementState, MouseButton, WindowEvent};
+use winit::event_loop::{ActiveEventLoop, EventLoop};
+use winit::window::{Window, WindowId};
+
+/// A wgpu app that owns its window and draws its own scene, with a NoxKit HUD on top.
+struct Host {
+    state: Option<HostState>,
+    started: std::time::Instant,
+}
+
+struct HostState {
+    window: Arc<Window>,
+    surface: wgpu::Surface<'static>,
+    config: wgpu::SurfaceConfiguration,
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    hud: Renderer,
+    cursor: (f32, f32),
+}
+
+fn hud() -> Box<dyn View> {
+    Box::new(view! {
+        Column {
+            Text("Health 100 · Ammo 30"),
+            Button("Pause", || println!("Paused")),
+        }
+    })
+}
+
+impl ApplicationHandler for Host {
+    fn resumed(&mut self, event_loop: &ActiveEventLoop) {
+        if self.state.is_some() {
+            return;
+        }
+        let window = Arc::new(event_loop.create_window(Window::default_attributes()).unwrap());
+        let instance = wgpu::Instance::default();
+        let surface = instance.create_surface(window.clone()).unwrap();
+        let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+            compatible_surface: Some(&surface),
+            ..Default::default()
+        }))
+        .unwrap();
+        let (device, queue) = pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor::default())).unwrap();
+        let size = window.inner_size();
+        let config = surface.get_default_config(&adapter, size.width.max(1), size.height.max(1)).unwrap();
+        surface.configure(&device, &config);
+
+        // Draw over the scene in the same frame instead of clearing it
+        let hud = Renderer::new(&device, &queue, config.format, hud(), config.width, config.height)
+            .unwrap()
+            .with_clear_color(None);
+        self.state = Some(HostState { window, surface, config, device, queue, hud, cursor: (0.0, 0.0) });
+    }
+
+    fn window_event(&mut self, event_loop: &ActiveEventLoop, _: WindowId, event: WindowEvent) {
+        let Some(state) = &mut self.state else { return };
+        match event {
+            WindowEvent::CloseRequested => event_loop.exit(),
+            WindowEvent::Resized(size) => {
+                state.config.width = size.width.max(1);
+                state.config.height = size.height.max(1);
+                state.surface.configure(&state.device, &state.config);
+                state.hud.resize(state.config.width, state.config.height).unwrap();
+            }
+            WindowEvent::CursorMoved { position, .. } => {
+                state.cursor = (position.x as f32, position.y as f32);
+                let (x, y) = state.cursor;
+                state.hud.handle_event(&Event::MouseMove { x, y });
+            }
+            WindowEvent::CursorLeft { .. } => state.hud.pointer_left(),
+            WindowEvent::MouseInput { state: button_state, button: MouseButton::Left, .. } => {
+                let (x, y) = state.cursor;
+                match button_state {
+                    ElementState::Pressed => state.hud.handle_event(&Event::MouseDown { x, y }),
+                    ElementState::Released => {
+                        state.hud.handle_event(&Event::MouseUp { x, y });
+                        state.hud.handle_event(&Event::MouseClick { x, y });
+                    }
+                }
+            }
+            WindowEvent::RedrawRequested => {
+                let frame = state.surface.get_current_texture().unwrap();
+                let target = frame.texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+                // The "scene": a slowly pulsing clear color
+                let t = self.started.elapsed().as_secs_f64();
+                let mut encoder = state.device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+                encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                    label: Some("Scene"),
+                    color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                        view: &target,
+                        resolve_target: None,
+                        ops: wgpu::Operations {
+                            load: wgpu::LoadOp::Clear(wgpu::Color { r: 0.1, g: 0.2 + 0.1 * t.sin().abs(), b: 0.3, a: 1.0 }),
+                            store: wgpu::StoreOp::Store,
+                        },
+                    })],
+                    ..Default::default()
+                });
+                state.queue.submit(Some(encoder.finish()));
+
+                state.hud.render(&target).unwrap();
+                frame.present();
+                state.window.request_redraw();
+            }
+            _ => (),
+        }
+    }
+}
+
+fn main() {
+    let event_loop = EventLoop::new().unwrap();
+    let mut host = Host { state: None, started: std::time::Instant::now() };
+    event_loop.run_app(&mut host).unwrap();
+}
@@ -0,0 +1,33 @@
+use noxkit::prelude::*;
+use std::path::{Path, PathBuf};
+
+/// Directories first, then files, each sorted by name. Directories are read lazily.
+fn entries(dir: &Path) -> Vec<TreeNode<PathBuf>> {
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(dir)
+        .map(|entries| entries.filter_map(|entry| entry.ok().map(|entry| entry.path())).collect())
+        .unwrap_or_default();
+    paths.sort_by_key(|path| (!path.is_dir(), path.file_name().map(|name| name.to_os_string())));
+    paths.into_iter()
+        .map(|path| if path.is_dir() { TreeNode::lazy(path) } else { TreeNode::new(path) })
+        .collect()
+}
+
+fn main() -> Result<(), AppError> {
+    let root = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+    let tree = TreeView::new(entries(&root), |path: &PathBuf| {
+        let name = path.file_name().map_or_else(|| path.display().to_string(), |name| name.to_string_lossy().into_owned());
+        if path.is_dir() { format!("📁 {}", name) } else { name }
+    })
+    .load_children(|path| entries(path))
+    .on_select(|path| println!("Selected {}", path.display()));
+
+    let app_view = view! {
+        Column {
+            Text(root.display().to_string()).size(18.0),
+            ScrollView::new(vec![Box::new(tree)])
+        }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
@@ -0,0 +1,30 @@
+use noxkit::prelude::*;
+use std::path::PathBuf;
+
+// Click a file, then: ctrl-click (cmd on macOS) toggles files, shift-click selects a range.
+// With the list focused, up/down move the outlined row, shift+up/down extend the selection,
+// space toggles the outlined row and ctrl+A selects everything.
+fn main() -> Result<(), AppError> {
+    let dir = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+    let mut files: Vec<PathBuf> = std::fs::read_dir(&dir)
+        .map(|entries| entries.filter_map(|entry| entry.ok().map(|entry| entry.path())).collect())
+        .unwrap_or_default();
+    files.sort();
+
+    let list = ListView::new(files, |path: &PathBuf| {
+        let name = path.file_name().map_or_else(|| path.display().to_string(), |name| name.to_string_lossy().into_owned());
+        if path.is_dir() { format!("📁 {}", name) } else { name }
+    })
+    .multi_select(true)
+    .on_selection_change(|selected| println!("Selected rows {:?}", selected));
+
+    let app_view = view! {
+        Column {
+            Text(dir.display().to_string()).size(18.0),
+            ScrollView::new(vec![Box::new(list)])
+        }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
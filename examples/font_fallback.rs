@@ -0,0 +1,26 @@
+use noxkit::prelude::*;
+
+// Characters the UI font lacks come from the first listed family that has them, then from
+// the platform's own fallbacks. Families that aren't installed are skipped.
+fn main() -> Result<(), AppError> {
+    let app_view = view! {
+        Column {
+            Text("Mixed scripts in one line") { size: 18.0 },
+            Text("Hello 👋 世界 · こんにちは · 안녕하세요 🎉"),
+            Text("Latin, emoji and CJK: café ☕ 漢字 かな 🚀"),
+            Text("Symbols: ✓ ✗ → ∞ ♥ ★")
+        }
+    };
+
+    let app = App::new(Box::new(app_view)).with_font_fallbacks([
+        // Emoji
+        "Noto Color Emoji",
+        "Apple Color Emoji",
+        "Segoe UI Emoji",
+        // CJK
+        "Noto Sans CJK SC",
+        "PingFang SC",
+        "Microsoft YaHei",
+    ]);
+    app.run()
+}
@@ -0,0 +1,23 @@
+use noxkit::prelude::*;
+
+// Run with `--features gamepad`. The d-pad moves focus around the grid and A presses the
+// focused button, which names itself in the window title; the mouse works too
+fn main() -> Result<(), AppError> {
+    let commands = Commands::new();
+
+    let rows: Vec<Box<dyn View>> = (1..=3)
+        .map(|row| {
+            let tiles: Vec<Box<dyn View>> = (1..=4)
+                .map(|column| {
+                    let label = format!("Tile {}-{}", row, column);
+                    let commands = commands.clone();
+                    Box::new(Button::new(label.clone(), move || commands.set_title(format!("Picked {}", label)))) as Box<dyn View>
+                })
+                .collect();
+            Box::new(Row::new(tiles)) as Box<dyn View>
+        })
+        .collect();
+
+    let app = App::new(Box::new(Column::new(rows)));
+    app.run()
+}
@@ -0,0 +1,82 @@
+use noxkit::prelude::*;
+use noxkit::Renderer;
+use noxkit::layout::{LayoutContext, LayoutError};
+use noxkit::render::RenderContext;
+use noxkit::view::Geometry;
+
+const FRAMES: usize = 200;
+
+/// Shows different characters at a different size every frame, so nearly every glyph it
+/// draws is new to the glyph atlas.
+struct Churn {
+    frame: usize,
+    view: Text,
+}
+
+impl Churn {
+    fn text(frame: usize) -> (String, f32) {
+        // 12 lines of 64 characters from the Latin, Greek and Cyrillic blocks
+        let text = (0..12).map(|line| {
+            let start = 0xC0 + ((frame * 12 + line) * 64) % 0x3C0;
+            (start..start + 64).filter_map(|c| char::from_u32(c as u32)).collect::<String>()
+        });
+        (text.collect::<Vec<_>>().join("\n"), 10.0 + (frame % 24) as f32)
+    }
+}
+
+impl View for Churn {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<taffy::prelude::NodeId, LayoutError> {
+        self.view.layout(ctx)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        self.view.prepare(ctx, layout_ctx, geometry);
+    }
+
+    fn collect_text_areas<'a>(&'a self, layout_ctx: &LayoutContext, geometry: Geometry, areas: &mut Vec<glyphon::TextArea<'a>>) {
+        self.view.collect_text_areas(layout_ctx, geometry, areas);
+    }
+
+    fn render<'rp>(&'rp self, _: &'rp RenderContext, _: &mut wgpu::RenderPass<'rp>, _: Geometry) {}
+
+    fn handle_event(&mut self, _: &Event, _: &LayoutContext, _: Geometry) {}
+
+    fn on_update(&mut self) {
+        self.frame += 1;
+        let (text, size) = Self::text(self.frame);
+        self.view = Text::new(text).size(size);
+        request_layout();
+    }
+}
+
+// Draws a few hundred thousand glyphs offscreen, most of them distinct. The glyph atlas
+// grows and evicts glyphs older frames used; `atlas_overflows` stays at zero unless one
+// frame needs more glyphs than the largest atlas holds.
+fn main() {
+    let instance = wgpu::Instance::default();
+    let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions::default()))
+        .expect("no graphics adapter");
+    let (device, queue) = pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor::default())).unwrap();
+    let (width, height) = (1280, 800);
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("Glyph stress target"),
+        size: wgpu::Extent3d { width, height, depth_or_array_layers: 1 },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba8UnormSrgb,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+        view_formats: &[],
+    });
+    let target = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+    let churn = Churn { frame: 0, view: Text::new("") };
+    let mut renderer = Renderer::new(&device, &queue, texture.format(), Box::new(churn), width, height).unwrap();
+    let mut glyphs = 0;
+    for _ in 0..FRAMES {
+        renderer.render(&target).unwrap();
+        glyphs += renderer.render_context().stats.glyphs;
+    }
+    let stats = &renderer.render_context().stats;
+    println!("Drew {glyphs} glyphs in {FRAMES} frames; atlas overflows: {}", stats.atlas_overflows);
+}
@@ -0,0 +1,36 @@
+use noxkit::prelude::*;
+use std::time::{Duration, Instant};
+use winit::event_loop::EventLoop;
+
+// The host owns the event loop and its own frame timing; NoxKit handles whatever window
+// events arrived since the last tick. (Hosts happy to hand over the loop can instead call
+// `event_loop.run_app(&mut app)`, since `App` is a winit `ApplicationHandler`.)
+fn main() -> Result<(), AppError> {
+    let app_view = view! {
+        Column {
+            Text("The host loop keeps ticking while NoxKit handles input"),
+            Button("Hello", || println!("Clicked between host ticks"))
+        }
+    };
+    let mut app = App::new(Box::new(app_view));
+
+    let mut event_loop = EventLoop::new()?;
+    let started = Instant::now();
+    let mut ticks = 0u64;
+    let mut last_report = Instant::now();
+    loop {
+        // Don't wait for events: the host has its own work to do every iteration
+        if !app.pump_events(&mut event_loop, Some(Duration::ZERO))? {
+            break;
+        }
+
+        // The host's own per-frame work, e.g. a simulation step
+        ticks += 1;
+        if last_report.elapsed() >= Duration::from_secs(1) {
+            println!("{} host ticks in {:.0?}", ticks, started.elapsed());
+            last_report = Instant::now();
+        }
+        std::thread::sleep(Duration::from_millis(16));
+    }
+    Ok(())
+}
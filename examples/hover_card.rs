@@ -0,0 +1,101 @@
+use noxkit::prelude::*;
+use noxkit::layout::{LayoutContext, LayoutError};
+use noxkit::render::RenderContext;
+use noxkit::view::Geometry;
+use std::time::{Duration, Instant};
+
+const EXPAND: Duration = Duration::from_millis(200);
+
+/// A card that lifts and grows while the pointer is over it, driven by enter/leave events.
+struct HoverCard {
+    column: Column,
+    hovered: bool,
+    /// When the last enter/leave happened and how far expanded the card was then.
+    changed: (Instant, f32),
+    node_id: Option<taffy::prelude::NodeId>,
+}
+
+impl Default for HoverCard {
+    fn default() -> Self {
+        Self {
+            column: Column::default(),
+            hovered: false,
+            changed: (Instant::now(), 0.0),
+            node_id: None,
+        }
+    }
+}
+
+impl HoverCard {
+    fn child(mut self, child: impl IntoViews) -> Self {
+        self.column = self.column.child(child);
+        self
+    }
+
+    /// 0 when resting, 1 when fully expanded.
+    fn expansion(&self) -> f32 {
+        let (since, from) = self.changed;
+        let step = since.elapsed().as_secs_f32() / EXPAND.as_secs_f32();
+        if self.hovered { (from + step).min(1.0) } else { (from - step).max(0.0) }
+    }
+
+    fn set_hovered(&mut self, hovered: bool) {
+        self.changed = (Instant::now(), self.expansion());
+        self.hovered = hovered;
+    }
+}
+
+impl View for HoverCard {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<taffy::prelude::NodeId, LayoutError> {
+        let node = self.column.layout(ctx)?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        if let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) {
+            let t = self.expansion();
+            let grow = 8.0 * t;
+            let card = Geometry { x: my_geo.x - grow, y: my_geo.y - grow, width: my_geo.width + grow * 2.0, height: my_geo.height + grow * 2.0 };
+            let shadow = Geometry { y: card.y + 4.0 + 8.0 * t, ..card };
+            ctx.render_queue.push_rounded_rect(shadow, [0.0, 0.0, 0.0, 0.2 + 0.3 * t], 16.0);
+            ctx.render_queue.push_rounded_rect(card, [0.16 + 0.06 * t, 0.17 + 0.06 * t, 0.22 + 0.08 * t, 1.0], 16.0);
+            if t > 0.0 && t < 1.0 {
+                ctx.request_redraw();
+            }
+        }
+        self.column.prepare(ctx, layout_ctx, geometry);
+    }
+
+    fn collect_text_areas<'a>(&'a self, layout_ctx: &LayoutContext, geometry: Geometry, areas: &mut Vec<glyphon::TextArea<'a>>) {
+        self.column.collect_text_areas(layout_ctx, geometry, areas);
+    }
+
+    fn render<'rp>(&'rp self, ctx: &'rp RenderContext, render_pass: &mut wgpu::RenderPass<'rp>, geometry: Geometry) {
+        self.column.render(ctx, render_pass, geometry);
+    }
+
+    fn handle_event(&mut self, event: &Event, layout_ctx: &LayoutContext, geometry: Geometry) {
+        match event {
+            Event::MouseEnter { node, .. } if Some(*node) == self.node_id => self.set_hovered(true),
+            Event::MouseLeave { node, .. } if Some(*node) == self.node_id => self.set_hovered(false),
+            _ => {}
+        }
+        self.column.handle_event(event, layout_ctx, geometry);
+    }
+}
+
+fn main() -> Result<(), AppError> {
+    let app_view = view! {
+        Column {
+            padding: EdgeInsets::all(48.0),
+            HoverCard {
+                Text("Hover me").size(24.0),
+                Text("The card lifts when the pointer enters and settles when it leaves.")
+            }
+        }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
@@ -0,0 +1,29 @@
+use noxkit::prelude::*;
+
+// A status bar at the bottom explains whatever the pointer is over, going by the widgets'
+// debug names
+fn main() -> Result<(), AppError> {
+    let count = create_signal(0);
+    let hovered = create_signal(None::<String>);
+
+    let help = hovered.clone();
+    let app = App::with_render_fn(move || {
+        let status = match help.get() {
+            Some(name) => format!("Hovering: {}", name),
+            None => "Point at something for help".to_string(),
+        };
+        let (increment, reset) = (count.clone(), count.clone());
+        Box::new(view! {
+            Column {
+                Text(format!("Count: {}", count.get())),
+                Row {
+                    Button("+", move || increment.update(|n| *n += 1)).debug_name("Increment button"),
+                    Button("Reset", move || reset.set(0)).debug_name("Reset button")
+                },
+                Text(status).color([0.7, 0.7, 0.7, 1.0])
+            }
+        })
+    })
+    .with_hovered_widget(hovered);
+    app.run()
+}
@@ -1,11 +1,14 @@
 use noxkit::prelude::*;
 
-fn main() {
+fn main() -> Result<(), AppError> {
     let count = create_signal(0);
 
     let app_view = view! {
         Column {
-            Rect([0.2, 0.2, 0.2, 1.0]), // Background
+            Rect([0.2, 0.2, 0.2, 1.0]) {
+                on_click: |x, y| println!("Rect clicked at ({}, {})", x, y),
+                on_hover_change: |hovered| println!("Rect hovered: {}", hovered),
+            },
             Button("Click Me", move || {
                 count.update(|n| *n += 1);
                 println!("Count is now: {}", count.get());
@@ -14,5 +17,5 @@ fn main() {
     };
 
     let app = App::new(Box::new(app_view));
-    app.run();
+    app.run()
 }
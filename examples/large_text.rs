@@ -0,0 +1,20 @@
+use noxkit::prelude::*;
+
+// Run with `-- 24` (or any size) to scale every default text size from a different base
+fn main() -> Result<(), AppError> {
+    let base = std::env::args().nth(1).and_then(|arg| arg.parse().ok()).unwrap_or(20.0);
+
+    let app_view = view! {
+        Column {
+            AppBar("Readable text"),
+            Text("Body text follows the base font size"),
+            Text("Captions can stay relative to it") { relative_size: 0.75 },
+            Text("Or opt out with an explicit size") { size: 12.0 },
+            Button("Buttons scale too", || println!("Clicked")),
+            TextInput("Inputs as well", |_| {})
+        }
+    };
+
+    let app = App::new(Box::new(app_view)).with_base_font_size(base);
+    app.run()
+}
@@ -0,0 +1,63 @@
+use noxkit::prelude::*;
+use noxkit::layout::{LayoutContext, LayoutError};
+use noxkit::render::RenderContext;
+use noxkit::view::Geometry;
+
+/// Wraps the app and prints the resolved layout when Ctrl+D is pressed.
+struct Inspector {
+    content: Box<dyn View>,
+}
+
+impl Inspector {
+    fn new(content: impl View + 'static) -> Self {
+        Self { content: Box::new(content) }
+    }
+}
+
+impl View for Inspector {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<taffy::prelude::NodeId, LayoutError> {
+        self.content.layout(ctx)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        self.content.prepare(ctx, layout_ctx, geometry);
+    }
+
+    fn collect_text_areas<'a>(&'a self, layout_ctx: &LayoutContext, geometry: Geometry, areas: &mut Vec<glyphon::TextArea<'a>>) {
+        self.content.collect_text_areas(layout_ctx, geometry, areas);
+    }
+
+    fn render<'rp>(&'rp self, ctx: &'rp RenderContext, render_pass: &mut wgpu::RenderPass<'rp>, geometry: Geometry) {
+        self.content.render(ctx, render_pass, geometry);
+    }
+
+    fn handle_event(&mut self, event: &Event, layout_ctx: &LayoutContext, geometry: Geometry) {
+        if let Event::KeyDown { key: Key::Character('d'), modifiers } = event
+            && modifiers.ctrl
+        {
+            print!("{}", layout_ctx.dump_tree());
+            for (name, geometry) in layout_ctx.named_geometries() {
+                println!("{}: {:?}", name, geometry);
+            }
+        }
+        self.content.handle_event(event, layout_ctx, geometry);
+    }
+}
+
+fn main() -> Result<(), AppError> {
+    let content = view! {
+        Column {
+            debug_name: "page",
+            Text("Press Ctrl+D to print the layout") { debug_name: "hint" },
+            TextInput("", |_| {}) { debug_name: "search" },
+            Row {
+                debug_name: "actions",
+                Button("Cancel", || {}) { debug_name: "cancel" },
+                Button("Submit", || {}) { debug_name: "submit" }
+            }
+        }
+    };
+
+    let app = App::new(Box::new(Inspector::new(content))).with_debug(true);
+    app.run()
+}
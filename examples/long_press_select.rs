@@ -0,0 +1,53 @@
+use noxkit::prelude::*;
+use std::time::Duration;
+
+const PHOTOS: [&str; 5] = ["Beach", "Mountains", "City at night", "Forest trail", "Harbor"];
+
+// Long-pressing a photo enters selection mode, where clicks toggle photos in and out of the
+// selection. The hold time comes from the first argument in milliseconds, e.g.
+// `cargo run --example long_press_select -- 800`
+fn main() -> Result<(), AppError> {
+    let long_press_ms = std::env::args().nth(1).and_then(|ms| ms.parse().ok()).unwrap_or(500);
+    let selection = create_signal(None::<Vec<usize>>);
+
+    let app = App::with_render_fn(move || {
+        let current = selection.get();
+        let header = match &current {
+            Some(selected) => format!("{} selected", selected.len()),
+            None => "Hold a photo to select".to_string(),
+        };
+        let list = Column::default().children(PHOTOS.iter().enumerate().map(|(index, name)| {
+            let selected = current.as_ref().is_some_and(|selected| selected.contains(&index));
+            let label = if selected { format!("[x] {}", name) } else { name.to_string() };
+            let (toggle, select) = (selection.clone(), selection.clone());
+            Interactive::new(Text::new(label))
+                .on_click(move |_, _| {
+                    toggle.update(|selection| {
+                        if let Some(selected) = selection {
+                            match selected.iter().position(|&i| i == index) {
+                                Some(i) => { selected.remove(i); }
+                                None => selected.push(index),
+                            }
+                        }
+                    })
+                })
+                .on_long_press(move |_, _| {
+                    select.update(|selection| {
+                        if selection.is_none() {
+                            *selection = Some(vec![index]);
+                        }
+                    })
+                })
+        }));
+        let done = selection.clone();
+        Box::new(view! {
+            Column {
+                Text(header),
+                list,
+                Button("Done", move || done.set(None))
+            }
+        })
+    })
+    .with_long_press_time(Duration::from_millis(long_press_ms));
+    app.run()
+}
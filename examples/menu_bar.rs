@@ -0,0 +1,24 @@
+use noxkit::prelude::*;
+
+fn main() -> Result<(), AppError> {
+    let commands = Commands::new();
+    let opened = create_signal(0);
+
+    let file = Menu::new("File")
+        .item(MenuItem::new("Open", {
+            let opened = opened.clone();
+            move || opened.update(|n| *n += 1)
+        }))
+        .separator()
+        .item(MenuItem::new("Quit", move || commands.exit()));
+    let help = Menu::new("Help").item(MenuItem::new("About", || show_snackbar("NoxKit menu bar example", std::time::Duration::from_secs(3))));
+
+    let app = App::with_render_fn(move || Box::new(view! {
+        Column {
+            Text(format!("File > Open picked {} times", opened.get())),
+            Text("File > Quit exits the app")
+        }
+    }))
+    .with_menu(vec![file, help]);
+    app.run()
+}
@@ -0,0 +1,28 @@
+use noxkit::prelude::*;
+
+// Shows the monitor the window is on, updating as it is dragged to another one, and uses
+// larger text on 4K monitors
+fn main() -> Result<(), AppError> {
+    let monitor = create_signal(None::<MonitorInfo>);
+
+    let current = monitor.clone();
+    let app = App::with_render_fn(move || {
+        let Some(info) = current.get() else {
+            return Box::new(view! { Text("Monitor unknown") });
+        };
+        let scale = if info.size.0 >= 3840 { 1.5 } else { 1.0 };
+        let refresh = match info.refresh_rate {
+            Some(hz) => format!("{:.0} Hz", hz),
+            None => "unknown refresh rate".to_string(),
+        };
+        Box::new(view! {
+            Column {
+                Text(info.name.clone().unwrap_or_else(|| "Unnamed monitor".to_string())).relative_size(1.25 * scale),
+                Text(format!("{} x {} at {}", info.size.0, info.size.1, refresh)).relative_size(scale),
+                Text(format!("Scale factor {}", info.scale_factor)).relative_size(scale)
+            }
+        })
+    })
+    .with_monitor(monitor);
+    app.run()
+}
@@ -0,0 +1,28 @@
+use noxkit::prelude::*;
+
+// Each screen is its own tree; navigating replaces the app's root with the other one
+fn home() -> Box<dyn View> {
+    let commands = Commands::new();
+    Box::new(view! {
+        Column {
+            Text("Home") { size: 24.0 },
+            Button("Open settings", move || commands.set_root(settings()))
+        }
+    })
+}
+
+fn settings() -> Box<dyn View> {
+    let commands = Commands::new();
+    Box::new(view! {
+        Column {
+            Text("Settings") { size: 24.0 },
+            Text("Nothing to configure yet"),
+            Button("Back to home", move || commands.set_root(home()))
+        }
+    })
+}
+
+fn main() -> Result<(), AppError> {
+    let app = App::new(home());
+    app.run()
+}
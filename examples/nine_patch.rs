@@ -0,0 +1,53 @@
+use noxkit::prelude::*;
+
+const FRAME_SIZE: u32 = 32;
+const BORDER: u32 = 10;
+
+/// A 32x32 panel skin: a gold frame with studded corners around a dark, translucent center.
+fn panel_skin() -> Vec<u8> {
+    let mut rgba = Vec::with_capacity((FRAME_SIZE * FRAME_SIZE * 4) as usize);
+    for y in 0..FRAME_SIZE {
+        for x in 0..FRAME_SIZE {
+            let edge = x.min(y).min(FRAME_SIZE - 1 - x).min(FRAME_SIZE - 1 - y);
+            let middle = BORDER..FRAME_SIZE - BORDER;
+            let corner = !middle.contains(&x) && !middle.contains(&y);
+            let (cx, cy) = (x.min(FRAME_SIZE - 1 - x) as i32 - 4, y.min(FRAME_SIZE - 1 - y) as i32 - 4);
+            let pixel = if corner && cx * cx + cy * cy <= 4 {
+                [250, 240, 200, 255] // stud
+            } else if edge < 2 {
+                [120, 80, 20, 255]
+            } else if edge < BORDER - 2 {
+                [200, 150, 50, 255]
+            } else if edge < BORDER {
+                [120, 80, 20, 255]
+            } else {
+                [20, 24, 40, 220]
+            };
+            rgba.extend(pixel);
+        }
+    }
+    rgba
+}
+
+fn main() -> Result<(), AppError> {
+    let insets = EdgeInsets::all(BORDER as f32);
+
+    let app_view = view! {
+        Column {
+            NinePatch(FRAME_SIZE, FRAME_SIZE, panel_skin(), insets) {
+                padding: EdgeInsets::all(24.0),
+                Text("Quest Log"),
+                Text("The frame is one 32x32 image: its corners stay crisp while the edges and center stretch."),
+                Text("Resize the window to see it follow.")
+            },
+            Row {
+                NinePatch(FRAME_SIZE, FRAME_SIZE, panel_skin(), insets).child(Text("160 x 60")).size(160.0, 60.0),
+                // Smaller than two corners: they shrink to fit instead of overlapping
+                NinePatch(FRAME_SIZE, FRAME_SIZE, panel_skin(), insets).size(12.0, 12.0)
+            }
+        }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
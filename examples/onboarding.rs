@@ -0,0 +1,28 @@
+use noxkit::prelude::*;
+
+fn main() -> Result<(), AppError> {
+    let pages = ScrollController::new();
+    let next = pages.clone();
+    let back = pages.clone();
+
+    let app_view = view! {
+        Column {
+            padding: EdgeInsets::all(0.0),
+            ScrollView::new(vec![
+                Box::new(view! { Column { Text("Welcome to NoxKit").size(28.0), Text("Scroll sideways or use the buttons below.") } }),
+                Box::new(view! { Column { Text("Reactive state").size(28.0), Text("Signals redraw only what changed.") } }),
+                Box::new(view! { Column { Text("Ready?").size(28.0), Text("Build something nice.") } }),
+            ])
+            .horizontal()
+            .snap(true)
+            .controller(pages),
+            Row {
+                Button("Back", move || back.scroll_to_item(back.current_item().saturating_sub(1), true)),
+                Button("Next", move || next.scroll_to_item(next.current_item() + 1, true))
+            }
+        }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
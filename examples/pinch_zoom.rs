@@ -0,0 +1,102 @@
+use noxkit::prelude::*;
+use noxkit::layout::{LayoutContext, LayoutError};
+use noxkit::render::{Clip, RenderContext};
+use noxkit::view::Geometry;
+use taffy::prelude::*;
+use std::collections::HashMap;
+
+/// A checkerboard "image" that zooms with trackpad pinches and pans with one finger.
+struct ZoomableImage {
+    scale: f32,
+    /// Top-left of the image relative to the view, in pixels.
+    offset: (f32, f32),
+    touches: HashMap<u64, (f32, f32)>,
+    node_id: Option<NodeId>,
+}
+
+impl ZoomableImage {
+    fn new() -> Self {
+        Self { scale: 1.0, offset: (0.0, 0.0), touches: HashMap::new(), node_id: None }
+    }
+
+    /// Zooms by `factor` while keeping the image point under `center` in place.
+    fn zoom(&mut self, factor: f32, center: (f32, f32), my_geo: Geometry) {
+        let new_scale = (self.scale * factor).clamp(0.25, 8.0);
+        let applied = new_scale / self.scale;
+        let (cx, cy) = (center.0 - my_geo.x, center.1 - my_geo.y);
+        self.offset = (cx - (cx - self.offset.0) * applied, cy - (cy - self.offset.1) * applied);
+        self.scale = new_scale;
+    }
+}
+
+impl View for ZoomableImage {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<NodeId, LayoutError> {
+        let node = ctx.taffy.new_leaf(Style { flex_grow: 1.0, ..Default::default() })?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        let previous_clip = ctx.render_queue.set_clip(Some(Clip { geometry: my_geo, radius: 0.0 }));
+        let cell = 40.0 * self.scale;
+        for row in 0..12 {
+            for col in 0..12 {
+                let color = if (row + col) % 2 == 0 { [0.9, 0.9, 0.9, 1.0] } else { [0.25, 0.32, 0.71, 1.0] };
+                ctx.render_queue.push_rect(
+                    Geometry {
+                        x: my_geo.x + self.offset.0 + col as f32 * cell,
+                        y: my_geo.y + self.offset.1 + row as f32 * cell,
+                        width: cell,
+                        height: cell,
+                    },
+                    color,
+                );
+            }
+        }
+        ctx.render_queue.set_clip(previous_clip);
+    }
+
+    fn render<'rp>(&'rp self, _ctx: &'rp RenderContext, _render_pass: &mut wgpu::RenderPass<'rp>, _geometry: Geometry) {}
+
+    fn handle_event(&mut self, event: &Event, layout_ctx: &LayoutContext, geometry: Geometry) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        match event {
+            Event::Pinch { scale, center } if my_geo.contains(center.0, center.1) => self.zoom(*scale, *center, my_geo),
+            Event::Touch { id, phase, x, y } => match phase {
+                TouchPhase::Started if my_geo.contains(*x, *y) => {
+                    self.touches.insert(*id, (*x, *y));
+                }
+                TouchPhase::Moved => {
+                    // Pan with a single finger; a second finger only anchors the gesture
+                    if self.touches.len() == 1
+                        && let Some(last) = self.touches.get_mut(id)
+                    {
+                        self.offset.0 += x - last.0;
+                        self.offset.1 += y - last.1;
+                        *last = (*x, *y);
+                    } else if let Some(last) = self.touches.get_mut(id) {
+                        *last = (*x, *y);
+                    }
+                }
+                TouchPhase::Ended | TouchPhase::Cancelled => {
+                    self.touches.remove(id);
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+}
+
+fn main() -> Result<(), AppError> {
+    let app_view = view! {
+        Column {
+            Text("Pinch on the trackpad to zoom around the cursor; drag with a finger to pan."),
+            ZoomableImage::new()
+        }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
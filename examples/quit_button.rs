@@ -0,0 +1,23 @@
+use noxkit::prelude::*;
+
+fn main() -> Result<(), AppError> {
+    let commands = Commands::new();
+    let clicks = create_signal(0);
+
+    let app_view = view! {
+        Column {
+            Text("Handlers can act on the window through Commands"),
+            Button("Rename window", {
+                let commands = commands.clone();
+                move || {
+                    clicks.update(|n| *n += 1);
+                    commands.set_title(format!("Renamed {} times", clicks.get()));
+                }
+            }),
+            Button("Quit", move || commands.exit())
+        }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
@@ -0,0 +1,70 @@
+use noxkit::prelude::*;
+use noxkit::layout::{LayoutContext, LayoutError};
+use noxkit::render::RenderContext;
+use noxkit::view::Geometry;
+use std::f32::consts::TAU;
+use taffy::prelude::*;
+
+/// A radar chart: one spoke per value (0..=1), the values joined into a filled polygon.
+struct RadarChart {
+    values: Vec<f32>,
+    node_id: Option<NodeId>,
+}
+
+impl RadarChart {
+    fn new(values: Vec<f32>) -> Self {
+        Self { values, node_id: None }
+    }
+
+    fn point(center: [f32; 2], radius: f32, index: usize, count: usize) -> [f32; 2] {
+        let angle = index as f32 / count as f32 * TAU - TAU / 4.0;
+        [center[0] + radius * angle.cos(), center[1] + radius * angle.sin()]
+    }
+}
+
+impl View for RadarChart {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<NodeId, LayoutError> {
+        let node = ctx.taffy.new_leaf(Style { size: Size { width: length(320.0), height: length(320.0) }, ..Default::default() })?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        let center = [my_geo.x + my_geo.width / 2.0, my_geo.y + my_geo.height / 2.0];
+        let radius = my_geo.width.min(my_geo.height) / 2.0;
+        let count = self.values.len();
+
+        // Background rings, outermost first so the inner ones draw on top
+        for (ring, color) in [(1.0, [0.18, 0.18, 0.22, 1.0]), (0.66, [0.14, 0.14, 0.18, 1.0]), (0.33, [0.18, 0.18, 0.22, 1.0])] {
+            let points: Vec<[f32; 2]> = (0..count).map(|i| Self::point(center, radius * ring, i, count)).collect();
+            ctx.render_queue.push_polygon(&points, color);
+        }
+
+        // The values may form a concave shape, so draw one triangle per segment from the center
+        for i in 0..count {
+            let next = (i + 1) % count;
+            ctx.render_queue.push_triangle(
+                center,
+                Self::point(center, radius * self.values[i], i, count),
+                Self::point(center, radius * self.values[next], next, count),
+                [0.25, 0.32, 0.71, 0.8],
+            );
+        }
+    }
+
+    fn render<'rp>(&'rp self, _ctx: &'rp RenderContext, _render_pass: &mut wgpu::RenderPass<'rp>, _geometry: Geometry) {}
+    fn handle_event(&mut self, _event: &Event, _layout_ctx: &LayoutContext, _geometry: Geometry) {}
+}
+
+fn main() -> Result<(), AppError> {
+    let app_view = view! {
+        Column {
+            Text("Skills"),
+            RadarChart::new(vec![0.9, 0.6, 0.75, 0.4, 0.85, 0.5])
+        }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
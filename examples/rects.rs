@@ -1,6 +1,6 @@
 use noxkit::prelude::*;
 
-fn main() {
+fn main() -> Result<(), AppError> {
     let app_view = view! {
         Column {
             Rect([1.0, 0.0, 0.0, 1.0]), // Red
@@ -10,5 +10,5 @@ fn main() {
     };
 
     let app = App::new(Box::new(app_view));
-    app.run();
+    app.run()
 }
@@ -0,0 +1,46 @@
+use noxkit::prelude::*;
+use noxkit::layout::{LayoutContext, LayoutError};
+use noxkit::render::RenderContext;
+use noxkit::view::Geometry;
+use std::time::{Duration, Instant};
+
+/// Prints a line for every frame drawn, to show when the app is actually redrawing.
+/// Nothing is printed while idle; showing a snackbar prints a burst while it slides in,
+/// nothing while it sits on screen, then another burst as it fades out.
+struct FrameLog {
+    started: Instant,
+    frames: u64,
+}
+
+impl FrameLog {
+    fn new() -> Self {
+        Self { started: Instant::now(), frames: 0 }
+    }
+}
+
+impl View for FrameLog {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<taffy::prelude::NodeId, LayoutError> {
+        Ok(ctx.taffy.new_leaf(taffy::prelude::Style::default())?)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, _: &LayoutContext, _: Geometry) {
+        self.frames += 1;
+        println!("frame {} at {:.2}s ({:.1} fps)", self.frames, self.started.elapsed().as_secs_f32(), ctx.stats.fps);
+    }
+
+    fn render<'rp>(&'rp self, _: &'rp RenderContext, _: &mut wgpu::RenderPass<'rp>, _: Geometry) {}
+    fn handle_event(&mut self, _: &Event, _: &LayoutContext, _: Geometry) {}
+}
+
+fn main() -> Result<(), AppError> {
+    let app_view = view! {
+        Column {
+            Text("Watch the terminal: frames are only drawn while something changes."),
+            Button("Show snackbar", || show_snackbar("Saved", Duration::from_secs(2))),
+            FrameLog::new()
+        }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
@@ -0,0 +1,61 @@
+use noxkit::prelude::*;
+
+fn main() -> Result<(), AppError> {
+    let navigator = Navigator::new("home");
+
+    let router = Router::new(navigator.clone())
+        .route("home", {
+            let navigator = navigator.clone();
+            move || {
+                let (to_inbox, to_settings) = (navigator.clone(), navigator.clone());
+                Box::new(view! {
+                    Column {
+                        Text("Home") { size: 24.0 },
+                        Button("Inbox", move || to_inbox.navigate("inbox")),
+                        Button("Settings", move || to_settings.navigate("settings"))
+                    }
+                })
+            }
+        })
+        .route("inbox", {
+            let navigator = navigator.clone();
+            move || {
+                let (to_message, back) = (navigator.clone(), navigator.clone());
+                Box::new(view! {
+                    Column {
+                        Text("Inbox") { size: 24.0 },
+                        Button("Open message", move || to_message.navigate("message")),
+                        Button("Back", move || { back.back(); })
+                    }
+                })
+            }
+        })
+        .route("message", {
+            let navigator = navigator.clone();
+            move || {
+                let back = navigator.clone();
+                Box::new(view! {
+                    Column {
+                        Text("Hello from the message screen"),
+                        Button("Back", move || { back.back(); })
+                    }
+                })
+            }
+        })
+        .route("settings", {
+            let navigator = navigator.clone();
+            move || {
+                let back = navigator.clone();
+                Box::new(view! {
+                    Column {
+                        Text("Settings") { size: 24.0 },
+                        Text(format!("{} screens deep", navigator.depth())),
+                        Button("Back", move || { back.back(); })
+                    }
+                })
+            }
+        });
+
+    let app = App::new(Box::new(router));
+    app.run()
+}
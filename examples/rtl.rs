@@ -0,0 +1,39 @@
+use noxkit::prelude::*;
+use noxkit::layout::text_direction;
+
+fn main() -> Result<(), AppError> {
+    let app_view = view! {
+        Column {
+            padding: EdgeInsets::all(0.0),
+            AppBar("صندوق الوارد") {
+                leading: Button("☰", || println!("Menu")),
+                action: Button("بحث", || println!("Search")),
+            },
+            Column {
+                // Mixed runs: the Latin name and number keep their order inside the Arabic text
+                Text("مرحبا بكم في NoxKit 2.0 — واجهة من اليمين إلى اليسار"),
+                Text("שלום! This line starts in Hebrew and ends in English."),
+                Row {
+                    Button("حفظ", || println!("Save")),
+                    Button("إلغاء", || println!("Cancel"))
+                },
+                Row {
+                    text_direction: TextDirection::Ltr,
+                    Text("This row always runs left to right:"),
+                    Button("1", || {}),
+                    Button("2", || {})
+                },
+                Button("Switch direction", || {
+                    let next = match text_direction() {
+                        TextDirection::Ltr => TextDirection::Rtl,
+                        TextDirection::Rtl => TextDirection::Ltr,
+                    };
+                    set_text_direction(next);
+                })
+            }
+        }
+    };
+
+    let app = App::new(Box::new(app_view)).with_text_direction(TextDirection::Rtl);
+    app.run()
+}
@@ -0,0 +1,33 @@
+use noxkit::prelude::*;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Screen {
+    Welcome,
+    Login,
+    Done,
+}
+
+fn main() -> Result<(), AppError> {
+    // The view tree is built once, so the screen is picked up front: `cargo run --example screens login`
+    let screen = match std::env::args().nth(1).as_deref() {
+        Some("login") => Screen::Login,
+        Some("done") => Screen::Done,
+        _ => Screen::Welcome,
+    };
+
+    let app_view = view! {
+        Column {
+            match screen {
+                Screen::Welcome => Button("Get started", || println!("Run with `login` to see the next screen")),
+                Screen::Login => Column {
+                    Text("Log in"),
+                    Button("Continue", || println!("Run with `done` to see the last screen"))
+                },
+                Screen::Done => Text("All set!") { size: 24.0 },
+            }
+        }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
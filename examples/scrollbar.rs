@@ -0,0 +1,21 @@
+use noxkit::prelude::*;
+
+fn main() -> Result<(), AppError> {
+    // Long enough that the thumb bottoms out at its minimum size
+    let rows: Vec<Box<dyn View>> = (1..=2000)
+        .map(|n| Box::new(Text::new(format!("Row {}", n))) as Box<dyn View>)
+        .collect();
+    let short: Vec<Box<dyn View>> = (1..=40)
+        .map(|n| Box::new(Text::new(format!("Item {}", n))) as Box<dyn View>)
+        .collect();
+
+    let app_view = view! {
+        Row {
+            ScrollView::new(rows).scrollbar(ScrollbarMode::Always),
+            ScrollView::new(short)
+        }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
@@ -0,0 +1,47 @@
+use std::rc::Rc;
+use noxkit::prelude::*;
+
+fn main() -> Result<(), AppError> {
+    let dark_mode = create_signal(true);
+    let notifications = create_signal(false);
+    let autosave = create_signal(true);
+
+    let path = std::env::temp_dir().join("noxkit_settings.json");
+    let store = Rc::new(
+        StateStore::new()
+            .signal("dark_mode", &dark_mode)
+            .signal("notifications", &notifications)
+            .signal("autosave", &autosave),
+    );
+    // The first run has no file yet and keeps the defaults
+    if let Err(err) = store.load_from(&path) {
+        println!("Using default settings ({})", err);
+    }
+
+    let toggle = |label: &str, signal: &Signal<bool>| {
+        let signal = signal.clone();
+        let store = store.clone();
+        let path = path.clone();
+        Chip::new(label).selected(signal.get()).on_select(move |selected| {
+            signal.set(selected);
+            if let Err(err) = store.save_to(&path) {
+                eprintln!("Failed to save settings: {}", err);
+            }
+        })
+    };
+
+    let app_view = view! {
+        Column {
+            Text("Settings"),
+            Row {
+                toggle("Dark mode", &dark_mode),
+                toggle("Notifications", &notifications),
+                toggle("Autosave", &autosave)
+            },
+            Text(format!("Saved to {}; restart to see the toggles restored.", path.display()))
+        }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
@@ -0,0 +1,35 @@
+use noxkit::prelude::*;
+
+fn main() -> Result<(), AppError> {
+    let email = Field::new("")
+        .required("Email is required")
+        .email("Enter a valid email address");
+    let password = Field::new("")
+        .required("Password is required")
+        .min_length(8, "Use at least 8 characters");
+    let confirm = {
+        let password = password.clone();
+        Field::new("").validator(move |value| (value != password.value.get()).then(|| "Passwords don't match".to_string()))
+    };
+
+    let (e, p, c) = (email.clone(), password.clone(), confirm.clone());
+    let app_view = view! {
+        Column {
+            Text("Email"),
+            TextInput::for_field(&email).placeholder("you@example.com"),
+            Text("Password"),
+            TextInput::for_field(&password).secure(true),
+            Text("Confirm password"),
+            TextInput::for_field(&confirm).secure(true),
+            Button("Sign up", move || {
+                if validate_all(&[&e, &p, &c]) {
+                    println!("Signed up as {}", e.value.get());
+                }
+            })
+        }
+    };
+
+    // The form stops fitting below this size
+    let app = App::new(Box::new(app_view)).with_min_size(360.0, 480.0);
+    app.run()
+}
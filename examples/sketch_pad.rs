@@ -0,0 +1,69 @@
+use noxkit::prelude::*;
+use noxkit::layout::{LayoutContext, LayoutError};
+use noxkit::render::RenderContext;
+use noxkit::view::Geometry;
+
+const DOT_SIZE: f32 = 10.0;
+
+/// A canvas that drops a dot wherever it's clicked, or a trail of them while dragging.
+/// Dots are kept in local coordinates, so they stay put on the canvas when it moves, e.g.
+/// as the window is resized.
+struct SketchPad {
+    dots: Vec<(f32, f32)>,
+    drawing: bool,
+    node_id: Option<taffy::prelude::NodeId>,
+}
+
+impl SketchPad {
+    fn new() -> Self {
+        Self { dots: Vec::new(), drawing: false, node_id: None }
+    }
+}
+
+impl View for SketchPad {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<taffy::prelude::NodeId, LayoutError> {
+        use taffy::prelude::*;
+        let node = ctx.taffy.new_leaf(Style {
+            size: Size { width: length(480.0), height: length(320.0) },
+            ..Default::default()
+        })?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        ctx.render_queue.push_rounded_rect(my_geo, [0.95, 0.95, 0.92, 1.0], 8.0);
+        for &(x, y) in &self.dots {
+            let dot = Geometry { x: my_geo.x + x - DOT_SIZE / 2.0, y: my_geo.y + y - DOT_SIZE / 2.0, width: DOT_SIZE, height: DOT_SIZE };
+            ctx.render_queue.push_circle(dot, [0.2, 0.3, 0.8, 1.0]);
+        }
+    }
+
+    fn render<'rp>(&'rp self, _: &'rp RenderContext, _: &mut wgpu::RenderPass<'rp>, _: Geometry) {}
+
+    fn handle_event(&mut self, event: &Event, layout_ctx: &LayoutContext, geometry: Geometry) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        match *event {
+            Event::MouseDown { x, y } if my_geo.contains(x, y) => {
+                self.drawing = true;
+                self.dots.push(my_geo.to_local(x, y));
+            }
+            Event::MouseMove { x, y } if self.drawing && my_geo.contains(x, y) => self.dots.push(my_geo.to_local(x, y)),
+            Event::MouseUp { .. } => self.drawing = false,
+            _ => {}
+        }
+    }
+}
+
+fn main() -> Result<(), AppError> {
+    let app_view = view! {
+        Column {
+            Text("Click or drag to draw"),
+            SketchPad::new()
+        }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
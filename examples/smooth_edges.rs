@@ -0,0 +1,69 @@
+use noxkit::prelude::*;
+use noxkit::layout::{LayoutContext, LayoutError};
+use noxkit::render::RenderContext;
+use noxkit::view::Geometry;
+use std::time::Instant;
+
+/// Shapes at fractional positions and sizes, plus a pair drifting a fraction of a pixel per
+/// frame. Edges are anti-aliased from each shape's distance field at one sample per pixel,
+/// so the moving shapes glide instead of stepping from pixel to pixel.
+struct Shapes {
+    started: Instant,
+    node_id: Option<taffy::prelude::NodeId>,
+}
+
+impl Shapes {
+    fn new() -> Self {
+        Self { started: Instant::now(), node_id: None }
+    }
+}
+
+impl View for Shapes {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<taffy::prelude::NodeId, LayoutError> {
+        use taffy::prelude::*;
+        let node = ctx.taffy.new_leaf(Style {
+            size: Size { width: length(420.0), height: length(220.0) },
+            ..Default::default()
+        })?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        let at = |x: f32, y: f32, width: f32, height: f32| Geometry { x: my_geo.x + x, y: my_geo.y + y, width, height };
+
+        // Thin bars a fraction of a pixel apart: each edge gets the coverage it really has
+        for i in 0..8 {
+            let offset = i as f32 * 0.25;
+            ctx.render_queue.push_rect(at(10.0 + i as f32 * 12.0 + offset, 10.0, 1.5, 60.0), [0.9, 0.9, 0.9, 1.0]);
+        }
+        // Small circles and rounded rects, where blurry or jagged edges show first
+        for i in 0..6 {
+            let size = 6.0 + i as f32 * 3.3;
+            ctx.render_queue.push_circle(at(120.0 + i as f32 * 26.0, 10.0, size, size), [0.95, 0.6, 0.2, 1.0]);
+            ctx.render_queue.push_rounded_rect(at(120.0 + i as f32 * 26.0, 45.0, size, size), [0.3, 0.7, 0.95, 1.0], size * 0.3);
+        }
+
+        let t = self.started.elapsed().as_secs_f32();
+        let drift = (t * 0.5).sin() * 0.5 + 0.5;
+        ctx.render_queue.push_circle(at(20.0 + drift * 300.0, 100.0, 48.0, 48.0), [0.9, 0.3, 0.4, 1.0]);
+        ctx.render_queue.push_rounded_rect(at(20.0 + (1.0 - drift) * 300.0, 160.0, 80.0, 40.0), [0.4, 0.85, 0.5, 1.0], 14.0);
+        ctx.request_redraw();
+    }
+
+    fn render<'rp>(&'rp self, _: &'rp RenderContext, _: &mut wgpu::RenderPass<'rp>, _: Geometry) {}
+    fn handle_event(&mut self, _: &Event, _: &LayoutContext, _: Geometry) {}
+}
+
+fn main() -> Result<(), AppError> {
+    let app_view = view! {
+        Column {
+            Text("Anti-aliased shapes without MSAA"),
+            Shapes::new()
+        }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
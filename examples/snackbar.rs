@@ -0,0 +1,30 @@
+use noxkit::prelude::*;
+use std::time::Duration;
+
+fn main() -> Result<(), AppError> {
+    let saves = create_signal(0);
+
+    let app_view = view! {
+        Column {
+            Text("Snackbars"),
+            Button("Save", move || {
+                saves.update(|n| *n += 1);
+                let saves = saves.clone();
+                Snackbar::new(format!("Saved draft #{}", saves.get()), Duration::from_secs(4))
+                    .action("Undo", move || {
+                        saves.update(|n| *n -= 1);
+                        println!("Undone, {} saves left", saves.get());
+                    })
+                    .show();
+            }),
+            Button("Queue three", || {
+                for i in 1..=3 {
+                    show_snackbar(format!("Message {} of 3", i), Duration::from_secs(2));
+                }
+            })
+        }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
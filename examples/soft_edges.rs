@@ -0,0 +1,22 @@
+use noxkit::prelude::*;
+
+fn main() -> Result<(), AppError> {
+    let app_view = view! {
+        Column {
+            Text("Crisp and soft edges"),
+            Row {
+                RoundedRect([0.25, 0.6, 0.95, 1.0], 16.0),
+                RoundedRect([0.25, 0.6, 0.95, 1.0], 16.0) { edge_softness: 12.0 }
+            },
+            // Wide softness blurs a bright shape into a neon glow
+            Row {
+                Circle([0.2, 1.0, 0.6, 1.0]),
+                Circle([0.2, 1.0, 0.6, 1.0]) { edge_softness: 24.0 }
+            },
+            Button("Soft button", || println!("Clicked")) { edge_softness: 4.0 }
+        }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
@@ -0,0 +1,19 @@
+use noxkit::prelude::*;
+use noxkit::Background;
+
+fn main() -> Result<(), AppError> {
+    let app_view = view! {
+        Column {
+            padding: EdgeInsets::all(48.0),
+            Text("NoxKit").size(48.0).shadow([0.0, 2.0], 6.0, [0.0, 0.0, 0.0, 0.5]),
+            Text("Loading your workspace…").color([1.0, 1.0, 1.0, 0.8])
+        }
+    };
+
+    let app = App::new(Box::new(app_view)).with_background(Background::LinearGradient {
+        from: [0.25, 0.08, 0.55, 1.0],
+        to: [0.02, 0.35, 0.45, 1.0],
+        angle: 135.0,
+    });
+    app.run()
+}
@@ -0,0 +1,40 @@
+use noxkit::prelude::*;
+
+fn main() -> Result<(), AppError> {
+    let sidebar = view! {
+        Column {
+            Text("EXPLORER").size(12.0),
+            Text("src/"),
+            Text("  main.rs"),
+            Text("  lib.rs"),
+            Text("Cargo.toml")
+        }
+    };
+    let editor = SplitPane::new(
+        view! {
+            Column {
+                Text("main.rs").size(12.0),
+                Text("fn main() {\n    println!(\"Hello, NoxKit!\");\n}")
+            }
+        },
+        view! {
+            Column {
+                Text("TERMINAL").size(12.0),
+                Text("$ cargo run")
+            }
+        },
+    )
+    .vertical()
+    .ratio(0.7)
+    .min_size(80.0);
+
+    let app_view = view! {
+        Column {
+            padding: EdgeInsets::all(0.0),
+            SplitPane::new(sidebar, editor).ratio(0.25).min_size(160.0)
+        }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
@@ -0,0 +1,63 @@
+use noxkit::prelude::*;
+use noxkit::Renderer;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Counts heap allocations, to see what a frame of unchanged text costs.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const TEXTS: usize = 500;
+const FRAMES: usize = 100;
+
+// Draws 500 labels that never change offscreen and reports allocations and re-shaped texts
+// per frame once the first frames have shaped everything
+fn main() {
+    let instance = wgpu::Instance::default();
+    let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions::default()))
+        .expect("no graphics adapter");
+    let (device, queue) = pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor::default())).unwrap();
+    let (width, height) = (1600, 1000);
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("Static text target"),
+        size: wgpu::Extent3d { width, height, depth_or_array_layers: 1 },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba8UnormSrgb,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+        view_formats: &[],
+    });
+    let target = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+    let labels: Vec<Box<dyn View>> = (0..TEXTS)
+        .map(|i| Box::new(Text::new(format!("Label {i}")).size(10.0)) as Box<dyn View>)
+        .collect();
+    let mut renderer = Renderer::new(&device, &queue, texture.format(), Box::new(Column::new(labels)), width, height).unwrap();
+
+    for _ in 0..3 {
+        renderer.render(&target).unwrap();
+    }
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    for _ in 0..FRAMES {
+        renderer.render(&target).unwrap();
+    }
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - before;
+    let stats = &renderer.render_context().stats;
+    println!("{TEXTS} static texts: {} allocations and {} re-shaped texts per frame", allocations / FRAMES, stats.shaped_texts);
+}
@@ -0,0 +1,21 @@
+use noxkit::prelude::*;
+
+fn main() -> Result<(), AppError> {
+    let app_view = view! {
+        Column {
+            Text("Stats overlay").size(28.0),
+            Text("Press ` to toggle the panel. It's shown by default in debug builds."),
+            Row {
+                RoundedRect([0.2, 0.4, 0.9, 1.0], 12.0),
+                RoundedRect([0.9, 0.4, 0.2, 1.0], 12.0),
+                Circle([0.3, 0.8, 0.4, 1.0])
+            },
+            StatsOverlay::new()
+                .visible(cfg!(debug_assertions))
+                .toggle_key(Key::Character('`'))
+        }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
@@ -0,0 +1,42 @@
+use noxkit::prelude::*;
+
+fn main() -> Result<(), AppError> {
+    let username = Field::new("").required("Username is required");
+    let password = Field::new("").min_length(8, "Use at least 8 characters");
+
+    // Recomputes whenever either field changes, and only once when both change in a batch
+    let submit_enabled = Computed::derive((&username.value, &password.value), |username, password| {
+        !username.trim().is_empty() && password.chars().count() >= 8
+    });
+    submit_enabled.subscribe({
+        let submit_enabled = submit_enabled.clone();
+        move || println!("Submit enabled: {}", submit_enabled.get())
+    });
+
+    let (u, p, enabled) = (username.clone(), password.clone(), submit_enabled.clone());
+    let (demo_u, demo_p) = (username.clone(), password.clone());
+    let app_view = view! {
+        Column {
+            Text("Username"),
+            TextInput::for_field(&username).placeholder("ada"),
+            Text("Password"),
+            TextInput::for_field(&password).secure(true),
+            Button("Fill demo account", move || {
+                batch(|| {
+                    demo_u.set("ada");
+                    demo_p.set("analytical");
+                });
+            }),
+            Button("Log in", move || {
+                if enabled.get() {
+                    println!("Logging in as {}", u.value.get());
+                } else {
+                    p.validate();
+                }
+            })
+        }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
@@ -0,0 +1,29 @@
+use noxkit::prelude::*;
+use noxkit::Renderer;
+
+// Renders the counter view once without a window and writes it to counter.svg
+fn main() {
+    let count = create_signal(3);
+    let label = format!("Counter: {}", count.get());
+    let counter = view! {
+        Column {
+            Text(label),
+            Button("Increment", move || count.update(|n| *n += 1)),
+            RoundedRect([0.0, 0.5, 1.0, 1.0], 20.0),
+            Circle([1.0, 0.8, 0.0, 1.0])
+        }
+    };
+
+    // Any adapter will do, since nothing is drawn on it
+    let instance = wgpu::Instance::default();
+    let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions::default()))
+        .expect("no graphics adapter");
+    let (device, queue) = pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor::default())).unwrap();
+
+    let mut renderer = Renderer::new(&device, &queue, wgpu::TextureFormat::Rgba8UnormSrgb, Box::new(counter), 400, 300)
+        .unwrap()
+        .with_clear_color(Some([0.1, 0.1, 0.12, 1.0]));
+    let svg = renderer.to_svg().unwrap();
+    std::fs::write("counter.svg", &svg).unwrap();
+    println!("Wrote counter.svg ({} bytes)", svg.len());
+}
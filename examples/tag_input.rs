@@ -0,0 +1,79 @@
+use noxkit::prelude::*;
+use noxkit::layout::{LayoutContext, LayoutError};
+use noxkit::render::RenderContext;
+use noxkit::view::Geometry;
+
+/// A row of deletable chips mirroring a list of tags. The row is rebuilt on every layout,
+/// so changing the list just needs a `request_layout()`.
+struct TagList {
+    tags: Signal<Vec<String>>,
+    row: Row,
+}
+
+impl TagList {
+    fn new(tags: Signal<Vec<String>>) -> Self {
+        Self { tags, row: Row::new(Vec::new()) }
+    }
+}
+
+impl View for TagList {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<taffy::prelude::NodeId, LayoutError> {
+        let chips = self.tags.get().into_iter().enumerate().map(|(i, tag)| {
+            let tags = self.tags.clone();
+            Box::new(Chip::new(tag).icon("#").on_delete(move || {
+                tags.update(|tags| { tags.remove(i); });
+                request_layout();
+            })) as Box<dyn View>
+        });
+        self.row = Row::new(chips.collect());
+        self.row.layout(ctx)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        self.row.prepare(ctx, layout_ctx, geometry);
+    }
+
+    fn collect_text_areas<'a>(&'a self, layout_ctx: &LayoutContext, geometry: Geometry, areas: &mut Vec<glyphon::TextArea<'a>>) {
+        self.row.collect_text_areas(layout_ctx, geometry, areas);
+    }
+
+    fn render<'rp>(&'rp self, ctx: &'rp RenderContext, render_pass: &mut wgpu::RenderPass<'rp>, geometry: Geometry) {
+        self.row.render(ctx, render_pass, geometry);
+    }
+
+    fn handle_event(&mut self, event: &Event, layout_ctx: &LayoutContext, geometry: Geometry) {
+        self.row.handle_event(event, layout_ctx, geometry);
+    }
+}
+
+fn main() -> Result<(), AppError> {
+    let tags = create_signal(vec!["rust".to_string(), "gui".to_string(), "wgpu".to_string()]);
+    let draft = create_signal(String::new());
+
+    let (d, t) = (draft.clone(), tags.clone());
+    let app_view = view! {
+        Column {
+            Text("Tags"),
+            TagList::new(tags.clone()),
+            Row {
+                TextInput("", move |value| d.set(value.to_string())).placeholder("New tag"),
+                Button("Add", move || {
+                    let tag = draft.get().trim().to_string();
+                    if !tag.is_empty() && !t.get().contains(&tag) {
+                        t.update(|tags| tags.push(tag));
+                        request_layout();
+                    }
+                })
+            },
+            Text("Filters"),
+            Row {
+                Chip("Unread").selected(true).on_select(|selected| println!("Unread: {}", selected)),
+                Chip("Starred").icon("★").on_select(|selected| println!("Starred: {}", selected)),
+                Chip("Attachments").on_select(|selected| println!("Attachments: {}", selected))
+            }
+        }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
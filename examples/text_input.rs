@@ -0,0 +1,17 @@
+use noxkit::prelude::*;
+
+fn main() -> Result<(), AppError> {
+    let app_view = view! {
+        Column {
+            Text("Name:"),
+            TextInput("", |value| println!("Name: {}", value)).placeholder("Search…"),
+            Text("Password:"),
+            TextInput("", |value| println!("Password length: {}", value.chars().count())).secure(true),
+            Text("Notes (Ctrl+Arrows jump words, Home/End, Up/Down):"),
+            TextInput("fn main() {\n    println!(\"hello\");\n}", |_| {}).multiline(6)
+        }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
@@ -0,0 +1,35 @@
+use noxkit::prelude::*;
+
+// Run with `-- --snap` to start every text block on a whole pixel and compare
+fn main() -> Result<(), AppError> {
+    let snap = std::env::args().any(|arg| arg == "--snap");
+
+    let app_view = view! {
+        Column {
+            Text(if snap { "Snapped to whole pixels" } else { "Unsnapped: run with -- --snap to compare" }) { size: 18.0 },
+            Row {
+                Column {
+                    Text("Advanced shaping") { size: 14.0 },
+                    Text("Office affine: AV Wa To ffi") { size: 10.0 },
+                    Text("Office affine: AV Wa To ffi") { size: 12.0 },
+                    Text("Office affine: AV Wa To ffi") { size: 14.0 }
+                },
+                Column {
+                    Text("Basic shaping") { size: 14.0, shaping: Shaping::Basic },
+                    Text("Office affine: AV Wa To ffi") { size: 10.0, shaping: Shaping::Basic },
+                    Text("Office affine: AV Wa To ffi") { size: 12.0, shaping: Shaping::Basic },
+                    Text("Office affine: AV Wa To ffi") { size: 14.0, shaping: Shaping::Basic }
+                }
+            },
+            // Padding of a third of a pixel puts this text between pixels unless snapped
+            Column {
+                padding: EdgeInsets::all(16.33),
+                Text("Small text at a fractional offset") { size: 11.0 },
+                Text("Small text at a fractional offset") { size: 11.0, letter_spacing: 0.02 }
+            }
+        }
+    };
+
+    let app = App::new(Box::new(app_view)).with_text_snapping(snap);
+    app.run()
+}
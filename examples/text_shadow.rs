@@ -0,0 +1,55 @@
+use noxkit::prelude::*;
+use noxkit::layout::{LayoutContext, LayoutError};
+use noxkit::render::RenderContext;
+use noxkit::view::Geometry;
+
+/// Stands in for a photo: a busy pattern of bright stripes and dots behind its children.
+struct Busy {
+    column: Column,
+    node_id: Option<taffy::prelude::NodeId>,
+}
+
+impl View for Busy {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<taffy::prelude::NodeId, LayoutError> {
+        let node = self.column.layout(ctx)?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        if let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) {
+            let colors = [[1.0, 0.85, 0.2, 1.0], [0.95, 0.95, 0.95, 1.0], [0.3, 0.8, 1.0, 1.0], [1.0, 0.4, 0.6, 1.0]];
+            let stripe = 12.0;
+            for i in 0..(my_geo.width / stripe).ceil() as usize {
+                let x = my_geo.x + i as f32 * stripe;
+                let width = stripe.min(my_geo.x + my_geo.width - x);
+                ctx.render_queue.push_rect(Geometry { x, y: my_geo.y, width, height: my_geo.height }, colors[i % colors.len()]);
+                let y = my_geo.y + (i * 37 % 100) as f32 / 100.0 * my_geo.height;
+                ctx.render_queue.push_circle(Geometry { x, y, width: stripe, height: stripe }, colors[(i + 2) % colors.len()]);
+            }
+        }
+        self.column.prepare(ctx, layout_ctx, geometry);
+    }
+
+    fn collect_text_areas<'a>(&'a self, layout_ctx: &LayoutContext, geometry: Geometry, areas: &mut Vec<glyphon::TextArea<'a>>) {
+        self.column.collect_text_areas(layout_ctx, geometry, areas);
+    }
+
+    fn render<'rp>(&'rp self, ctx: &'rp RenderContext, render_pass: &mut wgpu::RenderPass<'rp>, geometry: Geometry) {
+        self.column.render(ctx, render_pass, geometry);
+    }
+
+    fn handle_event(&mut self, event: &Event, layout_ctx: &LayoutContext, geometry: Geometry) {
+        self.column.handle_event(event, layout_ctx, geometry);
+    }
+}
+
+fn main() -> Result<(), AppError> {
+    let caption = Text::new("Sunset over the harbor").size(28.0).shadow([2.0, 2.0], 1.5, [0.0, 0.0, 0.0, 0.9]);
+    let plain = Text::new("The same caption without a shadow").size(28.0);
+
+    let app_view = Busy { column: Column::new(vec![Box::new(caption), Box::new(plain)]), node_id: None };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
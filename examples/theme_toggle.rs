@@ -0,0 +1,30 @@
+use noxkit::prelude::*;
+
+fn main() -> Result<(), AppError> {
+    let dark = create_signal(false);
+
+    // Colors follow the theme signal; the tree is built once and never rebuilt
+    let surface = {
+        let dark = dark.clone();
+        create_computed(move || if dark.get() { [0.12, 0.12, 0.14, 1.0] } else { [0.93, 0.93, 0.96, 1.0] })
+    };
+    let text = {
+        let dark = dark.clone();
+        create_computed(move || if dark.get() { [0.95, 0.95, 0.95, 1.0] } else { [0.1, 0.1, 0.12, 1.0] })
+    };
+    let accent = {
+        let dark = dark.clone();
+        create_computed(move || if dark.get() { [0.98, 0.6, 0.2, 1.0] } else { [0.247, 0.317, 0.709, 1.0] })
+    };
+
+    let app_view = view! {
+        Column {
+            Text("Colors bound to a theme signal") { bind_color: text },
+            RoundedRect([0.0; 4], 12.0) { bind_color: surface },
+            Button("Toggle theme", move || dark.update(|dark| *dark = !*dark)) { bind_color: accent }
+        }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
@@ -0,0 +1,56 @@
+use noxkit::prelude::*;
+
+const ICON_SIZE: u32 = 32;
+
+// A toolbar of white icons drawn from the same pixels: tinted to the theme color, grayed out
+// while editing is locked, and flooded with the accent color for the selected tool
+fn main() -> Result<(), AppError> {
+    let locked = create_signal(false);
+    let selected = create_signal(0usize);
+
+    let app = App::with_render_fn(move || {
+        let toolbar = Row::default().children((0..4).map(|tool| {
+            let (color, mode) = if locked.get() {
+                ([0.5, 0.5, 0.5, 0.4], TintMode::Multiply)
+            } else if selected.get() == tool {
+                ([1.0, 0.6, 0.1, 1.0], TintMode::Overlay)
+            } else {
+                ([0.25, 0.32, 0.71, 1.0], TintMode::Multiply)
+            };
+            let (select, is_locked) = (selected.clone(), locked.clone());
+            Interactive::new(Image(ICON_SIZE, ICON_SIZE, icon(tool)).tint(color).tint_mode(mode))
+                .on_click(move |_, _| if !is_locked.get() { select.set(tool) })
+        }));
+        let toggle = locked.clone();
+        let label = if locked.get() { "Unlock" } else { "Lock" };
+        Box::new(view! {
+            Column {
+                toolbar,
+                Button(label, move || toggle.update(|locked| *locked = !*locked))
+            }
+        })
+    });
+    app.run()
+}
+
+// White shapes on a transparent background: a square, a circle, a plus and a triangle
+fn icon(shape: usize) -> Vec<u8> {
+    let mut rgba = vec![0u8; (ICON_SIZE * ICON_SIZE * 4) as usize];
+    let center = ICON_SIZE as f32 / 2.0;
+    for y in 0..ICON_SIZE {
+        for x in 0..ICON_SIZE {
+            let (dx, dy) = (x as f32 + 0.5 - center, y as f32 + 0.5 - center);
+            let inside = match shape {
+                0 => dx.abs() < 10.0 && dy.abs() < 10.0,
+                1 => dx * dx + dy * dy < 144.0,
+                2 => (dx.abs() < 3.0 && dy.abs() < 12.0) || (dy.abs() < 3.0 && dx.abs() < 12.0),
+                _ => dy > -10.0 && dy < 10.0 && dx.abs() < (dy + 10.0) * 0.6,
+            };
+            if inside {
+                let i = ((y * ICON_SIZE + x) * 4) as usize;
+                rgba[i..i + 4].copy_from_slice(&[255, 255, 255, 255]);
+            }
+        }
+    }
+    rgba
+}
@@ -0,0 +1,81 @@
+use noxkit::prelude::*;
+use noxkit::layout::{LayoutContext, LayoutError};
+use noxkit::render::RenderContext;
+use noxkit::view::Geometry;
+
+/// A bare-bones editor over a `Signal<String>`: type to append, Backspace to delete,
+/// Ctrl+Z to undo and Ctrl+Shift+Z (or Ctrl+Y) to redo. Keystrokes less than a second
+/// apart undo together; Enter always starts a new undo step.
+struct Editor {
+    text: Signal<String>,
+    history: History<String>,
+    view: Text,
+}
+
+impl Editor {
+    fn new(text: &Signal<String>, history: &History<String>) -> Self {
+        Self { text: text.clone(), history: history.clone(), view: Text::new(text.get()) }
+    }
+}
+
+impl View for Editor {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<taffy::prelude::NodeId, LayoutError> {
+        self.view.layout(ctx)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        self.view.prepare(ctx, layout_ctx, geometry);
+    }
+
+    fn collect_text_areas<'a>(&'a self, layout_ctx: &LayoutContext, geometry: Geometry, areas: &mut Vec<glyphon::TextArea<'a>>) {
+        self.view.collect_text_areas(layout_ctx, geometry, areas);
+    }
+
+    fn render<'rp>(&'rp self, _: &'rp RenderContext, _: &mut wgpu::RenderPass<'rp>, _: Geometry) {}
+
+    fn handle_event(&mut self, event: &Event, _: &LayoutContext, _: Geometry) {
+        let Event::KeyDown { key, modifiers } = event else { return };
+        let command = modifiers.ctrl || modifiers.logo;
+        match key {
+            Key::Character('z') | Key::Character('Z') if command && modifiers.shift => { self.history.redo(); }
+            Key::Character('z') if command => { self.history.undo(); }
+            Key::Character('y') if command => { self.history.redo(); }
+            Key::Character(c) if !command => self.text.update(|text| text.push(*c)),
+            Key::Backspace => self.text.update(|text| { text.pop(); }),
+            Key::Enter => {
+                self.history.checkpoint();
+                self.text.update(|text| text.push('\n'));
+            }
+            _ => {}
+        }
+    }
+
+    // Follow the signal however it changed: typing, undo or redo
+    fn on_update(&mut self) {
+        let text = self.text.get();
+        if self.view.text != text {
+            self.view.text = text;
+            request_layout();
+        }
+    }
+}
+
+fn main() -> Result<(), AppError> {
+    let text = create_signal(String::from("Start typing. "));
+    let history = History::new(&text);
+
+    let app_view = view! {
+        Column {
+            let undo = history.clone();
+            let redo = history.clone();
+            Row {
+                Button("Undo", move || { undo.undo(); }),
+                Button("Redo", move || { redo.redo(); })
+            },
+            Editor::new(&text, &history)
+        }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
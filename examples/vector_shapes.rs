@@ -0,0 +1,25 @@
+use noxkit::prelude::*;
+
+const HEART: &str = "M 12 21 C 5 15 2 12 2 8 C 2 5 4 3 7 3 C 9 3 11 4 12 6 C 13 4 15 3 17 3 C 20 3 22 5 22 8 C 22 12 19 15 12 21 Z";
+// A five-pointed star drawn in one stroke, crossing itself around the middle
+const STAR: &str = "M 50 5 L 79 95 L 2 40 L 98 40 L 21 95 Z";
+
+// Shapes filled from SVG path data: a heart of cubic curves, and the same star filled with
+// both fill rules, the even-odd one leaving its middle empty
+fn main() -> Result<(), AppError> {
+    let app_view = view! {
+        Row {
+            Path::parse(HEART, [0.9, 0.2, 0.3, 1.0]).unwrap().view_box(0.0, 0.0, 24.0, 24.0).size(120.0, 120.0),
+            Column {
+                Path::parse(STAR, [1.0, 0.75, 0.1, 1.0]).unwrap().size(120.0, 120.0),
+                Text("Nonzero")
+            },
+            Column {
+                Path::parse(STAR, [1.0, 0.75, 0.1, 1.0]).unwrap().fill_rule(FillRule::EvenOdd).size(120.0, 120.0),
+                Text("Even-odd")
+            }
+        }
+    };
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
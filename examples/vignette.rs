@@ -0,0 +1,33 @@
+use noxkit::prelude::*;
+use noxkit::Background;
+
+// Darkens the frame gently towards its corners
+const VIGNETTE: &str = r#"
+@fragment
+fn fs_main(in: PostProcessInput) -> @location(0) vec4<f32> {
+    let color = textureSample(frame, frame_sampler, in.uv);
+    // Measure in pixels so the falloff stays round in wide windows
+    let offset = (in.uv - vec2<f32>(0.5)) * post.size / max(post.size.x, post.size.y);
+    let shade = 1.0 - 0.45 * smoothstep(0.2, 0.75, length(offset));
+    return vec4<f32>(color.rgb * shade, color.a);
+}
+"#;
+
+fn main() -> Result<(), AppError> {
+    let app_view = view! {
+        Column {
+            Text("A vignette drawn over the whole UI by a post-process shader"),
+            Row {
+                RoundedRect([0.25, 0.6, 0.95, 1.0], 16.0),
+                Circle([1.0, 0.8, 0.0, 1.0]),
+                CircularProgress(0.6)
+            },
+            Button("Click me", || println!("Clicked"))
+        }
+    };
+
+    let app = App::new(Box::new(app_view))
+        .with_background(Background::Solid([0.95, 0.95, 0.97, 1.0]))
+        .with_post_process(VIGNETTE);
+    app.run()
+}
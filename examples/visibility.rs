@@ -0,0 +1,30 @@
+use noxkit::prelude::*;
+
+fn toggle(visibility: &Signal<Visibility>, hidden: Visibility) {
+    visibility.update(|v| *v = if *v == Visibility::Visible { hidden } else { Visibility::Visible });
+}
+
+fn main() -> Result<(), AppError> {
+    let reserved = create_signal(Visibility::Visible);
+    let collapsed = create_signal(Visibility::Visible);
+
+    let app_view = view! {
+        Column {
+            let hide = reserved.clone();
+            let collapse = collapsed.clone();
+            Row {
+                Button("Hide (keep space)", move || toggle(&hide, Visibility::Hidden)),
+                Button("Collapse", move || toggle(&collapse, Visibility::Collapsed))
+            },
+            Text("Above the placeholder"),
+            // Typed text survives hiding: the input stays in the tree
+            TextInput("", |_| {}) { placeholder: "Reserved: its space stays when hidden", visibility: reserved },
+            Text("Between the placeholders"),
+            TextInput("", |_| {}) { placeholder: "Collapsible: the text below moves up", visibility: collapsed },
+            Text("Below the placeholders")
+        }
+    };
+
+    let app = App::new(Box::new(app_view));
+    app.run()
+}
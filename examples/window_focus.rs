@@ -0,0 +1,62 @@
+use noxkit::prelude::*;
+use noxkit::layout::{LayoutContext, LayoutError};
+use noxkit::render::RenderContext;
+use noxkit::view::Geometry;
+
+/// Covers its child with a translucent veil while the window is in the background.
+struct DimWhenInactive {
+    child: Column,
+    focused: bool,
+    node_id: Option<taffy::prelude::NodeId>,
+}
+
+impl DimWhenInactive {
+    fn new(child: Column) -> Self {
+        Self { child, focused: true, node_id: None }
+    }
+}
+
+impl View for DimWhenInactive {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<taffy::prelude::NodeId, LayoutError> {
+        let node = self.child.layout(ctx)?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        self.child.prepare(ctx, layout_ctx, geometry);
+        if !self.focused && let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) {
+            let previous_z = ctx.render_queue.set_z_index(1000);
+            ctx.render_queue.push_rect(my_geo, [0.0, 0.0, 0.0, 0.5]);
+            ctx.render_queue.set_z_index(previous_z);
+        }
+    }
+
+    fn collect_text_areas<'a>(&'a self, layout_ctx: &LayoutContext, geometry: Geometry, areas: &mut Vec<glyphon::TextArea<'a>>) {
+        self.child.collect_text_areas(layout_ctx, geometry, areas);
+    }
+
+    fn render<'rp>(&'rp self, ctx: &'rp RenderContext, render_pass: &mut wgpu::RenderPass<'rp>, geometry: Geometry) {
+        self.child.render(ctx, render_pass, geometry);
+    }
+
+    fn handle_event(&mut self, event: &Event, layout_ctx: &LayoutContext, geometry: Geometry) {
+        if let Event::WindowFocus { focused } = event {
+            self.focused = *focused;
+        }
+        self.child.handle_event(event, layout_ctx, geometry);
+    }
+}
+
+fn main() -> Result<(), AppError> {
+    let content = view! {
+        Column {
+            Text("Switch to another window to dim this one.").size(20.0),
+            RoundedRect([0.2, 0.5, 0.9, 1.0], 16.0),
+            Button("Still clickable", || println!("Clicked"))
+        }
+    };
+
+    let app = App::new(Box::new(DimWhenInactive::new(content)));
+    app.run()
+}
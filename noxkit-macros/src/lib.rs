@@ -1,33 +1,202 @@
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{parse_macro_input, Expr, Ident, Token, parse::{Parse, ParseStream}, braced};
+use quote::{quote, quote_spanned};
+use syn::{parse_macro_input, spanned::Spanned, Expr, Ident, Path, Token, parse::{Parse, ParseStream, discouraged::Speculative}, punctuated::Punctuated, braced, parenthesized};
 
 enum ViewElement {
     Widget {
-        name: Ident,
+        path: Path,
+        props: Option<Vec<Expr>>,
+        attributes: Vec<Attribute>,
+        bindings: Vec<syn::Stmt>,
         children: Vec<ViewElement>,
     },
+    Match {
+        scrutinee: Expr,
+        arms: Vec<MatchArm>,
+    },
     Expr(Expr),
 }
 
+/// `pattern if guard => view`, where the view may use any `view!` syntax.
+struct MatchArm {
+    pattern: syn::Pat,
+    guard: Option<Expr>,
+    body: ViewElement,
+}
+
+impl Parse for MatchArm {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let pattern = syn::Pat::parse_multi_with_leading_vert(input)?;
+        let guard = if input.peek(Token![if]) {
+            input.parse::<Token![if]>()?;
+            Some(input.call(Expr::parse_without_eager_brace)?)
+        } else {
+            None
+        };
+        input.parse::<Token![=>]>()?;
+        let body = input.parse()?;
+        Ok(MatchArm { pattern, guard, body })
+    }
+}
+
+/// A `key: value` entry in a widget's braces, applied as the builder call `.key(value)`.
+struct Attribute {
+    key: Ident,
+    value: Expr,
+}
+
+enum Entry {
+    Attribute(Attribute),
+    Let(syn::Stmt),
+    Child(ViewElement),
+}
+
+impl Parse for Entry {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![let]) {
+            Ok(Entry::Let(input.parse()?))
+        } else if input.peek(Ident) && input.peek2(Token![:]) && !input.peek2(Token![::]) {
+            let key: Ident = input.parse()?;
+            input.parse::<Token![:]>()?;
+            let value: Expr = input.parse()?;
+            Ok(Entry::Attribute(Attribute { key, value }))
+        } else {
+            Ok(Entry::Child(input.parse()?))
+        }
+    }
+}
+
+/// Properties handled by wrapping the widget in `Interactive`, so any widget can take them.
+const HANDLERS: &[&str] = &["on_click", "on_double_click", "on_long_press", "on_hover_change", "on_press_change", "on_focus_change"];
+
+/// Parses the `Path` or `Path(props)` in front of a widget's `{ children }` block.
+fn parse_widget_head(input: ParseStream) -> syn::Result<(Path, Option<Vec<Expr>>)> {
+    let path = input.call(Path::parse_mod_style)?;
+    let props = if input.peek(syn::token::Paren) {
+        let content;
+        parenthesized!(content in input);
+        Some(Punctuated::<Expr, Token![,]>::parse_terminated(&content)?.into_iter().collect())
+    } else {
+        None
+    };
+    Ok((path, props))
+}
+
+impl ViewElement {
+    fn span(&self) -> proc_macro2::Span {
+        match self {
+            ViewElement::Widget { path, .. } => path.span(),
+            ViewElement::Match { scrutinee, .. } => scrutinee.span(),
+            ViewElement::Expr(expr) => expr.span(),
+        }
+    }
+}
+
+/// The contents of a widget's braces.
+#[derive(Default)]
+struct Entries {
+    attributes: Vec<Attribute>,
+    bindings: Vec<syn::Stmt>,
+    children: Vec<ViewElement>,
+}
+
+/// Parses the comma-separated entries of a widget's braces. `let` bindings end with `;`
+/// instead of a comma.
+fn parse_entries(input: ParseStream) -> syn::Result<Entries> {
+    let mut entries = Entries::default();
+    while !input.is_empty() {
+        match input.parse()? {
+            Entry::Let(binding) => {
+                entries.bindings.push(binding);
+                continue;
+            }
+            Entry::Attribute(attribute) => entries.attributes.push(attribute),
+            Entry::Child(child) => {
+                // A bare name followed by a value is most likely a property missing its colon
+                if let ViewElement::Expr(Expr::Path(path)) = &child
+                    && let Some(name) = path.path.get_ident()
+                    && !input.is_empty()
+                    && !input.peek(Token![,])
+                {
+                    return Err(syn::Error::new(
+                        name.span(),
+                        format!("expected `,` after `{}`, or `{}: value` to set a property", name, name),
+                    ));
+                }
+                entries.children.push(child);
+            }
+        }
+        if !input.is_empty() && !input.peek(Token![,]) {
+            return Err(input.error("expected `,` between entries"));
+        }
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+    }
+    Ok(entries)
+}
+
 impl Parse for ViewElement {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        if input.peek(Ident) && input.peek2(syn::token::Brace) {
-            let name: Ident = input.parse()?;
+        if input.peek(Token![match]) {
+            input.parse::<Token![match]>()?;
+            let scrutinee = input.call(Expr::parse_without_eager_brace)?;
             let content;
             braced!(content in input);
-            let children = content.parse_terminated(ViewElement::parse, Token![,])?;
-            Ok(ViewElement::Widget {
-                name,
-                children: children.into_iter().collect(),
-            })
-        } else {
-            let expr: Expr = input.parse()?;
-            Ok(ViewElement::Expr(expr))
+            let mut arms = Vec::new();
+            while !content.is_empty() {
+                arms.push(content.parse()?);
+                if content.peek(Token![,]) {
+                    content.parse::<Token![,]>()?;
+                }
+            }
+            return Ok(ViewElement::Match { scrutinee, arms });
+        }
+
+        // Anything not followed by a `{ children }` block is a plain expression
+        let fork = input.fork();
+        if let Ok((path, props)) = parse_widget_head(&fork)
+            && fork.peek(syn::token::Brace)
+        {
+            input.advance_to(&fork);
+            let content;
+            braced!(content in input);
+            let Entries { attributes, bindings, children } = parse_entries(&content)?;
+            return Ok(ViewElement::Widget { path, props, attributes, bindings, children });
         }
+        let expr: Expr = input.parse()?;
+        Ok(ViewElement::Expr(expr))
     }
 }
 
+/// Builds a view tree. `Name { a, b }` expands to `Name::default().child(a).child(b)`, where
+/// every child is converted to `Box<dyn View>` through `IntoViews`, whether it is a nested
+/// container or any other expression, such as a helper returning `impl View` or
+/// `Box<dyn View>`. `Fragment { a, b }` children (or helpers returning a `Fragment`) are
+/// spliced in as siblings.
+///
+/// Names resolve like any other path in scope, so user-defined components work the same
+/// way as built-in widgets. Props go in parentheses and are passed to `new`:
+/// `Card("Title") { a, b }` calls `Card::new("Title").child(a).child(b)`, so a component
+/// needs a `new` taking its props and, to take children, a `child(impl IntoViews)` builder.
+/// A lowercase name is treated as a component function instead: `card("Title") { ... }`
+/// calls `card("Title", children)` with the children collected into a `Vec`.
+///
+/// `key: value` entries in the braces set properties through builder methods, so
+/// `Button("Save", save) { radius: 12.0 }` becomes `Button::new("Save", save).radius(12.0)`.
+/// Unknown properties fail to compile, pointing at the key.
+///
+/// `let` bindings in the braces (`let label = format!("{}", n);`) are evaluated before the
+/// widget is built and are in scope for its props, properties and children.
+///
+/// `match value { Pattern => view, ... }` picks a view by pattern; arms may build different
+/// widget types and are boxed as `Box<dyn View>`.
+///
+/// Event handlers (`on_click`, `on_double_click`, `on_long_press`, `on_hover_change`,
+/// `on_press_change`, `on_focus_change`) work on any widget: it gets wrapped in
+/// `Interactive`, e.g.
+/// `Rect(color) { on_click: |x, y| println!("{x}, {y}") }`. Likewise `visibility: signal`
+/// wraps any widget in `Hideable`, showing or hiding it as the `Signal<Visibility>` changes.
 #[proc_macro]
 pub fn view(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as ViewElement);
@@ -37,15 +206,71 @@ pub fn view(input: TokenStream) -> TokenStream {
 
 fn expand_view_element(element: &ViewElement) -> proc_macro2::TokenStream {
     match element {
-        ViewElement::Widget { name, children } => {
-            let expanded_children = children.iter().map(|child| {
-                let expanded = expand_view_element(child);
-                quote! { Box::new(#expanded) as Box<dyn noxkit::view::View> }
+        ViewElement::Widget { path, props, attributes, bindings, children } => {
+            let (visibility, attributes): (Vec<_>, Vec<_>) = attributes.iter()
+                .partition(|attribute| attribute.key == "visibility");
+            let (handlers, properties): (Vec<_>, Vec<_>) = attributes.into_iter()
+                .partition(|attribute| HANDLERS.contains(&attribute.key.to_string().as_str()));
+            let setters = properties.iter().map(|Attribute { key, value }| {
+                quote_spanned! { key.span()=> .#key(#value) }
             });
-            quote! {
-                noxkit::widgets::#name::new(vec![#(#expanded_children),*])
+            let handlers: Vec<_> = handlers.iter().map(|Attribute { key, value }| {
+                quote_spanned! { key.span()=> .#key(#value) }
+            }).collect();
+            let is_function = path.segments.last()
+                .is_some_and(|segment| segment.ident.to_string().starts_with(|c: char| c.is_lowercase()));
+            let widget = if is_function {
+                let props = props.iter().flatten();
+                // Mixed-site hygiene keeps the temporary from shadowing user variables
+                let views = Ident::new("views", proc_macro2::Span::mixed_site());
+                // Spanned per child so a child that isn't a view is reported on the child itself
+                let pushes = children.iter().map(|child| {
+                    let expanded = expand_view_element(child);
+                    quote_spanned! { child.span()=> #views.extend(noxkit::view::IntoViews::into_views(#expanded)); }
+                });
+                quote! {
+                    #path(#(#props,)* {
+                        let mut #views: Vec<Box<dyn noxkit::view::View>> = Vec::new();
+                        #(#pushes)*
+                        #views
+                    })
+                }
+            } else {
+                let constructor = match props {
+                    Some(props) => quote! { #path::new(#(#props),*) },
+                    None => quote! { #path::default() },
+                };
+                // Spanned per child so a child that isn't a view is reported on the child itself
+                let children = children.iter().map(|child| {
+                    let expanded = expand_view_element(child);
+                    quote_spanned! { child.span()=> .child(#expanded) }
+                });
+                quote! { #constructor #(#children)* }
+            };
+            let widget = if handlers.is_empty() {
+                quote! { #widget #(#setters)* }
+            } else {
+                quote! { noxkit::widgets::Interactive::new(#widget #(#setters)*) #(#handlers)* }
+            };
+            let widget = match visibility.last() {
+                Some(Attribute { key, value }) => quote_spanned! { key.span()=> noxkit::widgets::Hideable::new(#widget).visibility(#value) },
+                None => widget,
+            };
+            if bindings.is_empty() {
+                widget
+            } else {
+                quote! {{ #(#bindings)* #widget }}
             }
         }
+        ViewElement::Match { scrutinee, arms } => {
+            // Arms produce different widget types, so each is boxed to a common type
+            let arms = arms.iter().map(|MatchArm { pattern, guard, body }| {
+                let guard = guard.as_ref().map(|guard| quote! { if #guard });
+                let body = expand_view_element(body);
+                quote! { #pattern #guard => Box::new(#body) as Box<dyn noxkit::view::View>, }
+            });
+            quote! { match #scrutinee { #(#arms)* } }
+        }
         ViewElement::Expr(expr) => {
             quote! { #expr }
         }
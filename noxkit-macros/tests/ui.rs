@@ -0,0 +1,8 @@
+/// Mistakes in `view!` should be reported on the tokens that caused them, and valid
+/// trees should expand to code that compiles.
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+    t.pass("tests/ui/pass/*.rs");
+}
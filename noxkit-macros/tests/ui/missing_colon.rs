@@ -0,0 +1,14 @@
+#[path = "support/noxkit.rs"]
+mod noxkit;
+
+use noxkit::widgets::*;
+use noxkit_macros::view;
+
+fn main() {
+    let _ = view! {
+        Column {
+            padding 8.0,
+            Text("Hello")
+        }
+    };
+}
@@ -0,0 +1,29 @@
+#[path = "../support/noxkit.rs"]
+mod noxkit;
+
+use noxkit::view::View;
+use noxkit::widgets::*;
+use noxkit_macros::view;
+
+fn title() -> impl View {
+    Text::new("Title")
+}
+
+fn footer() -> Box<dyn View> {
+    Box::new(Text::new("Footer"))
+}
+
+fn main() {
+    let column = view! {
+        Column {
+            padding: 8.0,
+            Text("Inline"),
+            title(),
+            footer(),
+            Column {
+                footer(),
+            },
+        }
+    };
+    assert_eq!(column.child_count(), 4);
+}
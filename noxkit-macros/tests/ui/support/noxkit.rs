@@ -0,0 +1,70 @@
+//! Just enough of noxkit's widget API for `view!` to expand against, so the UI tests don't
+//! build the whole toolkit.
+#![allow(dead_code)]
+
+pub mod view {
+    pub trait View {}
+
+    pub trait IntoViews {
+        fn into_views(self) -> Vec<Box<dyn View>>;
+    }
+
+    impl<V: View + 'static> IntoViews for V {
+        fn into_views(self) -> Vec<Box<dyn View>> {
+            vec![Box::new(self)]
+        }
+    }
+
+    impl<V: View + ?Sized> View for Box<V> {}
+
+    impl IntoViews for Vec<Box<dyn View>> {
+        fn into_views(self) -> Vec<Box<dyn View>> {
+            self
+        }
+    }
+}
+
+pub mod widgets {
+    use super::view::{IntoViews, View};
+
+    #[derive(Default)]
+    pub struct Column {
+        children: Vec<Box<dyn View>>,
+        padding: f32,
+    }
+
+    impl Column {
+        pub fn child(mut self, child: impl IntoViews) -> Self {
+            self.children.extend(child.into_views());
+            self
+        }
+
+        pub fn child_count(&self) -> usize {
+            self.children.len()
+        }
+
+        pub fn padding(mut self, padding: f32) -> Self {
+            self.padding = padding;
+            self
+        }
+    }
+
+    impl View for Column {}
+
+    pub struct Text {
+        text: String,
+    }
+
+    impl Text {
+        pub fn new(text: &str) -> Self {
+            Self { text: text.to_string() }
+        }
+    }
+
+    impl View for Text {}
+
+    #[allow(non_snake_case)]
+    pub fn Text(text: &str) -> Text {
+        Text::new(text)
+    }
+}
@@ -0,0 +1,188 @@
+use std::f32::consts::PI;
+
+/// How an animation moves from start to end over time: maps progress in 0..1 to how far
+/// along the animated value is, mostly in 0..1 too. `Back` and `Elastic` overshoot.
+///
+/// ```
+/// use noxkit::animation::Easing;
+///
+/// assert_eq!(Easing::Linear.apply(0.25), 0.25);
+/// assert!((Easing::CubicInOut.apply(0.5) - 0.5).abs() < 1e-6);
+/// assert!((Easing::QuadIn.apply(0.5) - 0.25).abs() < 1e-6);
+/// assert!((Easing::BounceOut.apply(1.0) - 1.0).abs() < 1e-6);
+/// // Back overshoots the end before settling
+/// assert!(Easing::BackOut.apply(0.7) > 1.0);
+///
+/// // CSS `ease-in-out` is symmetric, so it is halfway at half time
+/// assert!((Easing::EASE_IN_OUT.apply(0.5) - 0.5).abs() < 1e-3);
+/// // and `cubic-bezier(0, 0, 1, 1)` is linear
+/// assert!((Easing::CubicBezier(0.0, 0.0, 1.0, 1.0).apply(0.3) - 0.3).abs() < 1e-3);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Easing {
+    #[default]
+    Linear,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    /// Starts fast and slows down, used for the button ripple and animated scrolling.
+    CubicOut,
+    CubicInOut,
+    QuartIn,
+    QuartOut,
+    QuartInOut,
+    /// Pulls back slightly before moving off.
+    BackIn,
+    /// Overshoots the end slightly, then settles.
+    BackOut,
+    BackInOut,
+    /// Springs past the end and wobbles back, like a stretched rubber band.
+    ElasticIn,
+    ElasticOut,
+    ElasticInOut,
+    /// Bounces off the end like a dropped ball.
+    BounceIn,
+    BounceOut,
+    BounceInOut,
+    /// A cubic Bézier curve from (0, 0) to (1, 1) with control points (x1, y1) and (x2, y2),
+    /// like CSS `cubic-bezier()`. `x1` and `x2` must be in 0..1; the `y`s may overshoot.
+    CubicBezier(f32, f32, f32, f32),
+}
+
+/// Overshoot of the `Back` easings, about 10% past the end.
+const BACK_OVERSHOOT: f32 = 1.70158;
+
+impl Easing {
+    /// CSS `ease`: starts quickly and slows down gently.
+    pub const EASE: Easing = Easing::CubicBezier(0.25, 0.1, 0.25, 1.0);
+    /// CSS `ease-in`.
+    pub const EASE_IN: Easing = Easing::CubicBezier(0.42, 0.0, 1.0, 1.0);
+    /// CSS `ease-out`.
+    pub const EASE_OUT: Easing = Easing::CubicBezier(0.0, 0.0, 0.58, 1.0);
+    /// CSS `ease-in-out`.
+    pub const EASE_IN_OUT: Easing = Easing::CubicBezier(0.42, 0.0, 0.58, 1.0);
+
+    /// How far along the animated value is after `t` of the animation's duration, with `t`
+    /// clamped to 0..1. Always 0 at the start and 1 at the end.
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::QuadIn => t * t,
+            Easing::QuadOut => out(t, |t| t * t),
+            Easing::QuadInOut => in_out(t, |t| t * t),
+            Easing::CubicIn => t.powi(3),
+            Easing::CubicOut => out(t, |t| t.powi(3)),
+            Easing::CubicInOut => in_out(t, |t| t.powi(3)),
+            Easing::QuartIn => t.powi(4),
+            Easing::QuartOut => out(t, |t| t.powi(4)),
+            Easing::QuartInOut => in_out(t, |t| t.powi(4)),
+            Easing::BackIn => back_in(t),
+            Easing::BackOut => out(t, back_in),
+            Easing::BackInOut => in_out(t, back_in),
+            Easing::ElasticIn => elastic_in(t),
+            Easing::ElasticOut => out(t, elastic_in),
+            Easing::ElasticInOut => in_out(t, elastic_in),
+            Easing::BounceIn => 1.0 - bounce_out(1.0 - t),
+            Easing::BounceOut => bounce_out(t),
+            Easing::BounceInOut => in_out(t, |t| 1.0 - bounce_out(1.0 - t)),
+            Easing::CubicBezier(x1, y1, x2, y2) => cubic_bezier(x1, y1, x2, y2, t),
+        }
+    }
+
+    /// The value `t` of the way from `from` to `to` along this curve.
+    pub fn interpolate(self, from: f32, to: f32, t: f32) -> f32 {
+        from + (to - from) * self.apply(t)
+    }
+}
+
+/// The ease-out version of the ease-in curve `ease_in`: the same curve played backwards.
+fn out(t: f32, ease_in: impl Fn(f32) -> f32) -> f32 {
+    1.0 - ease_in(1.0 - t)
+}
+
+/// Eases in over the first half and out over the second, with `ease_in` for both.
+fn in_out(t: f32, ease_in: impl Fn(f32) -> f32) -> f32 {
+    if t < 0.5 {
+        ease_in(2.0 * t) / 2.0
+    } else {
+        1.0 - ease_in(2.0 - 2.0 * t) / 2.0
+    }
+}
+
+fn back_in(t: f32) -> f32 {
+    t * t * ((BACK_OVERSHOOT + 1.0) * t - BACK_OVERSHOOT)
+}
+
+fn elastic_in(t: f32) -> f32 {
+    if t <= 0.0 || t >= 1.0 {
+        return t;
+    }
+    // A sine wave with a period of 0.3, growing exponentially towards the end
+    -(2.0f32).powf(10.0 * t - 10.0) * ((t * 10.0 - 10.75) * (2.0 * PI / 3.0)).sin()
+}
+
+/// Four bounces of decreasing height, landing on 1.
+fn bounce_out(t: f32) -> f32 {
+    const N: f32 = 7.5625;
+    const D: f32 = 2.75;
+    if t < 1.0 / D {
+        N * t * t
+    } else if t < 2.0 / D {
+        let t = t - 1.5 / D;
+        N * t * t + 0.75
+    } else if t < 2.5 / D {
+        let t = t - 2.25 / D;
+        N * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D;
+        N * t * t + 0.984375
+    }
+}
+
+/// The curve's y where its x is `t`. x grows monotonically along the curve when `x1` and
+/// `x2` are in 0..1, so the curve parameter for `t` is found with Newton's method, falling
+/// back to bisection where the slope is too flat for it.
+fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32, t: f32) -> f32 {
+    // One coordinate of the curve at parameter s, with the end points at 0 and 1
+    let sample = |p1: f32, p2: f32, s: f32| {
+        let inv = 1.0 - s;
+        3.0 * inv * inv * s * p1 + 3.0 * inv * s * s * p2 + s * s * s
+    };
+    let slope = |p1: f32, p2: f32, s: f32| {
+        let inv = 1.0 - s;
+        3.0 * inv * inv * p1 + 6.0 * inv * s * (p2 - p1) + 3.0 * s * s * (1.0 - p2)
+    };
+    let (x1, x2) = (x1.clamp(0.0, 1.0), x2.clamp(0.0, 1.0));
+    const EPSILON: f32 = 1e-6;
+
+    let mut s = t;
+    for _ in 0..8 {
+        let error = sample(x1, x2, s) - t;
+        if error.abs() < EPSILON {
+            return sample(y1, y2, s);
+        }
+        let d = slope(x1, x2, s);
+        if d.abs() < EPSILON {
+            break;
+        }
+        s = (s - error / d).clamp(0.0, 1.0);
+    }
+
+    let (mut low, mut high) = (0.0f32, 1.0f32);
+    s = t;
+    for _ in 0..32 {
+        let x = sample(x1, x2, s);
+        if (x - t).abs() < EPSILON {
+            break;
+        }
+        if x < t {
+            low = s;
+        } else {
+            high = s;
+        }
+        s = (low + high) / 2.0;
+    }
+    sample(y1, y2, s)
+}
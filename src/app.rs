@@ -1,21 +1,234 @@
 use winit::application::ApplicationHandler;
 use winit::event_loop::{ActiveEventLoop, ControlFlow, EventLoop};
-use winit::window::{Window, WindowId};
-use winit::event::{WindowEvent, ElementState, MouseButton};
-use crate::view::{View, Geometry, Event};
-use crate::layout::LayoutContext;
-use crate::render::RenderContext;
+use winit::window::{Window, WindowAttributes, WindowId};
+use winit::event::{StartCause, WindowEvent, ElementState, MouseButton, MouseScrollDelta, KeyEvent, Ime, Touch};
+use winit::keyboard::{Key as WinitKey, NamedKey};
+use crate::view::{View, Geometry, Event, Key, Modifiers, TouchPhase, GamepadButton};
+use crate::layout::{self, LayoutContext, LayoutError, TextDirection, FocusDirection};
+use crate::render::{RenderContext, FrameStats, Background, Color, scale_text_areas, MAX_VERTICES, MAX_INDICES};
+use crate::widgets::SnackbarOverlay;
+use crate::state::{create_effect, Signal};
+use crate::menu::{Menu, MenuBar};
+use crate::gamepad::Gamepads;
+use crate::gesture::Gestures;
 use std::sync::Arc;
+use std::rc::Rc;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::path::PathBuf;
 use glam::Mat4;
 
+/// Pixels scrolled per wheel notch, for mice that report scrolling in lines.
+const SCROLL_LINE_HEIGHT: f32 = 40.0;
+
+thread_local! {
+    static COMMAND_QUEUE: Rc<RefCell<VecDeque<Command>>> = Rc::new(RefCell::new(VecDeque::new()));
+}
+
+/// A window or event loop action queued by a `Commands` handle.
+enum Command {
+    Exit,
+    SetTitle(String),
+    RequestRedraw,
+    SetRoot(Box<dyn View>),
+}
+
+/// Lets handlers act on the window and event loop, which they have no access to, e.g. a
+/// "Quit" button capturing `commands` and calling `commands.exit()`. Actions are queued and
+/// the app applies them in order once it has handled the current input.
+///
+/// Every handle on a thread shares the queue of the app running on it, so a handle can be
+/// created before the app, e.g. while building the view.
+#[derive(Clone)]
+pub struct Commands {
+    queue: Rc<RefCell<VecDeque<Command>>>,
+}
+
+impl Default for Commands {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Commands {
+    pub fn new() -> Self {
+        Self { queue: COMMAND_QUEUE.with(Rc::clone) }
+    }
+
+    /// Closes the window and ends `App::run`.
+    pub fn exit(&self) {
+        self.queue.borrow_mut().push_back(Command::Exit);
+    }
+
+    pub fn set_title(&self, title: impl Into<String>) {
+        self.queue.borrow_mut().push_back(Command::SetTitle(title.into()));
+    }
+
+    /// Draws another frame, e.g. after changing state the view reads without a signal.
+    pub fn request_redraw(&self) {
+        // Requests before the next frame all draw that frame, so one queued is enough
+        let mut queue = self.queue.borrow_mut();
+        if !queue.iter().any(|command| matches!(command, Command::RequestRedraw)) {
+            queue.push_back(Command::RequestRedraw);
+        }
+    }
+
+    /// Replaces the app's whole view tree with `view`, e.g. to navigate between screens. The
+    /// old tree is unmounted and the new one mounted and laid out before the next frame.
+    ///
+    /// In apps built with `App::with_render_fn`, the next rebuild replaces `view` in turn.
+    pub fn set_root(&self, view: Box<dyn View>) {
+        self.queue.borrow_mut().push_back(Command::SetRoot(view));
+    }
+
+    fn take(&self) -> VecDeque<Command> {
+        std::mem::take(&mut *self.queue.borrow_mut())
+    }
+}
+
+type WindowEventHook = Box<dyn FnMut(&WindowEvent) -> bool>;
+type OverflowHook = Box<dyn FnMut(&FrameStats)>;
+/// A tree rebuilt by the render function, waiting to replace the current one.
+type RebuiltView = Rc<RefCell<Option<Box<dyn View>>>>;
+
 pub struct App {
     view: Box<dyn View>,
     state: AppState,
     dirty: bool,
     last_frame: std::time::Instant,
     fps: f32,
+    present_mode: Option<wgpu::PresentMode>,
+    power_preference: wgpu::PowerPreference,
+    backends: wgpu::Backends,
+    error: Option<AppError>,
+    modifiers: Modifiers,
+    ime_allowed: bool,
+    window_event_hook: Option<WindowEventHook>,
+    overflow_hook: Option<OverflowHook>,
+    hovering_file: bool,
+    /// Nodes under the pointer as of the last pointer move, to detect enter/leave.
+    hovered_nodes: Vec<taffy::prelude::NodeId>,
+    /// Set to the name of the widget under the pointer, see `with_hovered_widget`.
+    hovered_widget: Option<Signal<Option<String>>>,
+    /// Set to the monitor the window is on, see `with_monitor`.
+    monitor: Option<Signal<Option<MonitorInfo>>>,
+    dropped_files: Vec<PathBuf>,
+    snackbars: SnackbarOverlay,
+    ui_scale: f32,
+    /// Smallest window size, in logical pixels, set with `with_min_size`.
+    min_size: Option<(f32, f32)>,
+    text_shaping: glyphon::Shaping,
+    /// Applied when the app starts, see `with_text_direction`.
+    text_direction: Option<TextDirection>,
+    base_font_size: f32,
+    snap_text: bool,
+    debug: bool,
+    show_fps: bool,
+    font_fallbacks: Vec<String>,
+    background: Background,
+    /// Whether the last frame asked for another one right away.
+    animating: bool,
+    /// When the last frame asked to be redrawn, if it is waiting on a timer.
+    wakeup: Option<std::time::Instant>,
+    /// Set for apps built with `with_render_fn`.
+    rebuilt: Option<RebuiltView>,
+    /// WGSL source set with `with_post_process`.
+    post_process: Option<String>,
+    menus: Vec<Menu>,
+    /// The native menu bar built from `menus` once the window exists.
+    menu_bar: Option<MenuBar>,
+    /// Game controllers, read with the `gamepad` feature.
+    gamepads: Option<Gamepads>,
+    /// Double click and long press recognition, with the timings set on the app.
+    gestures: Gestures,
 }
 
+/// The monitor a window is on, as reported by the OS.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonitorInfo {
+    /// Human-readable name, if the platform has one.
+    pub name: Option<String>,
+    /// Resolution in physical pixels.
+    pub size: (u32, u32),
+    /// Top-left corner in the desktop's physical pixel coordinates.
+    pub position: (i32, i32),
+    /// Refresh rate in Hz, if known.
+    pub refresh_rate: Option<f32>,
+    /// Physical pixels per logical pixel the OS suggests, e.g. 2.0 on most 4K laptop
+    /// screens. `App::with_ui_scale` sets the scale the app actually draws at.
+    pub scale_factor: f64,
+}
+
+impl MonitorInfo {
+    fn new(monitor: &winit::monitor::MonitorHandle) -> Self {
+        let size = monitor.size();
+        let position = monitor.position();
+        Self {
+            name: monitor.name(),
+            size: (size.width, size.height),
+            position: (position.x, position.y),
+            refresh_rate: monitor.refresh_rate_millihertz().map(|millihertz| millihertz as f32 / 1000.0),
+            scale_factor: monitor.scale_factor(),
+        }
+    }
+}
+
+/// Errors that can stop `App::run` before or while the window is running.
+#[derive(Debug)]
+pub enum AppError {
+    EventLoop(winit::error::EventLoopError),
+    Window(winit::error::OsError),
+    Surface(wgpu::CreateSurfaceError),
+    NoAdapter(wgpu::RequestAdapterError),
+    /// The adapter can't present to the window's surface at all.
+    UnsupportedSurface,
+    Device(wgpu::RequestDeviceError),
+    Layout(LayoutError),
+    PostProcess(wgpu::Error),
+}
+
+impl std::fmt::Display for AppError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AppError::EventLoop(err) => write!(f, "Failed to run the event loop: {}", err),
+            AppError::Window(err) => write!(f, "Failed to create a window: {}", err),
+            AppError::Surface(err) => write!(f, "Failed to create a surface: {}", err),
+            AppError::NoAdapter(err) => write!(f, "Failed to find an appropriate GPU adapter: {}", err),
+            AppError::UnsupportedSurface => write!(f, "The GPU adapter can't present to the window surface"),
+            AppError::Device(err) => write!(f, "Failed to create a GPU device: {}", err),
+            AppError::Layout(err) => write!(f, "Failed to lay out the view tree: {}", err),
+            AppError::PostProcess(err) => write!(f, "Failed to compile the post-process shader: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for AppError {}
+
+impl From<winit::error::EventLoopError> for AppError {
+    fn from(err: winit::error::EventLoopError) -> Self { AppError::EventLoop(err) }
+}
+
+impl From<winit::error::OsError> for AppError {
+    fn from(err: winit::error::OsError) -> Self { AppError::Window(err) }
+}
+
+impl From<wgpu::CreateSurfaceError> for AppError {
+    fn from(err: wgpu::CreateSurfaceError) -> Self { AppError::Surface(err) }
+}
+
+impl From<wgpu::RequestAdapterError> for AppError {
+    fn from(err: wgpu::RequestAdapterError) -> Self { AppError::NoAdapter(err) }
+}
+
+impl From<wgpu::RequestDeviceError> for AppError {
+    fn from(err: wgpu::RequestDeviceError) -> Self { AppError::Device(err) }
+}
+
+impl From<LayoutError> for AppError {
+    fn from(err: LayoutError) -> Self { AppError::Layout(err) }
+}
+
+#[allow(clippy::large_enum_variant)]
 enum AppState {
     Idle,
     Running {
@@ -37,151 +250,805 @@ impl App {
             dirty: true,
             last_frame: std::time::Instant::now(),
             fps: 0.0,
+            present_mode: None,
+            power_preference: wgpu::PowerPreference::default(),
+            backends: wgpu::Backends::all(),
+            error: None,
+            modifiers: Modifiers::default(),
+            ime_allowed: false,
+            window_event_hook: None,
+            overflow_hook: None,
+            hovering_file: false,
+            hovered_nodes: Vec::new(),
+            hovered_widget: None,
+            monitor: None,
+            dropped_files: Vec::new(),
+            snackbars: SnackbarOverlay::default(),
+            ui_scale: 1.0,
+            min_size: None,
+            text_shaping: glyphon::Shaping::Advanced,
+            text_direction: None,
+            base_font_size: 16.0,
+            snap_text: false,
+            debug: false,
+            show_fps: false,
+            font_fallbacks: Vec::new(),
+            background: Background::default(),
+            animating: false,
+            wakeup: None,
+            rebuilt: None,
+            post_process: None,
+            menus: Vec::new(),
+            menu_bar: None,
+            gamepads: None,
+            gestures: Gestures::new(),
+        }
+    }
+
+    /// Builds the view tree with `render` and builds it again whenever a signal it read
+    /// changes, e.g. `App::with_render_fn(move || Box::new(view! { Text(format!("{}", count.get())) }))`.
+    ///
+    /// The new tree replaces the old one before the next frame, with the old tree unmounted
+    /// and the new one mounted. Nothing carries over between trees: state that should survive
+    /// a rebuild (like an input's text) belongs in signals, and signals read only inside
+    /// callbacks don't trigger rebuilds. Subscriptions the widgets of a tree make, e.g. with
+    /// `bind_color` or `TextInput::bind`, are released when the next tree is built.
+    pub fn with_render_fn<F>(render: F) -> Self
+    where
+        F: Fn() -> Box<dyn View> + 'static,
+    {
+        let rebuilt: RebuiltView = Rc::new(RefCell::new(None));
+        create_effect({
+            let rebuilt = rebuilt.clone();
+            move || *rebuilt.borrow_mut() = Some(render())
+        });
+        let view = rebuilt.borrow_mut().take().expect("effects run once when created");
+        Self { rebuilt: Some(rebuilt), ..Self::new(view) }
+    }
+
+    /// Requests a specific present mode (e.g. `Mailbox` for low latency or `Immediate` for
+    /// uncapped rendering). Falls back to `Fifo` if the surface does not support it.
+    pub fn with_present_mode(mut self, present_mode: wgpu::PresentMode) -> Self {
+        self.present_mode = Some(present_mode);
+        self
+    }
+
+    /// Hints which GPU to prefer, e.g. `LowPower` to stay on the integrated GPU on laptops.
+    pub fn with_power_preference(mut self, power_preference: wgpu::PowerPreference) -> Self {
+        self.power_preference = power_preference;
+        self
+    }
+
+    /// Restricts which graphics backends (Vulkan, Metal, DX12, GL) the instance may use.
+    pub fn with_backends(mut self, backends: wgpu::Backends) -> Self {
+        self.backends = backends;
+        self
+    }
+
+    /// Sees every raw winit window event before NoxKit handles it. Returning `true` marks the
+    /// event as consumed and skips NoxKit's own handling, so avoid consuming `RedrawRequested`.
+    pub fn on_window_event(mut self, hook: impl FnMut(&WindowEvent) -> bool + 'static) -> Self {
+        self.window_event_hook = Some(Box::new(hook));
+        self
+    }
+
+    /// Scales the whole UI, text and fixed sizes alike, e.g. `1.25` for a "larger text"
+    /// accessibility setting. Views lay out and receive events in logical pixels, which are
+    /// `ui_scale` physical pixels each.
+    pub fn with_ui_scale(mut self, ui_scale: f32) -> Self {
+        self.ui_scale = ui_scale.max(0.1);
+        self
+    }
+
+    /// Runs `source`, a WGSL fragment shader, over every frame before it is shown, e.g. a
+    /// vignette or color grading over the whole UI. See `RenderContext::set_post_process`
+    /// for what the shader gets to work with. `run` fails with `AppError::PostProcess` if
+    /// it doesn't compile.
+    pub fn with_post_process(mut self, source: impl Into<String>) -> Self {
+        self.post_process = Some(source.into());
+        self
+    }
+
+    /// Gives the window a native menu bar, e.g. File, Edit and Help menus. Picking an item
+    /// runs its handler on the event loop before the next frame. Needs the `native-menu`
+    /// feature, and is shown on Windows and macOS; elsewhere the app runs without it.
+    pub fn with_menu(mut self, menus: Vec<Menu>) -> Self {
+        self.menus = menus;
+        self
+    }
+
+    /// Stops the window from being resized below `width` x `height` layout pixels (see
+    /// `with_ui_scale`). Layout never gets less space than this, even where the platform
+    /// ignores the limit.
+    pub fn with_min_size(mut self, width: f32, height: f32) -> Self {
+        self.min_size = Some((width.max(0.0), height.max(0.0)));
+        self
+    }
+
+    /// Shapes text with `Basic` instead of the default `Advanced`, trading ligatures, kerning
+    /// and complex scripts for speed. Text can override it with `Text::shaping`.
+    pub fn with_text_shaping(mut self, shaping: glyphon::Shaping) -> Self {
+        self.text_shaping = shaping;
+        self
+    }
+
+    /// Size of body text in logical pixels, 16 by default. Widgets size their text relative
+    /// to it (buttons at 0.875, app bar titles at 1.25) unless given an explicit size, so
+    /// raising it enlarges all text for readability.
+    pub fn with_base_font_size(mut self, size: f32) -> Self {
+        self.base_font_size = size.max(1.0);
+        self
+    }
+
+    /// Starts every block of text on a whole physical pixel for crisper small text (see
+    /// `RenderContext::snap_text`).
+    pub fn with_text_snapping(mut self, snap: bool) -> Self {
+        self.snap_text = snap;
+        self
+    }
+
+    /// Outlines every container and widget to show how the view was laid out. Off by
+    /// default, so frames only contain what the views draw.
+    pub fn with_debug(mut self, debug: bool) -> Self {
+        self.debug = debug;
+        self
+    }
+
+    /// Shows the frame rate in the top-left corner of the window. Off by default.
+    pub fn with_fps_counter(mut self, show: bool) -> Self {
+        self.show_fps = show;
+        self
+    }
+
+    /// Font families to try, in order, for characters the UI font lacks, such as emoji or
+    /// CJK in a Latin font (see `RenderContext::set_font_fallbacks`).
+    pub fn with_font_fallbacks<S: Into<String>>(mut self, families: impl IntoIterator<Item = S>) -> Self {
+        self.font_fallbacks = families.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Keeps `hovered` set to the `debug_name` of the innermost named widget under the
+    /// pointer, or `None`, e.g. for a status bar showing help about what the user points at.
+    pub fn with_hovered_widget(mut self, hovered: Signal<Option<String>>) -> Self {
+        self.hovered_widget = Some(hovered);
+        self
+    }
+
+    /// Keeps `monitor` set to the monitor the window is on, or `None` if the platform can't
+    /// tell, updating it as the window moves between monitors or their settings change,
+    /// e.g. to lay out more generously on a 4K screen.
+    pub fn with_monitor(mut self, monitor: Signal<Option<MonitorInfo>>) -> Self {
+        self.monitor = Some(monitor);
+        self
+    }
+
+    /// Longest time between two clicks for the second to also send `Event::DoubleClick`,
+    /// 500ms by default. Some users need longer, and some platforms let them set it.
+    pub fn with_double_click_time(mut self, time: std::time::Duration) -> Self {
+        self.gestures.double_click_time = time;
+        self
+    }
+
+    /// How long the mouse button has to be held without moving to send `Event::LongPress`,
+    /// 500ms by default.
+    pub fn with_long_press_time(mut self, time: std::time::Duration) -> Self {
+        self.gestures.long_press_time = time;
+        self
+    }
+
+    /// Lays the app out right to left (or left to right, the default). Takes effect when
+    /// the app starts; change it while running with `set_text_direction`.
+    pub fn with_text_direction(mut self, direction: TextDirection) -> Self {
+        self.text_direction = Some(direction);
+        self
+    }
+
+    /// Sets what the window shows behind the view. Solid colors just clear the frame;
+    /// gradients and images are drawn as a full-window layer beneath every primitive.
+    pub fn with_background(mut self, background: Background) -> Self {
+        self.background = background;
+        self
+    }
+
+    /// Called after any frame that pushed more primitives than the render queue can hold.
+    /// The extra primitives are skipped; `FrameStats::dropped_primitives` says how many.
+    pub fn on_render_overflow(mut self, hook: impl FnMut(&FrameStats) + 'static) -> Self {
+        self.overflow_hook = Some(Box::new(hook));
+        self
+    }
+
+    /// An adapter from `instance` that can present to `surface`, if given. Fails with
+    /// `AppError::NoAdapter` when neither a hardware nor the fallback adapter is available.
+    pub(crate) fn request_adapter(instance: &wgpu::Instance, surface: Option<&wgpu::Surface<'_>>, power_preference: wgpu::PowerPreference) -> Result<wgpu::Adapter, AppError> {
+        with_fallback(|force_fallback_adapter| {
+            pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+                power_preference,
+                compatible_surface: surface,
+                force_fallback_adapter,
+            }))
+        })
+        .map_err(AppError::from)
+    }
+
+    fn surface_config(surface: &wgpu::Surface, adapter: &wgpu::Adapter, size: winit::dpi::PhysicalSize<u32>, present_mode: Option<wgpu::PresentMode>) -> Result<wgpu::SurfaceConfiguration, AppError> {
+        let mut config = surface.get_default_config(adapter, size.width, size.height).ok_or(AppError::UnsupportedSurface)?;
+        if let Some(mode) = present_mode {
+            let supported = surface.get_capabilities(adapter).present_modes;
+            config.present_mode = if supported.contains(&mode) {
+                mode
+            } else {
+                // Fifo is the only mode every surface is required to support
+                tracing::warn!("Present mode {:?} is not supported, falling back to Fifo", mode);
+                wgpu::PresentMode::Fifo
+            };
         }
+        Ok(config)
     }
 
-    fn update_layout(view: &mut Box<dyn View>, layout_ctx: &mut LayoutContext, size: winit::dpi::PhysicalSize<u32>) -> taffy::prelude::NodeId {
-        let root_node = view.layout(layout_ctx);
-        layout_ctx.taffy.compute_layout(
+    pub(crate) fn update_layout(view: &mut Box<dyn View>, layout_ctx: &mut LayoutContext, size: winit::dpi::PhysicalSize<u32>, font_system: &mut glyphon::FontSystem) -> Result<taffy::prelude::NodeId, LayoutError> {
+        // E.g. a resize event that didn't change the size
+        if let Some(root) = layout_ctx.current_root(size) {
+            return Ok(root);
+        }
+        // Views rebuild their nodes on every layout, so start from an empty tree
+        layout_ctx.clear();
+        let width = (size.width as f32 / layout_ctx.ui_scale).max(layout_ctx.min_window_size.width);
+        let height = (size.height as f32 / layout_ctx.ui_scale).max(layout_ctx.min_window_size.height);
+        layout_ctx.window_size = taffy::prelude::Size { width, height };
+        let root_node = view.layout(layout_ctx)?;
+        layout_ctx.compute_layout(
             root_node,
             taffy::prelude::Size {
-                width: taffy::prelude::AvailableSpace::Definite(size.width as f32),
-                height: taffy::prelude::AvailableSpace::Definite(size.height as f32),
+                width: taffy::prelude::AvailableSpace::Definite(width),
+                height: taffy::prelude::AvailableSpace::Definite(height),
             },
-        ).unwrap();
-        root_node
+            font_system,
+        )?;
+        layout_ctx.set_laid_out_for(size);
+        Ok(root_node)
     }
 
-    pub fn run(mut self) {
+    /// Swaps in the tree rebuilt by the render function, if there is one.
+    fn take_rebuilt_view(&mut self) {
+        let Some(view) = self.rebuilt.as_ref().and_then(|rebuilt| rebuilt.borrow_mut().take()) else { return };
+        Self::replace_view(&mut self.view, &mut self.hovered_nodes, view);
+    }
+
+    /// Unmounts the current tree and mounts `view` in its place, to be laid out next frame.
+    fn replace_view(current: &mut Box<dyn View>, hovered_nodes: &mut Vec<taffy::prelude::NodeId>, view: Box<dyn View>) {
+        current.on_unmount();
+        *current = view;
+        current.on_init();
+        current.on_mount();
+        // The nodes belonged to the old tree
+        hovered_nodes.clear();
+        layout::request_layout();
+    }
+
+    fn window_attributes(&self) -> WindowAttributes {
+        let mut attributes = Window::default_attributes();
+        if let Some((width, height)) = self.min_size {
+            // Layout pixels are `ui_scale` physical pixels each
+            attributes = attributes.with_min_inner_size(winit::dpi::PhysicalSize::new((width * self.ui_scale).ceil() as u32, (height * self.ui_scale).ceil() as u32));
+        }
+        attributes
+    }
+
+    fn start(&mut self, event_loop: &ActiveEventLoop) -> Result<(), AppError> {
+        let window = Arc::new(event_loop.create_window(self.window_attributes())?);
+        
+        let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor {
+            backends: self.backends,
+            ..Default::default()
+        });
+        let surface = instance.create_surface(window.clone())?;
+        
+        let adapter = Self::request_adapter(&instance, Some(&surface), self.power_preference)?;
+        let adapter_info = adapter.get_info();
+        tracing::info!("Using adapter \"{}\" ({:?})", adapter_info.name, adapter_info.backend);
+
+        let (device, queue) = pollster::block_on(adapter.request_device(
+            &wgpu::DeviceDescriptor {
+                label: None,
+                required_features: wgpu::Features::empty(),
+                required_limits: wgpu::Limits::default(),
+                ..Default::default()
+            }
+        ))?;
+
+        let config = Self::surface_config(&surface, &adapter, window.inner_size(), self.present_mode)?;
+        surface.configure(&device, &config);
+
+        let mut render_ctx = RenderContext::new(device, queue, &config);
+        render_ctx.stats.backend = Some(adapter_info.backend);
+        render_ctx.snap_text = self.snap_text;
+        render_ctx.debug = self.debug;
+        render_ctx.show_fps = self.show_fps;
+        if !self.font_fallbacks.is_empty() {
+            let families: Vec<&str> = self.font_fallbacks.iter().map(String::as_str).collect();
+            render_ctx.set_font_fallbacks(&families);
+        }
+        if let Background::Image { width, height, rgba } = &self.background {
+            render_ctx.set_background_image(*width, *height, rgba);
+        }
+        if let Some(source) = &self.post_process {
+            render_ctx.set_post_process(Some(source)).map_err(AppError::PostProcess)?;
+        }
+
+        if !self.menus.is_empty() {
+            self.menu_bar = Some(MenuBar::install(&self.menus, &window));
+        }
+        report_monitor(self.monitor.as_ref(), &window);
+        self.gamepads = Gamepads::new();
+
+        // Set only now, so building an app doesn't change the direction for others on the thread
+        if let Some(direction) = self.text_direction {
+            layout::set_text_direction(direction);
+        }
+        self.view.on_init();
+        self.view.on_mount();
+
+        let mut layout_ctx = LayoutContext::new();
+        layout_ctx.ui_scale = self.ui_scale;
+        layout_ctx.text_shaping = self.text_shaping;
+        layout_ctx.base_font_size = self.base_font_size;
+        if let Some((width, height)) = self.min_size {
+            layout_ctx.min_window_size = taffy::prelude::Size { width, height };
+        }
+        let root_node = Self::update_layout(&mut self.view, &mut layout_ctx, window.inner_size(), &mut render_ctx.font_system)?;
+
+        self.state = AppState::Running {
+            window,
+            surface,
+            adapter,
+            render_ctx,
+            cursor_pos: (0.0, 0.0),
+            layout_ctx,
+            root_node: Some(root_node),
+        };
+        self.dirty = true;
+        Ok(())
+    }
+
+    /// Runs the event loop until the window is closed.
+    ///
+    /// Returns an error instead of panicking when the window, GPU adapter or device
+    /// cannot be created, e.g. on a headless machine without a usable GPU.
+    ///
+    /// Apps that create the event loop themselves can pass the `App` to
+    /// `EventLoop::run_app` instead, since it is the loop's `ApplicationHandler`, and check
+    /// `take_error` afterwards. Closing the window exits that loop.
+    pub fn run(mut self) -> Result<(), AppError> {
         tracing_subscriber::fmt::init();
-        let event_loop = EventLoop::new().unwrap();
+        let event_loop = EventLoop::new()?;
         event_loop.set_control_flow(ControlFlow::Wait);
-        event_loop.run_app(&mut self).unwrap();
+        event_loop.run_app(&mut self)?;
+        match self.error.take() {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    /// The error that stopped the app, when the host drives the event loop (see `run`).
+    pub fn take_error(&mut self) -> Option<AppError> {
+        self.error.take()
+    }
+
+    /// Handles the events pending on `event_loop`, waiting up to `timeout` for the first
+    /// one, then returns, for hosts running their own loop that tick NoxKit once per
+    /// iteration. The window opens on the first call.
+    ///
+    /// Returns `Ok(false)` once the window was closed. Not available on the web or iOS,
+    /// where the platform owns the loop.
+    #[cfg(any(
+        windows,
+        target_os = "macos",
+        target_os = "android",
+        target_os = "linux",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd",
+    ))]
+    pub fn pump_events(&mut self, event_loop: &mut EventLoop<()>, timeout: Option<std::time::Duration>) -> Result<bool, AppError> {
+        use winit::platform::pump_events::{EventLoopExtPumpEvents, PumpStatus};
+        let status = event_loop.pump_app_events(timeout, self);
+        if let Some(err) = self.error.take() {
+            return Err(err);
+        }
+        Ok(matches!(status, PumpStatus::Continue))
     }
 }
 
-impl ApplicationHandler for App {
-    fn resumed(&mut self, event_loop: &ActiveEventLoop) {
-        if let AppState::Idle = self.state {
-            let window = Arc::new(event_loop.create_window(Window::default_attributes()).unwrap());
-            
-            let instance = wgpu::Instance::default();
-            let surface = instance.create_surface(window.clone()).unwrap();
-            
-            let adapter = pollster::block_on(instance.request_adapter(
-                &wgpu::RequestAdapterOptions {
-                    power_preference: wgpu::PowerPreference::default(),
-                    compatible_surface: Some(&surface),
-                    force_fallback_adapter: false,
-                }
-            )).expect("Failed to find an appropriate adapter");
-
-            let (device, queue) = pollster::block_on(adapter.request_device(
-                &wgpu::DeviceDescriptor {
-                    label: None,
-                    required_features: wgpu::Features::empty(),
-                    required_limits: wgpu::Limits::default(),
-                    ..Default::default()
-                }
-            )).expect("Failed to create device");
+/// Sends `MouseLeave`/`MouseEnter` for the nodes the pointer stopped/started being over.
+/// `cursor` is `None` once the pointer has left the window.
+pub(crate) fn dispatch_hover(
+    view: &mut dyn View,
+    layout_ctx: &LayoutContext,
+    root_node: Option<taffy::prelude::NodeId>,
+    hovered: &mut Vec<taffy::prelude::NodeId>,
+    cursor: Option<(f32, f32)>,
+    root_geometry: Geometry,
+) {
+    let now = match (root_node, cursor) {
+        (Some(root), Some((x, y))) => layout_ctx.nodes_at(root, x, y),
+        _ => Vec::new(),
+    };
+    let (x, y) = cursor.unwrap_or((f32::NEG_INFINITY, f32::NEG_INFINITY));
+    // Innermost first when leaving, outermost first when entering, like the DOM
+    for &node in hovered.iter().rev().filter(|node| !now.contains(node)) {
+        view.handle_event(&Event::MouseLeave { node, x, y }, layout_ctx, root_geometry);
+    }
+    for &node in now.iter().filter(|node| !hovered.contains(node)) {
+        view.handle_event(&Event::MouseEnter { node, x, y }, layout_ctx, root_geometry);
+    }
+    *hovered = now;
+}
 
-            let config = surface.get_default_config(&adapter, window.inner_size().width, window.inner_size().height).unwrap();
-            surface.configure(&device, &config);
+/// The debug name of the innermost named node in `hovered`, the nodes under the pointer.
+pub(crate) fn hovered_name(layout_ctx: &LayoutContext, hovered: &[taffy::prelude::NodeId]) -> Option<String> {
+    hovered.iter().rev().find_map(|&node| layout_ctx.debug_name(node)).map(str::to_string)
+}
 
-            let render_ctx = RenderContext::new(device, queue, &config);
+/// Updates `signal` from `hovered` if the widget under the pointer changed.
+fn report_monitor(signal: Option<&Signal<Option<MonitorInfo>>>, window: &Window) {
+    let Some(signal) = signal else { return };
+    let monitor = window.current_monitor().map(|monitor| MonitorInfo::new(&monitor));
+    if *signal.borrow() != monitor {
+        signal.set(monitor);
+    }
+}
 
-            self.view.on_init();
-            self.view.on_mount();
+fn report_hovered(signal: Option<&Signal<Option<String>>>, layout_ctx: &LayoutContext, hovered: &[taffy::prelude::NodeId]) {
+    let Some(signal) = signal else { return };
+    let name = hovered_name(layout_ctx, hovered);
+    if *signal.borrow() != name {
+        signal.set(name);
+    }
+}
 
-            let mut layout_ctx = LayoutContext::new();
-            let root_node = Self::update_layout(&mut self.view, &mut layout_ctx, window.inner_size());
+/// Moves focus to the nearest focusable widget for d-pad presses, sending `FocusMoved`.
+/// Call after delivering `event` itself.
+pub(crate) fn dispatch_focus_navigation(view: &mut dyn View, layout_ctx: &LayoutContext, event: &Event, root_geometry: Geometry) {
+    let Event::GamepadButton { button, pressed: true } = *event else { return };
+    let direction = match button {
+        GamepadButton::DPadUp => FocusDirection::Up,
+        GamepadButton::DPadDown => FocusDirection::Down,
+        GamepadButton::DPadLeft => FocusDirection::Left,
+        GamepadButton::DPadRight => FocusDirection::Right,
+        _ => return,
+    };
+    if let Some(node) = layout_ctx.next_focus(direction) {
+        layout_ctx.set_focused(Some(node));
+        view.handle_event(&Event::FocusMoved { node }, layout_ctx, root_geometry);
+    }
+}
 
-            self.state = AppState::Running {
-                window,
-                surface,
-                adapter,
-                render_ctx,
-                cursor_pos: (0.0, 0.0),
-                layout_ctx,
-                root_node: Some(root_node),
-            };
-            self.dirty = true;
+/// The window's area in logical pixels, i.e. divided by the UI scale.
+fn window_geometry(window: &Window, ui_scale: f32) -> Geometry {
+    let size = window.inner_size();
+    Geometry {
+        x: 0.0,
+        y: 0.0,
+        width: size.width as f32 / ui_scale,
+        height: size.height as f32 / ui_scale,
+    }
+}
+
+/// How long the event loop may sleep: until the next event unless something is animating
+/// (`Poll`, redrawing every frame) or waiting on one of the `deadlines`, e.g. a timed redraw.
+/// Runs `request` without, then with `force_fallback_adapter` (e.g. a software renderer)
+/// if the first attempt fails. Returns the fallback's error when both fail.
+fn with_fallback<T, E: std::fmt::Display>(mut request: impl FnMut(bool) -> Result<T, E>) -> Result<T, E> {
+    request(false).or_else(|err| {
+        tracing::warn!("No adapter matched the requested options ({}), trying the fallback adapter", err);
+        request(true)
+    })
+}
+
+fn control_flow(animating: bool, deadlines: impl IntoIterator<Item = std::time::Instant>) -> ControlFlow {
+    if animating {
+        ControlFlow::Poll
+    } else if let Some(deadline) = deadlines.into_iter().min() {
+        ControlFlow::WaitUntil(deadline)
+    } else {
+        ControlFlow::Wait
+    }
+}
+
+fn translate_touch_phase(phase: winit::event::TouchPhase) -> TouchPhase {
+    match phase {
+        winit::event::TouchPhase::Started => TouchPhase::Started,
+        winit::event::TouchPhase::Moved => TouchPhase::Moved,
+        winit::event::TouchPhase::Ended => TouchPhase::Ended,
+        winit::event::TouchPhase::Cancelled => TouchPhase::Cancelled,
+    }
+}
+
+/// Translates a winit key press into NoxKit keys. Text input may yield several characters.
+fn translate_keys(event: &KeyEvent, modifiers: Modifiers) -> Vec<Key> {
+    let named = match &event.logical_key {
+        WinitKey::Named(NamedKey::Enter) => Some(Key::Enter),
+        WinitKey::Named(NamedKey::Tab) => Some(Key::Tab),
+        WinitKey::Named(NamedKey::Backspace) => Some(Key::Backspace),
+        WinitKey::Named(NamedKey::Delete) => Some(Key::Delete),
+        WinitKey::Named(NamedKey::Escape) => Some(Key::Escape),
+        WinitKey::Named(NamedKey::ArrowLeft) => Some(Key::ArrowLeft),
+        WinitKey::Named(NamedKey::ArrowRight) => Some(Key::ArrowRight),
+        WinitKey::Named(NamedKey::ArrowUp) => Some(Key::ArrowUp),
+        WinitKey::Named(NamedKey::ArrowDown) => Some(Key::ArrowDown),
+        WinitKey::Named(NamedKey::Home) => Some(Key::Home),
+        WinitKey::Named(NamedKey::End) => Some(Key::End),
+        WinitKey::Named(NamedKey::PageUp) => Some(Key::PageUp),
+        WinitKey::Named(NamedKey::PageDown) => Some(Key::PageDown),
+        _ => None,
+    };
+    if let Some(key) = named {
+        return vec![key];
+    }
+
+    // With Ctrl or Cmd held `text` may be a control character, so fall back to the logical key
+    let text = if modifiers.ctrl || modifiers.logo { event.logical_key.to_text() } else { event.text.as_deref() };
+    text.map(|text| text.chars().filter(|c| !c.is_control()).map(Key::Character).collect())
+        .unwrap_or_default()
+}
+
+impl ApplicationHandler for App {
+    fn resumed(&mut self, event_loop: &ActiveEventLoop) {
+        if let AppState::Idle = self.state
+            && let Err(err) = self.start(event_loop)
+        {
+            tracing::error!("{}", err);
+            self.error = Some(err);
+            event_loop.exit();
         }
     }
 
     fn window_event(&mut self, event_loop: &ActiveEventLoop, _id: WindowId, event: WindowEvent) {
+        if let Some(hook) = &mut self.window_event_hook
+            && hook(&event)
+        {
+            return;
+        }
+
         match event {
             WindowEvent::CloseRequested => {
                 event_loop.exit();
             }
             WindowEvent::Resized(size) => {
-                if let AppState::Running { surface, adapter, render_ctx, layout_ctx, root_node, .. } = &mut self.state {
-                    if size.width > 0 && size.height > 0 {
-                        let config = surface.get_default_config(adapter, size.width, size.height).unwrap();
-                        surface.configure(&render_ctx.device, &config);
-                        *root_node = Some(Self::update_layout(&mut self.view, layout_ctx, size));
-                        self.dirty = true;
+                if let AppState::Running { window, surface, adapter, render_ctx, layout_ctx, root_node, cursor_pos } = &mut self.state
+                    && size.width > 0 && size.height > 0
+                {
+                    let config = match Self::surface_config(surface, adapter, size, self.present_mode) {
+                        Ok(config) => config,
+                        Err(err) => {
+                            tracing::error!("{}", err);
+                            self.error = Some(err);
+                            event_loop.exit();
+                            return;
+                        }
+                    };
+                    surface.configure(&render_ctx.device, &config);
+                    render_ctx.stats.present_mode = config.present_mode;
+                    match Self::update_layout(&mut self.view, layout_ctx, size, &mut render_ctx.font_system) {
+                        Ok(node) => {
+                            *root_node = Some(node);
+                            self.view.on_resize(layout_ctx.window_size.width, layout_ctx.window_size.height);
+                            // Widgets may have moved under a still pointer
+                            dispatch_hover(self.view.as_mut(), layout_ctx, *root_node, &mut self.hovered_nodes, Some(*cursor_pos), window_geometry(window, self.ui_scale));
+                            report_hovered(self.hovered_widget.as_ref(), layout_ctx, &self.hovered_nodes);
+                            // Maximizing or snapping can land the window on another monitor
+                            report_monitor(self.monitor.as_ref(), window);
+                        }
+                        Err(err) => {
+                            tracing::error!("{}", err);
+                            self.error = Some(err.into());
+                            event_loop.exit();
+                            return;
+                        }
                     }
+                    self.dirty = true;
                 }
             }
             WindowEvent::CursorMoved { position, .. } => {
-                if let AppState::Running { window, cursor_pos, layout_ctx, .. } = &mut self.state {
-                    *cursor_pos = (position.x as f32, position.y as f32);
-                    let size = window.inner_size();
-                    let root_geometry = Geometry {
-                        x: 0.0,
-                        y: 0.0,
-                        width: size.width as f32,
-                        height: size.height as f32,
-                    };
+                if let AppState::Running { window, cursor_pos, layout_ctx, root_node, .. } = &mut self.state {
+                    *cursor_pos = (position.x as f32 / self.ui_scale, position.y as f32 / self.ui_scale);
+                    let root_geometry = window_geometry(window, self.ui_scale);
                     
+                    self.gestures.moved(cursor_pos.0, cursor_pos.1);
                     let ev = Event::MouseMove { x: cursor_pos.0, y: cursor_pos.1 };
-                    self.view.handle_event(&ev, &layout_ctx, root_geometry);
+                    self.view.handle_event(&ev, layout_ctx, root_geometry);
+                    dispatch_hover(self.view.as_mut(), layout_ctx, *root_node, &mut self.hovered_nodes, Some(*cursor_pos), root_geometry);
+                    report_hovered(self.hovered_widget.as_ref(), layout_ctx, &self.hovered_nodes);
+                    if self.hovering_file {
+                        let ev = Event::FileHovered { x: cursor_pos.0, y: cursor_pos.1 };
+                        self.view.handle_event(&ev, layout_ctx, root_geometry);
+                    }
                     
                     self.dirty = true;
                     window.request_redraw();
                 }
             }
+            WindowEvent::Moved(_) | WindowEvent::ScaleFactorChanged { .. } => {
+                if let AppState::Running { window, .. } = &self.state {
+                    report_monitor(self.monitor.as_ref(), window);
+                }
+            }
+            WindowEvent::Focused(focused) => {
+                if let AppState::Running { window, layout_ctx, .. } = &mut self.state {
+                    // The button may come up in another window, where we won't see it
+                    self.gestures.release();
+                    let ev = Event::WindowFocus { focused };
+                    self.view.handle_event(&ev, layout_ctx, window_geometry(window, self.ui_scale));
+
+                    self.dirty = true;
+                    window.request_redraw();
+                }
+            }
+            WindowEvent::CursorLeft { .. } => {
+                if let AppState::Running { window, layout_ctx, root_node, .. } = &mut self.state {
+                    self.gestures.release();
+                    dispatch_hover(self.view.as_mut(), layout_ctx, *root_node, &mut self.hovered_nodes, None, window_geometry(window, self.ui_scale));
+                    report_hovered(self.hovered_widget.as_ref(), layout_ctx, &self.hovered_nodes);
+
+                    self.dirty = true;
+                    window.request_redraw();
+                }
+            }
             WindowEvent::MouseInput { state, button: MouseButton::Left, .. } => {
                 if let AppState::Running { window, cursor_pos, layout_ctx, .. } = &mut self.state {
-                    let size = window.inner_size();
-                    let root_geometry = Geometry {
-                        x: 0.0,
-                        y: 0.0,
-                        width: size.width as f32,
-                        height: size.height as f32,
-                    };
+                    let root_geometry = window_geometry(window, self.ui_scale);
 
                     let ev = if let ElementState::Pressed = state {
                         Event::MouseDown { x: cursor_pos.0, y: cursor_pos.1 }
                     } else {
                         Event::MouseUp { x: cursor_pos.0, y: cursor_pos.1 }
                     };
-                    self.view.handle_event(&ev, &layout_ctx, root_geometry);
-                    
+                    // Snackbars sit on top, so clicks on them don't reach the views below
+                    if !self.snackbars.handle_event(&ev) {
+                        self.view.handle_event(&ev, layout_ctx, root_geometry);
+                    }
+
                     if let ElementState::Pressed = state {
                         let ev_click = Event::MouseClick { x: cursor_pos.0, y: cursor_pos.1 };
-                        self.view.handle_event(&ev_click, &layout_ctx, root_geometry);
+                        if !self.snackbars.handle_event(&ev_click) {
+                            self.view.handle_event(&ev_click, layout_ctx, root_geometry);
+                            if let Some(ev) = self.gestures.press(cursor_pos.0, cursor_pos.1, std::time::Instant::now()) {
+                                self.view.handle_event(&ev, layout_ctx, root_geometry);
+                            }
+                        }
+                    } else {
+                        self.gestures.release();
                     }
                     
                     self.dirty = true;
                     window.request_redraw();
                 }
             }
+            WindowEvent::MouseWheel { delta, .. } => {
+                if let AppState::Running { window, cursor_pos, layout_ctx, .. } = &mut self.state {
+                    // winit deltas are positive when scrolling up/left, which moves the offset back
+                    let (dx, dy) = match delta {
+                        MouseScrollDelta::LineDelta(x, y) => (-x * SCROLL_LINE_HEIGHT, -y * SCROLL_LINE_HEIGHT),
+                        MouseScrollDelta::PixelDelta(position) => (-position.x as f32 / self.ui_scale, -position.y as f32 / self.ui_scale),
+                    };
+                    let ev = Event::Scroll { dx, dy, x: cursor_pos.0, y: cursor_pos.1 };
+                    self.view.handle_event(&ev, layout_ctx, window_geometry(window, self.ui_scale));
+
+                    self.dirty = true;
+                    window.request_redraw();
+                }
+            }
+            WindowEvent::Touch(Touch { id, phase, location, .. }) => {
+                if let AppState::Running { window, layout_ctx, .. } = &mut self.state {
+                    let (x, y) = (location.x as f32 / self.ui_scale, location.y as f32 / self.ui_scale);
+                    let ev = Event::Touch { id, phase: translate_touch_phase(phase), x, y };
+                    self.view.handle_event(&ev, layout_ctx, window_geometry(window, self.ui_scale));
+
+                    self.dirty = true;
+                    window.request_redraw();
+                }
+            }
+            WindowEvent::PinchGesture { delta, .. } => {
+                if let AppState::Running { window, cursor_pos, layout_ctx, .. } = &mut self.state {
+                    // winit reports the change in magnification; 0 means no zoom
+                    let ev = Event::Pinch { scale: (1.0 + delta as f32).max(0.01), center: *cursor_pos };
+                    self.view.handle_event(&ev, layout_ctx, window_geometry(window, self.ui_scale));
+
+                    self.dirty = true;
+                    window.request_redraw();
+                }
+            }
+            WindowEvent::ModifiersChanged(modifiers) => {
+                let state = modifiers.state();
+                self.modifiers = Modifiers {
+                    shift: state.shift_key(),
+                    ctrl: state.control_key(),
+                    alt: state.alt_key(),
+                    logo: state.super_key(),
+                };
+                if let AppState::Running { window, layout_ctx, .. } = &mut self.state {
+                    let ev = Event::ModifiersChanged(self.modifiers);
+                    self.view.handle_event(&ev, layout_ctx, window_geometry(window, self.ui_scale));
+                }
+            }
+            WindowEvent::KeyboardInput { event, .. } => {
+                if let AppState::Running { window, layout_ctx, .. } = &mut self.state
+                    && event.state == ElementState::Pressed
+                {
+                    let root_geometry = window_geometry(window, self.ui_scale);
+                    for key in translate_keys(&event, self.modifiers) {
+                        let ev = Event::KeyDown { key, modifiers: self.modifiers };
+                        self.view.handle_event(&ev, layout_ctx, root_geometry);
+                    }
+
+                    self.dirty = true;
+                    window.request_redraw();
+                }
+            }
+            WindowEvent::Ime(ime) => {
+                if let AppState::Running { window, layout_ctx, .. } = &mut self.state {
+                    let ev = match ime {
+                        Ime::Preedit(text, cursor) => Event::ImePreedit { text, cursor },
+                        Ime::Commit(text) => Event::ImeCommit(text),
+                        Ime::Enabled | Ime::Disabled => return,
+                    };
+                    self.view.handle_event(&ev, layout_ctx, window_geometry(window, self.ui_scale));
+
+                    self.dirty = true;
+                    window.request_redraw();
+                }
+            }
+            WindowEvent::HoveredFile(_) => {
+                // Sent once per dragged file; views only need to hear about the gesture once
+                if let AppState::Running { window, cursor_pos, layout_ctx, .. } = &mut self.state
+                    && !self.hovering_file
+                {
+                    self.hovering_file = true;
+                    let ev = Event::FileHovered { x: cursor_pos.0, y: cursor_pos.1 };
+                    self.view.handle_event(&ev, layout_ctx, window_geometry(window, self.ui_scale));
+
+                    self.dirty = true;
+                    window.request_redraw();
+                }
+            }
+            WindowEvent::HoveredFileCancelled => {
+                if let AppState::Running { window, layout_ctx, .. } = &mut self.state {
+                    self.hovering_file = false;
+                    self.view.handle_event(&Event::FileHoverCancelled, layout_ctx, window_geometry(window, self.ui_scale));
+
+                    self.dirty = true;
+                    window.request_redraw();
+                }
+            }
+            WindowEvent::DroppedFile(path) => {
+                // Dispatched as one `FileDropped` in `about_to_wait`, once the whole drop has arrived
+                self.hovering_file = false;
+                self.dropped_files.push(path);
+            }
             WindowEvent::RedrawRequested => {
                 if !self.dirty { return; }
-                if let AppState::Running { window, surface, render_ctx, layout_ctx, .. } = &mut self.state {
+                self.take_rebuilt_view();
+                if let AppState::Running { window, surface, render_ctx, layout_ctx, root_node, .. } = &mut self.state {
                     let size = window.inner_size();
-                    let root_geometry = Geometry {
-                        x: 0.0,
-                        y: 0.0,
-                        width: size.width as f32,
-                        height: size.height as f32,
-                    };
+                    let root_geometry = window_geometry(window, self.ui_scale);
 
                     // 1. Lifecycle Update
                     self.view.on_update();
 
+                    if layout::take_layout_request() {
+                        layout_ctx.invalidate();
+                        match Self::update_layout(&mut self.view, layout_ctx, size, &mut render_ctx.font_system) {
+                            Ok(node) => *root_node = Some(node),
+                            Err(err) => {
+                                tracing::error!("{}", err);
+                                self.error = Some(err.into());
+                                event_loop.exit();
+                                return;
+                            }
+                        }
+                    }
+
                     // FPS calculation
                     let now = std::time::Instant::now();
                     let dt = now.duration_since(self.last_frame).as_secs_f32();
@@ -189,25 +1056,75 @@ impl ApplicationHandler for App {
                     if dt > 0.0 {
                         self.fps = 0.9 * self.fps + 0.1 * (1.0 / dt);
                     }
+                    render_ctx.stats.fps = self.fps;
+                    render_ctx.stats.frame_time_ms = dt * 1000.0;
 
                     // 2. Clear render queue
                     render_ctx.render_queue.clear();
+                    render_ctx.stats.shaped_texts = 0;
+                    render_ctx.stats.built_attrs = 0;
+
+                    if let Background::LinearGradient { from, to, angle } = self.background {
+                        let previous_z = render_ctx.render_queue.set_z_index(i32::MIN);
+                        render_ctx.render_queue.push_linear_gradient(root_geometry, from, to, angle);
+                        render_ctx.render_queue.set_z_index(previous_z);
+                    }
 
                     // 3. Prepare (Collect primitives and text)
-                    self.view.prepare(render_ctx, &layout_ctx, root_geometry);
+                    self.view.prepare(render_ctx, layout_ctx, root_geometry);
+                    let previous_z = render_ctx.render_queue.set_z_index(i32::MAX);
+                    self.snackbars.prepare(render_ctx, root_geometry);
+                    render_ctx.render_queue.set_z_index(previous_z);
+                    render_ctx.render_queue.sort_by_z();
+                    let previously_dropped = render_ctx.stats.dropped_primitives;
+                    render_ctx.stats.primitives = render_ctx.render_queue.len();
+                    render_ctx.stats.dropped_primitives = render_ctx.render_queue.dropped();
+                    render_ctx.stats.vertices = render_ctx.render_queue.vertices.len().min(MAX_VERTICES);
+                    render_ctx.stats.indices = render_ctx.render_queue.indices.len().min(MAX_INDICES);
+                    if render_ctx.render_queue.is_over_capacity() {
+                        // Only log when the overflow changes so a steady overflow doesn't spam every frame
+                        if render_ctx.render_queue.dropped() != previously_dropped {
+                            tracing::warn!(
+                                "Render queue overflowed: {} primitives skipped (capacity {})",
+                                render_ctx.render_queue.dropped(),
+                                render_ctx.render_queue.capacity(),
+                            );
+                        }
+                        if let Some(hook) = &mut self.overflow_hook {
+                            hook(&render_ctx.stats);
+                        }
+                    }
 
-                    let mut text_areas = Vec::new();
-                    self.view.collect_text_areas(&layout_ctx, root_geometry, &mut text_areas);
+                    // Only accept IME input while a text field has focus
+                    let ime_area = render_ctx.take_ime_cursor_area();
+                    if ime_area.is_some() != self.ime_allowed {
+                        self.ime_allowed = ime_area.is_some();
+                        window.set_ime_allowed(self.ime_allowed);
+                    }
+                    if let Some(area) = ime_area {
+                        let scale = self.ui_scale;
+                        window.set_ime_cursor_area(
+                            winit::dpi::PhysicalPosition::new(area.x * scale, area.y * scale),
+                            winit::dpi::PhysicalSize::new(area.width * scale, area.height * scale),
+                        );
+                    }
 
-                    // Add FPS debug text
-                    if render_ctx.debug {
+                    let mut text_areas = render_ctx.take_text_areas();
+                    self.view.collect_text_areas(layout_ctx, root_geometry, &mut text_areas);
+                    self.snackbars.collect_text_areas(&mut text_areas);
+                    render_ctx.stats.text_areas = text_areas.len();
+                    scale_text_areas(&mut text_areas, self.ui_scale, render_ctx.snap_text);
+
+                    // Add FPS debug text. The buffer leaves the context while its area borrows it
+                    let mut debug_buffer = std::mem::replace(&mut render_ctx.debug_buffer, glyphon::Buffer::new_empty(glyphon::Metrics::new(14.0, 20.0)));
+                    if render_ctx.show_fps {
                         let fps_text = format!("FPS: {:.1}", self.fps);
-                        render_ctx.debug_buffer.set_text(&mut render_ctx.font_system, &fps_text, &glyphon::Attrs::new().family(glyphon::Family::Monospace).color(glyphon::Color::rgb(0, 255, 0)), glyphon::Shaping::Advanced);
-                        render_ctx.debug_buffer.set_size(&mut render_ctx.font_system, Some(100.0), Some(20.0));
-                        render_ctx.debug_buffer.shape_until_scroll(&mut render_ctx.font_system, false);
+                        debug_buffer.set_text(&mut render_ctx.font_system, &fps_text, &glyphon::Attrs::new().family(glyphon::Family::Monospace).color(glyphon::Color::rgb(0, 255, 0)), glyphon::Shaping::Advanced);
+                        debug_buffer.set_size(&mut render_ctx.font_system, Some(100.0), Some(20.0));
+                        debug_buffer.shape_until_scroll(&mut render_ctx.font_system, false);
 
                         text_areas.push(glyphon::TextArea {
-                            buffer: &render_ctx.debug_buffer,
+                            buffer: &debug_buffer,
                             left: 10.0,
                             top: 10.0,
                             scale: 1.0,
@@ -227,36 +1144,39 @@ impl ApplicationHandler for App {
                         width: size.width,
                         height: size.height,
                     });
-                    render_ctx.text_renderer.prepare(
-                        &render_ctx.device,
-                        &render_ctx.queue,
-                        &mut render_ctx.font_system,
-                        &mut render_ctx.text_atlas,
-                        &render_ctx.viewport,
-                        text_areas,
-                        &mut render_ctx.swash_cache,
-                    ).unwrap();
+                    render_ctx.prepare_text(text_areas);
+                    render_ctx.debug_buffer = debug_buffer;
 
                     // 5. Render
-                    let projection = Mat4::orthographic_lh(0.0, size.width as f32, size.height as f32, 0.0, -1.0, 1.0);
+                    let projection = Mat4::orthographic_lh(0.0, root_geometry.width, root_geometry.height, 0.0, -1.0, 1.0);
                     render_ctx.queue.write_buffer(&render_ctx.uniform_buffer, 0, bytemuck::cast_slice(&projection.to_cols_array_2d()));
 
+                    render_ctx.update_background(size.width as f32, size.height as f32);
+
+                    // Flat backgrounds only need the clear; the others are drawn over it
+                    let clear = match self.background {
+                        Background::Solid(color) => Color(color).premultiplied(),
+                        _ => [0.0, 0.0, 0.0, 1.0],
+                    };
+
                     let frame = surface.get_current_texture().unwrap();
                     let view = frame.texture.create_view(&wgpu::TextureViewDescriptor::default());
+                    // With a post-process shader the frame is drawn to a texture it reads from
+                    let post_target = render_ctx.post_process_target(size.width, size.height);
                     let mut encoder = render_ctx.device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
                     
                     {
                         let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                             label: None,
                             color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                                view: &view,
+                                view: post_target.as_ref().unwrap_or(&view),
                                 resolve_target: None,
                                 ops: wgpu::Operations {
                                     load: wgpu::LoadOp::Clear(wgpu::Color {
-                                        r: 0.01, // Near-black for modern look
-                                        g: 0.01,
-                                        b: 0.02,
-                                        a: 1.0,
+                                        r: clear[0] as f64,
+                                        g: clear[1] as f64,
+                                        b: clear[2] as f64,
+                                        a: clear[3] as f64,
                                     }),
                                     store: wgpu::StoreOp::Store,
                                 },
@@ -266,43 +1186,190 @@ impl ApplicationHandler for App {
                             occlusion_query_set: None,
                         });
                         
-                        rpass.set_pipeline(&render_ctx.pipeline);
-                        rpass.set_bind_group(0, &render_ctx.bind_group, &[]);
-                        
-                        // Render batched primitives from queue
-                        if !render_ctx.render_queue.vertices.is_empty() {
-                            let v_len = render_ctx.render_queue.vertices.len();
-                            let i_len = render_ctx.render_queue.indices.len();
-                            
-                            // Safety check to avoid write_buffer overflow
-                            let v_data = &render_ctx.render_queue.vertices[..v_len.min(16384)];
-                            let i_data = &render_ctx.render_queue.indices[..i_len.min(24576)];
-
-                            render_ctx.queue.write_buffer(&render_ctx.vertex_buffer, 0, bytemuck::cast_slice(v_data));
-                            render_ctx.queue.write_buffer(&render_ctx.index_buffer, 0, bytemuck::cast_slice(i_data));
-                            
-                            rpass.set_vertex_buffer(0, render_ctx.vertex_buffer.slice(..));
-                            rpass.set_index_buffer(render_ctx.index_buffer.slice(..), wgpu::IndexFormat::Uint16);
-                            rpass.draw_indexed(0..i_data.len() as u32, 0, 0..1);
-                        }
-
-                        // Render widgets (for nested renders if any, though most now use queue)
-                        self.view.render(render_ctx, &mut rpass, root_geometry);
-
-                        // Render text
-                        render_ctx.text_renderer.render(&render_ctx.text_atlas, &render_ctx.viewport, &mut rpass).unwrap();
+                        render_ctx.draw(&mut rpass, self.view.as_ref(), root_geometry);
+                    }
+                    if post_target.is_some() {
+                        render_ctx.apply_post_process(&mut encoder, &view, wgpu::LoadOp::Clear(wgpu::Color::BLACK));
                     }
 
                     render_ctx.queue.submit(Some(encoder.finish()));
                     frame.present();
                     self.dirty = false;
+
+                    // Keep drawing while widgets are animating; `about_to_wait` picks the control flow
+                    self.animating = render_ctx.take_redraw_request();
+                    self.wakeup = render_ctx.take_redraw_at();
+                    if self.animating {
+                        self.dirty = true;
+                    }
                 }
             }
             _ => (),
         }
     }
 
+    fn new_events(&mut self, _event_loop: &ActiveEventLoop, cause: StartCause) {
+        // A timed redraw is due, rather than a controller poll
+        if let StartCause::ResumeTimeReached { .. } = cause
+            && let AppState::Running { window, .. } = &self.state
+            && self.wakeup.is_some_and(|wakeup| wakeup <= std::time::Instant::now())
+        {
+            self.wakeup = None;
+            self.dirty = true;
+            window.request_redraw();
+        }
+    }
+
+    fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
+        let AppState::Running { window, cursor_pos, layout_ctx, .. } = &mut self.state else { return };
+        // Before commands, so handlers can quit or retitle the window right away
+        if self.menu_bar.as_ref().is_some_and(MenuBar::dispatch) {
+            self.dirty = true;
+            window.request_redraw();
+        }
+        for command in Commands::new().take() {
+            match command {
+                Command::Exit => event_loop.exit(),
+                Command::SetTitle(title) => window.set_title(&title),
+                Command::RequestRedraw => {
+                    self.dirty = true;
+                    window.request_redraw();
+                }
+                Command::SetRoot(view) => {
+                    Self::replace_view(&mut self.view, &mut self.hovered_nodes, view);
+                    self.dirty = true;
+                    window.request_redraw();
+                }
+            }
+        }
+        if let Some(gamepads) = &mut self.gamepads {
+            let root_geometry = window_geometry(window, self.ui_scale);
+            for event in gamepads.poll() {
+                self.view.handle_event(&event, layout_ctx, root_geometry);
+                dispatch_focus_navigation(self.view.as_mut(), layout_ctx, &event, root_geometry);
+                self.dirty = true;
+                window.request_redraw();
+            }
+        }
+        if let Some(ev) = self.gestures.poll(std::time::Instant::now()) {
+            self.view.handle_event(&ev, layout_ctx, window_geometry(window, self.ui_scale));
+            self.dirty = true;
+            window.request_redraw();
+        }
+        if !self.dropped_files.is_empty() {
+            let ev = Event::FileDropped {
+                paths: std::mem::take(&mut self.dropped_files),
+                x: cursor_pos.0,
+                y: cursor_pos.1,
+            };
+            self.view.handle_event(&ev, layout_ctx, window_geometry(window, self.ui_scale));
+
+            self.dirty = true;
+            window.request_redraw();
+        }
+
+        // A signal changed outside of input handling, e.g. while preparing the last frame
+        if self.rebuilt.as_ref().is_some_and(|rebuilt| rebuilt.borrow().is_some()) {
+            self.dirty = true;
+            window.request_redraw();
+        }
+
+        let gamepad_poll = self.gamepads.as_ref().map(|gamepads| std::time::Instant::now() + gamepads.poll_interval());
+        let control_flow = control_flow(self.animating, self.wakeup.into_iter().chain(gamepad_poll).chain(self.gestures.deadline()));
+        if control_flow == ControlFlow::Poll {
+            window.request_redraw();
+        }
+        event_loop.set_control_flow(control_flow);
+    }
+
     fn exiting(&mut self, _event_loop: &ActiveEventLoop) {
         self.view.on_unmount();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{create_computed, create_signal};
+    use crate::widgets::{Column, Text, TextInput};
+
+    #[test]
+    fn rebuilds_release_the_previous_tree_subscriptions() {
+        let count = create_signal(0);
+        let name = create_signal(String::from("Ada"));
+        let color = create_computed(|| [1.0, 1.0, 1.0, 1.0]);
+        let _app = App::with_render_fn({
+            let (count, name, color) = (count.clone(), name.clone(), color.clone());
+            move || {
+                Box::new(Column::new(vec![
+                    Box::new(Text::new(format!("{}", count.get())).bind_color(color.clone())),
+                    Box::new(TextInput::bind(name.clone())),
+                ]))
+            }
+        });
+        let (name_subscribers, color_subscribers) = (name.subscriber_count(), color.subscriber_count());
+        for n in 1..=100 {
+            count.set(n);
+        }
+        assert_eq!(name.subscriber_count(), name_subscribers);
+        assert_eq!(color.subscriber_count(), color_subscribers);
+        assert_eq!(count.subscriber_count(), 1);
+    }
+
+    #[test]
+    fn window_attributes_carry_the_min_size_in_physical_pixels() {
+        let app = App::new(Box::new(Text::new("Hello")));
+        assert_eq!(app.window_attributes().min_inner_size, None);
+
+        let app = App::new(Box::new(Text::new("Hello"))).with_min_size(320.0, 240.5).with_ui_scale(1.5);
+        let min_size = winit::dpi::Size::Physical(winit::dpi::PhysicalSize::new(480, 361));
+        assert_eq!(app.window_attributes().min_inner_size, Some(min_size));
+    }
+
+    #[test]
+    fn idle_apps_sleep_until_the_next_event() {
+        assert_eq!(control_flow(false, []), ControlFlow::Wait);
+
+        let now = std::time::Instant::now();
+        let (soon, later) = (now + std::time::Duration::from_millis(16), now + std::time::Duration::from_secs(1));
+        assert_eq!(control_flow(false, [later, soon]), ControlFlow::WaitUntil(soon));
+        assert_eq!(control_flow(true, [soon]), ControlFlow::Poll);
+    }
+
+    #[test]
+    fn instances_without_backends_have_no_adapter() {
+        let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor {
+            backends: wgpu::Backends::empty(),
+            ..Default::default()
+        });
+        let result = App::request_adapter(&instance, None, wgpu::PowerPreference::default());
+        assert!(matches!(result, Err(AppError::NoAdapter(_))));
+    }
+
+    #[test]
+    fn adapter_requests_fall_back_when_the_primary_fails() {
+        let mut attempts = Vec::new();
+        let result = with_fallback(|force_fallback_adapter| {
+            attempts.push(force_fallback_adapter);
+            if force_fallback_adapter { Ok("fallback") } else { Err("no hardware adapter") }
+        });
+        assert_eq!(result, Ok("fallback"));
+        assert_eq!(attempts, [false, true]);
+
+        let mut attempts = Vec::new();
+        let result: Result<(), _> = with_fallback(|force_fallback_adapter| {
+            attempts.push(force_fallback_adapter);
+            Err(if force_fallback_adapter { "no fallback adapter" } else { "no hardware adapter" })
+        });
+        assert_eq!(result, Err("no fallback adapter"));
+        assert_eq!(attempts, [false, true]);
+
+        let mut attempts = 0;
+        let result: Result<_, &str> = with_fallback(|_| {
+            attempts += 1;
+            Ok("hardware")
+        });
+        assert_eq!(result, Ok("hardware"));
+        assert_eq!(attempts, 1);
+    }
+}
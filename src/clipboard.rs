@@ -0,0 +1,39 @@
+use std::cell::RefCell;
+
+thread_local! {
+    /// What was last copied within the app, used where the system clipboard can't be, e.g.
+    /// without the `clipboard` feature or in headless test runs.
+    static LOCAL: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+#[cfg(feature = "clipboard")]
+thread_local! {
+    /// Kept open for the app's lifetime: on X11, copied text disappears with its owner.
+    static SYSTEM: RefCell<Option<arboard::Clipboard>> = RefCell::new(
+        arboard::Clipboard::new().inspect_err(|err| tracing::warn!("The system clipboard is unavailable: {}", err)).ok(),
+    );
+}
+
+/// The text on the clipboard, if it holds any. Reads the system clipboard with the
+/// `clipboard` feature; without it, only text copied within the app with `set_text`.
+pub fn get_text() -> Option<String> {
+    #[cfg(feature = "clipboard")]
+    if let Some(text) = SYSTEM.with(|system| system.borrow_mut().as_mut().map(|clipboard| clipboard.get_text().ok())) {
+        return text;
+    }
+    LOCAL.with(|local| local.borrow().clone())
+}
+
+/// Puts `text` on the clipboard, see `get_text`.
+pub fn set_text(text: impl Into<String>) {
+    let text = text.into();
+    #[cfg(feature = "clipboard")]
+    SYSTEM.with(|system| {
+        if let Some(clipboard) = system.borrow_mut().as_mut()
+            && let Err(err) = clipboard.set_text(text.as_str())
+        {
+            tracing::warn!("Failed to copy to the system clipboard: {}", err);
+        }
+    });
+    LOCAL.with(|local| *local.borrow_mut() = Some(text));
+}
@@ -0,0 +1,119 @@
+use crate::view::Event;
+#[cfg(feature = "gamepad")]
+use crate::view::{GamepadAxis, GamepadButton};
+use std::time::Duration;
+
+/// How often controllers are polled while one is connected. gilrs can't wake the event
+/// loop by itself, so input is picked up on a timer.
+#[cfg(feature = "gamepad")]
+const POLL_INTERVAL: Duration = Duration::from_millis(16);
+/// How often to check for a controller being plugged in while none is.
+#[cfg(feature = "gamepad")]
+const CONNECT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The game controllers attached to the machine, read with gilrs.
+#[cfg_attr(not(feature = "gamepad"), allow(dead_code))]
+pub(crate) struct Gamepads {
+    #[cfg(feature = "gamepad")]
+    gilrs: gilrs::Gilrs,
+}
+
+impl Gamepads {
+    /// Starts reading controllers, or returns None if they can't be read on this machine.
+    #[cfg(feature = "gamepad")]
+    pub(crate) fn new() -> Option<Self> {
+        match gilrs::Gilrs::new() {
+            Ok(gilrs) => Some(Self { gilrs }),
+            Err(err) => {
+                tracing::warn!("Game controllers are unavailable: {}", err);
+                None
+            }
+        }
+    }
+
+    #[cfg(not(feature = "gamepad"))]
+    pub(crate) fn new() -> Option<Self> {
+        None
+    }
+
+    /// Input from all controllers since the last call, oldest first.
+    #[cfg(feature = "gamepad")]
+    pub(crate) fn poll(&mut self) -> Vec<Event> {
+        let mut events = Vec::new();
+        while let Some(gilrs::Event { event, .. }) = self.gilrs.next_event() {
+            let event = match event {
+                gilrs::EventType::ButtonPressed(button, _) => {
+                    gamepad_button(button).map(|button| Event::GamepadButton { button, pressed: true })
+                }
+                gilrs::EventType::ButtonReleased(button, _) => {
+                    gamepad_button(button).map(|button| Event::GamepadButton { button, pressed: false })
+                }
+                // Analog triggers report as buttons with a value
+                gilrs::EventType::ButtonChanged(gilrs::Button::LeftTrigger2, value, _) => {
+                    Some(Event::GamepadAxis { axis: GamepadAxis::LeftTrigger, value })
+                }
+                gilrs::EventType::ButtonChanged(gilrs::Button::RightTrigger2, value, _) => {
+                    Some(Event::GamepadAxis { axis: GamepadAxis::RightTrigger, value })
+                }
+                gilrs::EventType::AxisChanged(axis, value, _) => {
+                    gamepad_axis(axis).map(|axis| Event::GamepadAxis { axis, value })
+                }
+                _ => None,
+            };
+            events.extend(event);
+        }
+        events
+    }
+
+    #[cfg(not(feature = "gamepad"))]
+    pub(crate) fn poll(&mut self) -> Vec<Event> {
+        Vec::new()
+    }
+
+    /// How long the event loop may sleep before controllers need polling again.
+    #[cfg(feature = "gamepad")]
+    pub(crate) fn poll_interval(&self) -> Duration {
+        if self.gilrs.gamepads().next().is_some() { POLL_INTERVAL } else { CONNECT_POLL_INTERVAL }
+    }
+
+    #[cfg(not(feature = "gamepad"))]
+    pub(crate) fn poll_interval(&self) -> Duration {
+        Duration::MAX
+    }
+}
+
+#[cfg(feature = "gamepad")]
+fn gamepad_button(button: gilrs::Button) -> Option<GamepadButton> {
+    use gilrs::Button;
+    Some(match button {
+        Button::South => GamepadButton::South,
+        Button::East => GamepadButton::East,
+        Button::North => GamepadButton::North,
+        Button::West => GamepadButton::West,
+        Button::LeftTrigger => GamepadButton::LeftBumper,
+        Button::RightTrigger => GamepadButton::RightBumper,
+        Button::LeftTrigger2 => GamepadButton::LeftTrigger,
+        Button::RightTrigger2 => GamepadButton::RightTrigger,
+        Button::Select => GamepadButton::Select,
+        Button::Start => GamepadButton::Start,
+        Button::LeftThumb => GamepadButton::LeftStick,
+        Button::RightThumb => GamepadButton::RightStick,
+        Button::DPadUp => GamepadButton::DPadUp,
+        Button::DPadDown => GamepadButton::DPadDown,
+        Button::DPadLeft => GamepadButton::DPadLeft,
+        Button::DPadRight => GamepadButton::DPadRight,
+        _ => return None,
+    })
+}
+
+#[cfg(feature = "gamepad")]
+fn gamepad_axis(axis: gilrs::Axis) -> Option<GamepadAxis> {
+    use gilrs::Axis;
+    Some(match axis {
+        Axis::LeftStickX => GamepadAxis::LeftStickX,
+        Axis::LeftStickY => GamepadAxis::LeftStickY,
+        Axis::RightStickX => GamepadAxis::RightStickX,
+        Axis::RightStickY => GamepadAxis::RightStickY,
+        _ => return None,
+    })
+}
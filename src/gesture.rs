@@ -0,0 +1,85 @@
+use crate::view::Event;
+use std::time::{Duration, Instant};
+
+/// Longest time between two clicks for them to count as a double click.
+pub(crate) const DOUBLE_CLICK_TIME: Duration = Duration::from_millis(500);
+/// How long the pointer has to be held down for a long press.
+pub(crate) const LONG_PRESS_TIME: Duration = Duration::from_millis(500);
+/// How far, in layout pixels, the pointer may wander while still counting as the same spot,
+/// for both gestures. Hands shake, and touchpads more so.
+const SLOP: f32 = 4.0;
+
+/// Recognizes double clicks and long presses from the raw mouse button and movement events.
+pub(crate) struct Gestures {
+    pub(crate) double_click_time: Duration,
+    pub(crate) long_press_time: Duration,
+    /// The last click that could start a double click, with where it happened.
+    last_click: Option<(Instant, f32, f32)>,
+    /// The press a long press is waiting on, while the button is held and not moved.
+    held: Option<(Instant, f32, f32)>,
+}
+
+impl Gestures {
+    pub(crate) fn new() -> Self {
+        Self {
+            double_click_time: DOUBLE_CLICK_TIME,
+            long_press_time: LONG_PRESS_TIME,
+            last_click: None,
+            held: None,
+        }
+    }
+
+    /// The button went down at (`x`, `y`): arms the long press timer, and returns a
+    /// `DoubleClick` if this completes one. A third click starts over rather than making
+    /// another double click.
+    pub(crate) fn press(&mut self, x: f32, y: f32, now: Instant) -> Option<Event> {
+        self.held = Some((now, x, y));
+        match self.last_click.take() {
+            Some((at, last_x, last_y))
+                if now.duration_since(at) <= self.double_click_time && near(x, y, last_x, last_y) =>
+            {
+                Some(Event::DoubleClick { x, y })
+            }
+            _ => {
+                self.last_click = Some((now, x, y));
+                None
+            }
+        }
+    }
+
+    /// The pointer moved: moving off the pressed spot cancels the long press.
+    pub(crate) fn moved(&mut self, x: f32, y: f32) {
+        if let Some((_, held_x, held_y)) = self.held
+            && !near(x, y, held_x, held_y)
+        {
+            self.held = None;
+        }
+    }
+
+    /// The button came up, or the pointer left the window: no long press.
+    pub(crate) fn release(&mut self) {
+        self.held = None;
+    }
+
+    /// When the pending long press fires, if one is pending.
+    pub(crate) fn deadline(&self) -> Option<Instant> {
+        self.held.map(|(at, ..)| at + self.long_press_time)
+    }
+
+    /// Returns a `LongPress` once the button has been held long enough, only once per press.
+    pub(crate) fn poll(&mut self, now: Instant) -> Option<Event> {
+        let (_, x, y) = self.held?;
+        if self.deadline().is_some_and(|deadline| now >= deadline) {
+            self.held = None;
+            // Releasing a long press is not the second half of a double click
+            self.last_click = None;
+            Some(Event::LongPress { x, y })
+        } else {
+            None
+        }
+    }
+}
+
+fn near(x: f32, y: f32, other_x: f32, other_y: f32) -> bool {
+    (x - other_x).abs() <= SLOP && (y - other_y).abs() <= SLOP
+}
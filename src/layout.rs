@@ -1,13 +1,188 @@
 use taffy::prelude::*;
+use crate::view::Geometry;
+use crate::render::Transform;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+
+thread_local! {
+    static LAYOUT_REQUESTED: Cell<bool> = const { Cell::new(false) };
+    static TEXT_DIRECTION: Cell<TextDirection> = const { Cell::new(TextDirection::Ltr) };
+}
+
+/// Which way the d-pad moves focus, for `LayoutContext::next_focus`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// Which way rows run and text lines up: left to right, or right to left for languages
+/// like Arabic and Hebrew.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TextDirection {
+    #[default]
+    Ltr,
+    Rtl,
+}
+
+/// Sets the app-wide direction and lays the view tree out again, e.g. when the user
+/// switches language. Containers can override it for their children with `text_direction`.
+pub fn set_text_direction(direction: TextDirection) {
+    TEXT_DIRECTION.set(direction);
+    request_layout();
+}
+
+/// The app-wide direction set with `set_text_direction` or `App::with_text_direction`.
+pub fn text_direction() -> TextDirection {
+    TEXT_DIRECTION.get()
+}
+
+/// Asks the app to lay the view tree out again before the next frame, e.g. after a view
+/// added or removed children. Layout otherwise only happens at startup and on resize.
+pub fn request_layout() {
+    LAYOUT_REQUESTED.set(true);
+}
+
+/// Returns whether `request_layout` was called since the last check and resets the flag.
+pub(crate) fn take_layout_request() -> bool {
+    LAYOUT_REQUESTED.replace(false)
+}
+
+/// A failure while building or computing the layout tree, e.g. a widget referring to a
+/// node that doesn't exist. It propagates out of the whole tree's layout instead of
+/// panicking:
+///
+/// ```
+/// use noxkit::prelude::*;
+/// use noxkit::layout::LayoutContext;
+/// use noxkit::view::Geometry;
+/// use noxkit::testing::TestHarness;
+/// use taffy::prelude::*;
+///
+/// // Asks for a child its leaf doesn't have
+/// struct Broken;
+///
+/// impl View for Broken {
+///     fn layout(&mut self, ctx: &mut LayoutContext) -> Result<NodeId, LayoutError> {
+///         let node = ctx.taffy.new_leaf(Style::default())?;
+///         ctx.taffy.child_at_index(node, 0)?;
+///         Ok(node)
+///     }
+///     fn render<'rp>(&'rp self, _: &'rp noxkit::render::RenderContext, _: &mut wgpu::RenderPass<'rp>, _: Geometry) {}
+///     fn handle_event(&mut self, _: &Event, _: &LayoutContext, _: Geometry) {}
+/// }
+///
+/// let column = Column::default().child(Text::new("Fine")).child(Broken);
+/// let error = TestHarness::new(Box::new(column), 400.0, 300.0).err().unwrap();
+/// assert!(matches!(error.0, taffy::TaffyError::ChildIndexOutOfBounds { child_index: 0, .. }));
+/// ```
+#[derive(Debug)]
+pub struct LayoutError(pub taffy::TaffyError);
+
+impl std::fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Layout failed: {}", self.0)
+    }
+}
+
+impl std::error::Error for LayoutError {}
+
+impl From<taffy::TaffyError> for LayoutError {
+    fn from(err: taffy::TaffyError) -> Self { LayoutError(err) }
+}
+
+/// Text a leaf node is sized to during layout.
+#[derive(Debug, Clone)]
+pub struct TextMeasure {
+    pub text: String,
+    pub font_size: f32,
+    pub line_height: f32,
+    /// Extra space between letters, in em.
+    pub letter_spacing: f32,
+    /// Keeps the text on one line instead of wrapping it to the available width.
+    pub single_line: bool,
+    /// Must match the shaping the text is drawn with, or it may not fit its box.
+    pub shaping: glyphon::Shaping,
+    /// Distance from the top of the box to the first line's baseline, filled in when the
+    /// text is measured. Leave `None`.
+    pub baseline: Option<f32>,
+}
 
 pub struct LayoutContext {
-    pub taffy: TaffyTree<()>,
+    pub taffy: TaffyTree<TextMeasure>,
+    /// Size of the window being laid out, for views that adapt to it (see `Responsive`).
+    pub window_size: Size<f32>,
+    /// Physical pixels per logical pixel, set with `App::with_ui_scale`. Layout happens in
+    /// logical pixels, so views rarely need this.
+    pub ui_scale: f32,
+    /// Layout never gets less space than this, set with `App::with_min_size`.
+    pub min_window_size: Size<f32>,
+    /// How text is shaped unless a widget says otherwise, set with `App::with_text_shaping`.
+    pub text_shaping: glyphon::Shaping,
+    /// Size of body text, set with `App::with_base_font_size`. Widgets size their text
+    /// relative to it unless given an explicit size.
+    pub base_font_size: f32,
+    aspect_fits: Vec<AspectFit>,
+    /// Rows whose children line up on their first baselines, registered with `align_baselines`.
+    baseline_rows: Vec<NodeId>,
+    /// Top margins the children of `baseline_rows` had before they were aligned.
+    baseline_margins: HashMap<NodeId, f32>,
+    /// How scrolling or zooming views move their children on screen, for hit testing.
+    child_transforms: RefCell<HashMap<NodeId, Transform>>,
+    /// Names given to nodes with `set_debug_name`, for `named_geometries` and `dump_tree`.
+    debug_names: HashMap<NodeId, String>,
+    /// Nodes the d-pad can move focus to, registered with `set_focusable`.
+    focusables: Vec<NodeId>,
+    focused: Cell<Option<NodeId>>,
+    /// The root of the last `compute_layout`.
+    root: Option<NodeId>,
+    /// Directions set by the containers currently being laid out, innermost last.
+    directions: Vec<TextDirection>,
+    /// What the current layout was computed for, until `invalidate` or the next `clear`.
+    laid_out_for: Option<LayoutKey>,
+    /// How many times `compute_layout` ran.
+    passes: usize,
+}
+
+/// Everything outside the view tree that a layout depends on.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct LayoutKey {
+    size: (u32, u32),
+    ui_scale: f32,
+    min_window_size: Size<f32>,
+    base_font_size: f32,
+}
+
+/// A `frame` node sized after layout to the largest box of `ratio` that fits in `container`.
+#[derive(Debug, Clone, Copy)]
+struct AspectFit {
+    container: NodeId,
+    frame: NodeId,
+    ratio: f32,
 }
 
 impl Default for LayoutContext {
     fn default() -> Self {
         Self {
             taffy: TaffyTree::new(),
+            window_size: Size::ZERO,
+            ui_scale: 1.0,
+            min_window_size: Size::ZERO,
+            text_shaping: glyphon::Shaping::Advanced,
+            base_font_size: 16.0,
+            aspect_fits: Vec::new(),
+            baseline_rows: Vec::new(),
+            baseline_margins: HashMap::new(),
+            child_transforms: RefCell::new(HashMap::new()),
+            debug_names: HashMap::new(),
+            focusables: Vec::new(),
+            focused: Cell::new(None),
+            root: None,
+            directions: Vec::new(),
+            laid_out_for: None,
+            passes: 0,
         }
     }
 }
@@ -16,4 +191,427 @@ impl LayoutContext {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// `percent`% of the window width, e.g. `length(ctx.vw(50.0))` for half the window.
+    pub fn vw(&self, percent: f32) -> f32 {
+        self.window_size.width * percent / 100.0
+    }
+
+    /// `percent`% of the window height.
+    pub fn vh(&self, percent: f32) -> f32 {
+        self.window_size.height * percent / 100.0
+    }
+
+    /// The direction views being laid out should follow: the innermost container override,
+    /// or the app-wide `text_direction`.
+    pub fn direction(&self) -> TextDirection {
+        self.directions.last().copied().unwrap_or_else(text_direction)
+    }
+
+    /// Runs `f` (typically laying out children) with `direction` in effect, or unchanged for `None`.
+    pub fn with_direction<R>(&mut self, direction: Option<TextDirection>, f: impl FnOnce(&mut Self) -> R) -> R {
+        let Some(direction) = direction else { return f(self) };
+        self.directions.push(direction);
+        let result = f(self);
+        self.directions.pop();
+        result
+    }
+
+    /// Removes every node, ready for the view tree to be laid out again.
+    pub fn clear(&mut self) {
+        self.taffy.clear();
+        self.aspect_fits.clear();
+        self.baseline_rows.clear();
+        self.baseline_margins.clear();
+        self.child_transforms.borrow_mut().clear();
+        self.debug_names.clear();
+        self.focusables.clear();
+        self.focused.set(None);
+        self.root = None;
+        self.directions.clear();
+        self.laid_out_for = None;
+    }
+
+    fn key(&self, size: winit::dpi::PhysicalSize<u32>) -> LayoutKey {
+        LayoutKey {
+            size: (size.width, size.height),
+            ui_scale: self.ui_scale,
+            min_window_size: self.min_window_size,
+            base_font_size: self.base_font_size,
+        }
+    }
+
+    /// The root of the last layout if it was for a window of `size` with the current
+    /// settings and nothing invalidated it since, so laying out again would change nothing.
+    pub(crate) fn current_root(&self, size: winit::dpi::PhysicalSize<u32>) -> Option<NodeId> {
+        self.root.filter(|_| self.laid_out_for == Some(self.key(size)))
+    }
+
+    /// Records that the tree was just laid out for a window of `size`.
+    pub(crate) fn set_laid_out_for(&mut self, size: winit::dpi::PhysicalSize<u32>) {
+        self.laid_out_for = Some(self.key(size));
+    }
+
+    /// Makes the next layout run even if the window and settings haven't changed, e.g.
+    /// because views asked for it with `request_layout`.
+    pub(crate) fn invalidate(&mut self) {
+        self.laid_out_for = None;
+    }
+
+    /// How many times the tree has been laid out. Layout runs at startup, when the window
+    /// size or layout settings change, and after `request_layout`; other redraws reuse it.
+    ///
+    /// ```
+    /// use noxkit::prelude::*;
+    /// use noxkit::testing::TestHarness;
+    ///
+    /// let clicks = create_signal(0);
+    /// let button = Button::new("Count", move || clicks.update(|n| *n += 1)).debug_name("count");
+    /// let mut harness = TestHarness::new(Box::new(button), 400.0, 300.0).unwrap();
+    /// let passes = harness.layout_context().layout_passes();
+    ///
+    /// // State the tree doesn't depend on, and a resize to the same size, keep the layout
+    /// let count = harness.find_named("count").unwrap();
+    /// harness.click(count.x + 1.0, count.y + 1.0);
+    /// harness.resize(400.0, 300.0).unwrap();
+    /// assert_eq!(harness.layout_context().layout_passes(), passes);
+    ///
+    /// harness.resize(500.0, 300.0).unwrap();
+    /// assert_eq!(harness.layout_context().layout_passes(), passes + 1);
+    /// ```
+    pub fn layout_passes(&self) -> usize {
+        self.passes
+    }
+
+    /// Tags `node` with a name that inspection tools can look it up by. Widgets with a
+    /// `debug_name` call this from `layout`.
+    pub fn set_debug_name(&mut self, node: NodeId, name: impl Into<String>) {
+        self.debug_names.insert(node, name.into());
+    }
+
+    /// The name `node` was given with `set_debug_name`, if any.
+    pub fn debug_name(&self, node: NodeId) -> Option<&str> {
+        self.debug_names.get(&node).map(String::as_str)
+    }
+
+    /// Where every named node ended up in the window after the last layout, in tree order.
+    /// Positions include scrolling and zooming, like `nodes_at`.
+    ///
+    /// ```
+    /// use noxkit::prelude::*;
+    /// use noxkit::testing::TestHarness;
+    ///
+    /// let view = Column::default().child(Button::new("Save", || {}).debug_name("save"));
+    /// let harness = TestHarness::new(Box::new(view), 400.0, 300.0).unwrap();
+    ///
+    /// let named = harness.layout_context().named_geometries();
+    /// let [(name, save)] = named.as_slice() else { panic!("expected one named node") };
+    /// assert_eq!(name, "save");
+    /// // Below the column's 24px top padding, centered across the window
+    /// assert_eq!(save.y, 24.0);
+    /// assert!((save.x - (400.0 - save.width) / 2.0).abs() <= 0.5);
+    /// // 14px text on a 21px line, with 10px of padding above and below
+    /// assert_eq!(save.height, 41.0);
+    /// ```
+    pub fn named_geometries(&self) -> Vec<(String, Geometry)> {
+        let mut named = Vec::new();
+        self.walk(&mut |_, node, geometry| {
+            if let Some(name) = self.debug_names.get(&node) {
+                named.push((name.clone(), geometry));
+            }
+        });
+        named
+    }
+
+    /// The window geometry of the first node named `name`, if it was laid out.
+    pub fn find_named(&self, name: &str) -> Option<Geometry> {
+        self.named_geometries().into_iter().find(|(n, _)| n == name).map(|(_, geometry)| geometry)
+    }
+
+    /// The last layout as indented text, one node per line with its name (or text, for text
+    /// leaves), window position and size, e.g. `  "submit" (24, 80) 96x40`.
+    pub fn dump_tree(&self) -> String {
+        let mut out = String::new();
+        self.walk(&mut |depth, node, g| {
+            let name = match (self.debug_names.get(&node), self.taffy.get_node_context(node)) {
+                (Some(name), _) => format!("{:?}", name),
+                (None, Some(text)) => format!("text {:?}", text.text),
+                (None, None) => "node".to_string(),
+            };
+            out.push_str(&format!("{}{} ({}, {}) {}x{}\n", "  ".repeat(depth), name, g.x, g.y, g.width, g.height));
+        });
+        out
+    }
+
+    /// Lets the d-pad move focus to `node`. Focusable widgets call this from `layout`, with
+    /// `focused` telling whether they hold focus, so navigation continues from them.
+    pub fn set_focusable(&mut self, node: NodeId, focused: bool) {
+        self.focusables.push(node);
+        if focused {
+            self.focused.set(Some(node));
+        }
+    }
+
+    /// Records which focusable node holds focus, e.g. once a click focused or unfocused it.
+    pub fn set_focused(&self, node: Option<NodeId>) {
+        self.focused.set(node);
+    }
+
+    pub fn focused(&self) -> Option<NodeId> {
+        self.focused.get()
+    }
+
+    /// The focusable node the d-pad moves to from the focused one in `direction`: the
+    /// nearest one that way, favoring those in line with it. Without focus, the first one.
+    pub fn next_focus(&self, direction: FocusDirection) -> Option<NodeId> {
+        let mut focusables = Vec::new();
+        self.walk(&mut |_, node, geometry| {
+            if self.focusables.contains(&node) {
+                focusables.push((node, geometry));
+            }
+        });
+        let Some(from) = self.focused.get().and_then(|focused| focusables.iter().find(|(node, _)| *node == focused)) else {
+            return focusables.first().map(|(node, _)| *node);
+        };
+
+        let center = |geometry: &Geometry| (geometry.x + geometry.width / 2.0, geometry.y + geometry.height / 2.0);
+        let (fx, fy) = center(&from.1);
+        focusables.iter()
+            .filter(|(node, _)| *node != from.0)
+            .filter_map(|(node, geometry)| {
+                let (x, y) = center(geometry);
+                let (along, across) = match direction {
+                    FocusDirection::Up => (fy - y, x - fx),
+                    FocusDirection::Down => (y - fy, x - fx),
+                    FocusDirection::Left => (fx - x, y - fy),
+                    FocusDirection::Right => (x - fx, y - fy),
+                };
+                // Sideways distance counts double, so a grid moves along its rows and columns
+                (along > 0.5).then_some((*node, along + 2.0 * across.abs()))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(node, _)| node)
+    }
+
+    /// Visits every node under the last laid out root with its depth and window geometry.
+    fn walk(&self, f: &mut dyn FnMut(usize, NodeId, Geometry)) {
+        if let Some(root) = self.root {
+            let window = Geometry { x: 0.0, y: 0.0, width: 0.0, height: 0.0 };
+            self.walk_node(root, 0, window, Transform::IDENTITY, f);
+        }
+    }
+
+    fn walk_node(&self, node: NodeId, depth: usize, parent: Geometry, transform: Transform, f: &mut dyn FnMut(usize, NodeId, Geometry)) {
+        let Some(geometry) = self.geometry(Some(node), parent) else { return };
+        f(depth, node, transform.apply_geometry(geometry));
+        let child_transform = match self.child_transforms.borrow().get(&node) {
+            Some(&local) => transform.then(local),
+            None => transform,
+        };
+        for child in self.taffy.children(node).unwrap_or_default() {
+            self.walk_node(child, depth + 1, geometry, child_transform, f);
+        }
+    }
+
+    /// Records that `node` draws its children moved by `transform`, as `ScrollView` and
+    /// `Viewport` do, so `nodes_at` finds them where they appear. `transform` maps the
+    /// geometry children are laid out at to where they are drawn.
+    pub fn set_child_transform(&self, node: NodeId, transform: Transform) {
+        self.child_transforms.borrow_mut().insert(node, transform);
+    }
+
+    /// Every node under the window position `(x, y)` in the tree under `root`, outermost
+    /// first. Children outside their parent's box are not found.
+    pub fn nodes_at(&self, root: NodeId, x: f32, y: f32) -> Vec<NodeId> {
+        let mut nodes = Vec::new();
+        let window = Geometry { x: 0.0, y: 0.0, width: 0.0, height: 0.0 };
+        self.collect_nodes_at(root, window, Transform::IDENTITY, x, y, &mut nodes);
+        nodes
+    }
+
+    fn collect_nodes_at(&self, node: NodeId, parent: Geometry, transform: Transform, x: f32, y: f32, nodes: &mut Vec<NodeId>) {
+        let Some(geometry) = self.geometry(Some(node), parent) else { return };
+        if !transform.apply_geometry(geometry).contains(x, y) {
+            return;
+        }
+        nodes.push(node);
+
+        let child_transform = match self.child_transforms.borrow().get(&node) {
+            Some(&local) => transform.then(local),
+            None => transform,
+        };
+        for child in self.taffy.children(node).unwrap_or_default() {
+            self.collect_nodes_at(child, geometry, child_transform, x, y, nodes);
+        }
+    }
+
+    /// Sizes `frame` to the largest `width / height == ratio` box that fits in `container`
+    /// once the container's size is known. Taffy can't letterbox on its own: `aspect_ratio`
+    /// with a max size clamps one axis without shrinking the other.
+    pub fn fit_aspect_ratio(&mut self, container: NodeId, frame: NodeId, ratio: f32) {
+        self.aspect_fits.push(AspectFit { container, frame, ratio });
+    }
+
+    /// Lines the children of the row `container` up on their first baselines once the text
+    /// in them is measured. Children without text line up on their bottom edge.
+    ///
+    /// Taffy can't do this on its own: it doesn't know where text baselines are, so it would
+    /// line text up on the bottom of its line box, which sits lower for larger text.
+    pub fn align_baselines(&mut self, container: NodeId) {
+        self.baseline_rows.push(container);
+    }
+
+    /// Distance from the top of `node` to the first baseline of the text in it, as of the
+    /// last layout: the first text leaf's, following the first child of each container.
+    /// `None` if there is no text that way.
+    pub fn baseline(&self, node: NodeId) -> Option<f32> {
+        if let Some(text) = self.taffy.get_node_context(node) {
+            return text.baseline;
+        }
+        let child = self.taffy.child_at_index(node, 0).ok()?;
+        let offset = self.taffy.layout(child).ok()?.location.y;
+        self.baseline(child).map(|baseline| offset + baseline)
+    }
+
+    /// Lays out the tree under `root`, shaping text leaves with `font_system` to size them.
+    pub fn compute_layout(&mut self, root: NodeId, available_space: Size<AvailableSpace>, font_system: &mut glyphon::FontSystem) -> Result<(), LayoutError> {
+        self.root = Some(root);
+        self.passes += 1;
+        self.compute_pass(root, available_space, font_system)?;
+        // Baselines are only known once text has been measured, so push children down to
+        // them and lay out again
+        if self.align_row_baselines()? {
+            self.compute_pass(root, available_space, font_system)?;
+        }
+        // Fitting a frame can resize containers nested inside it, so repeat until nothing moves
+        for _ in 0..self.aspect_fits.len() {
+            let mut changed = self.align_row_baselines()?;
+            for fit in self.aspect_fits.clone() {
+                let container = self.taffy.layout(fit.container)?.size;
+                let width = container.width.min(container.height * fit.ratio);
+                let size = Size { width: length(width), height: length(width / fit.ratio) };
+                let style = self.taffy.style(fit.frame)?;
+                if style.size != size {
+                    let style = Style { size, ..style.clone() };
+                    self.taffy.set_style(fit.frame, style)?;
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+            self.compute_pass(root, available_space, font_system)?;
+        }
+        Ok(())
+    }
+
+    /// Gives the children of each `align_baselines` row top margins that bring their first
+    /// baselines level with the lowest one. Returns whether any margin changed.
+    fn align_row_baselines(&mut self) -> Result<bool, LayoutError> {
+        let mut changed = false;
+        for row in self.baseline_rows.clone() {
+            let mut children = Vec::new();
+            for child in self.taffy.children(row)? {
+                let style = self.taffy.style(child)?;
+                let margin = *self.baseline_margins.entry(child).or_insert(match style.margin.top {
+                    LengthPercentageAuto::Length(margin) => margin,
+                    _ => 0.0,
+                });
+                let baseline = match self.baseline(child) {
+                    Some(baseline) => baseline,
+                    None => self.taffy.layout(child)?.size.height,
+                };
+                children.push((child, margin, baseline));
+            }
+            let lowest = children.iter().map(|(_, margin, baseline)| margin + baseline).fold(0.0, f32::max);
+            for (child, _, baseline) in children {
+                let style = self.taffy.style(child)?;
+                let top = length(lowest - baseline);
+                if style.align_self == Some(AlignSelf::FlexStart) && style.margin.top == top {
+                    continue;
+                }
+                let style = Style {
+                    align_self: Some(AlignSelf::FlexStart),
+                    margin: taffy::prelude::Rect { top, ..style.margin },
+                    ..style.clone()
+                };
+                self.taffy.set_style(child, style)?;
+                changed = true;
+            }
+        }
+        Ok(changed)
+    }
+
+    /// Places `node` inside `parent`, or `None` if the node hasn't been laid out yet (e.g. a
+    /// widget added after the last layout pass). Widgets skip drawing and events in that case.
+    pub fn geometry(&self, node: Option<NodeId>, parent: Geometry) -> Option<Geometry> {
+        let node_layout = self.taffy.layout(node?).ok()?;
+        Some(Geometry {
+            x: parent.x + node_layout.location.x,
+            y: parent.y + node_layout.location.y,
+            width: node_layout.size.width,
+            height: node_layout.size.height,
+        })
+    }
+}
+
+fn measure_text(font_system: &mut glyphon::FontSystem, text: &mut TextMeasure, known: Size<Option<f32>>, available: Size<AvailableSpace>) -> Size<f32> {
+    if let Size { width: Some(width), height: Some(height) } = known {
+        return Size { width, height };
+    }
+
+    let max_width = if text.single_line {
+        None
+    } else {
+        known.width.or(match available.width {
+            AvailableSpace::Definite(width) => Some(width),
+            AvailableSpace::MinContent => Some(0.0),
+            AvailableSpace::MaxContent => None,
+        })
+    };
+
+    let mut buffer = glyphon::Buffer::new(font_system, glyphon::Metrics::new(text.font_size, text.line_height));
+    buffer.set_size(font_system, max_width, None);
+    let attrs = glyphon::Attrs::new().family(glyphon::Family::SansSerif).letter_spacing(text.letter_spacing);
+    buffer.set_text(font_system, &text.text, &attrs, text.shaping);
+    buffer.shape_until_scroll(font_system, false);
+
+    let (width, lines) = buffer.layout_runs().fold((0.0f32, 0usize), |(width, lines), run| (width.max(run.line_w), lines + 1));
+    text.baseline = buffer.layout_runs().next().map(|run| run.line_y);
+    Size {
+        width: known.width.unwrap_or(width.ceil()),
+        height: known.height.unwrap_or(lines.max(1) as f32 * text.line_height),
+    }
+}
+
+/// Spacing around the four edges of a widget, in pixels.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct EdgeInsets {
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+    pub left: f32,
+}
+
+impl EdgeInsets {
+    pub fn new(top: f32, right: f32, bottom: f32, left: f32) -> Self {
+        Self { top, right, bottom, left }
+    }
+
+    pub fn all(value: f32) -> Self {
+        Self::new(value, value, value, value)
+    }
+
+    pub fn symmetric(horizontal: f32, vertical: f32) -> Self {
+        Self::new(vertical, horizontal, vertical, horizontal)
+    }
+
+    pub fn to_taffy(self) -> taffy::prelude::Rect<LengthPercentage> {
+        taffy::prelude::Rect {
+            left: length(self.left),
+            right: length(self.right),
+            top: length(self.top),
+            bottom: length(self.bottom),
+        }
+    }
 }
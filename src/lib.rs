@@ -4,17 +4,39 @@ pub mod render;
 pub mod widgets;
 pub mod state;
 pub mod app;
+pub mod renderer;
+pub mod testing;
+pub mod menu;
+pub mod animation;
+pub mod path;
+pub mod clipboard;
+mod gamepad;
+mod gesture;
+mod svg;
 
 pub use noxkit_macros::view;
-pub use view::View;
-pub use widgets::{Column, Text, Button, Rect, RoundedRect, Circle};
-pub use state::{create_signal, Signal, Computed, create_computed, create_memo};
-pub use app::App;
+pub use view::{View, IntoViews, Interaction, Event, Key, Modifiers, TouchPhase, GamepadButton, GamepadAxis};
+pub use layout::{EdgeInsets, LayoutError, request_layout, TextDirection, set_text_direction, FocusDirection};
+pub use widgets::{Column, Row, Fragment, Text, TextShadow, AppBar, Button, Chip, TextInput, Interactive, Hideable, Visibility, Animated, Badge, TreeView, TreeNode, ListView, SelectionState, SelectionMode, SplitPane, DropZone, Responsive, Breakpoint, Router, Navigator, AspectRatio, ScrollView, ScrollController, ScrollbarMode, Viewport, Rect, RoundedRect, Circle, CircularProgress, Avatar, Image, NinePatch, Path, Snackbar, show_snackbar, StatsOverlay};
+pub use state::{create_signal, Signal, Computed, create_computed, create_memo, create_effect, batch, Field, validate_all, StateStore, StateError, History};
+pub use app::{App, AppError, Commands, MonitorInfo};
+pub use menu::{Menu, MenuItem};
+pub use animation::Easing;
+pub use path::{PathCommand, FillRule, PathError};
+pub use renderer::Renderer;
+pub use render::{FrameStats, Background, Texture, TintMode, Color};
+pub use glyphon::Shaping;
 
 pub mod prelude {
-    pub use crate::view::View;
-    pub use crate::widgets::{Column, Text, Button, Rect, RoundedRect, Circle};
-    pub use crate::state::{create_signal, Signal, Computed, create_computed, create_memo};
-    pub use crate::app::App;
+    pub use crate::view::{View, IntoViews, Interaction, Event, Key, Modifiers, TouchPhase, GamepadButton, GamepadAxis};
+    pub use crate::layout::{EdgeInsets, LayoutError, request_layout, TextDirection, set_text_direction, FocusDirection};
+    pub use crate::widgets::{Column, Row, Fragment, Text, TextShadow, AppBar, Button, Chip, TextInput, Interactive, Hideable, Visibility, Animated, Badge, TreeView, TreeNode, ListView, SelectionState, SelectionMode, SplitPane, DropZone, Responsive, Breakpoint, Router, Navigator, AspectRatio, ScrollView, ScrollController, ScrollbarMode, Viewport, Rect, RoundedRect, Circle, CircularProgress, Avatar, Image, NinePatch, Path, Snackbar, show_snackbar, StatsOverlay};
+    pub use crate::state::{create_signal, Signal, Computed, create_computed, create_memo, create_effect, batch, Field, validate_all, StateStore, StateError, History};
+    pub use crate::app::{App, AppError, Commands, MonitorInfo};
+    pub use crate::menu::{Menu, MenuItem};
+    pub use crate::animation::Easing;
+    pub use crate::path::{PathCommand, FillRule};
+    pub use crate::render::{Color, TintMode};
+    pub use glyphon::Shaping;
     pub use noxkit_macros::view;
 }
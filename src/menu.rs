@@ -0,0 +1,202 @@
+use std::rc::Rc;
+use winit::window::Window;
+
+/// A titled menu in the window's menu bar, e.g. File or Help, set with `App::with_menu`.
+///
+/// Menus are native: they need the `native-menu` feature and are shown on Windows and
+/// macOS. On macOS the first menu becomes the application menu, under the app's name.
+///
+/// ```
+/// use noxkit::prelude::*;
+///
+/// let commands = Commands::new();
+/// let file = Menu::new("File")
+///     .item(MenuItem::new("New", || println!("new file")))
+///     .separator()
+///     .item(MenuItem::new("Quit", move || commands.exit()));
+/// let app = App::new(Box::new(Text::new("Hello"))).with_menu(vec![file]);
+/// ```
+///
+/// `find` and `MenuItem::select` run items without the native menu, e.g. in tests:
+///
+/// ```
+/// use noxkit::prelude::*;
+///
+/// let saved = create_signal(false);
+/// let export = MenuItem::new("PDF", {
+///     let saved = saved.clone();
+///     move || saved.set(true)
+/// });
+/// let file = Menu::new("File").submenu(Menu::new("Export").item(export));
+/// file.find("PDF").unwrap().select();
+/// assert!(saved.get());
+/// ```
+pub struct Menu {
+    title: String,
+    entries: Vec<MenuEntry>,
+}
+
+enum MenuEntry {
+    Item(MenuItem),
+    Separator,
+    Submenu(Menu),
+}
+
+impl Menu {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self { title: title.into(), entries: Vec::new() }
+    }
+
+    pub fn item(mut self, item: MenuItem) -> Self {
+        self.entries.push(MenuEntry::Item(item));
+        self
+    }
+
+    /// A line between groups of items.
+    pub fn separator(mut self) -> Self {
+        self.entries.push(MenuEntry::Separator);
+        self
+    }
+
+    /// A nested menu, opening to the side of this one.
+    pub fn submenu(mut self, menu: Menu) -> Self {
+        self.entries.push(MenuEntry::Submenu(menu));
+        self
+    }
+
+    /// The first item labelled `label`, in this menu or its submenus.
+    pub fn find(&self, label: &str) -> Option<&MenuItem> {
+        self.entries.iter().find_map(|entry| match entry {
+            MenuEntry::Item(item) => (item.label == label).then_some(item),
+            MenuEntry::Separator => None,
+            MenuEntry::Submenu(menu) => menu.find(label),
+        })
+    }
+}
+
+/// An entry in a `Menu` that calls `on_select` when picked. The handler runs on the event
+/// loop like any other, so it can set signals or use `Commands`.
+pub struct MenuItem {
+    label: String,
+    enabled: bool,
+    on_select: Rc<dyn Fn()>,
+}
+
+impl MenuItem {
+    pub fn new(label: impl Into<String>, on_select: impl Fn() + 'static) -> Self {
+        Self { label: label.into(), enabled: true, on_select: Rc::new(on_select) }
+    }
+
+    /// Greys the item out so it can't be picked.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Runs the handler as if the item were picked, unless it is disabled.
+    pub fn select(&self) {
+        if self.enabled {
+            (self.on_select)();
+        }
+    }
+}
+
+/// Prefixes the ids of native items, so events from menus the app creates itself pass by.
+#[cfg(all(feature = "native-menu", any(target_os = "windows", target_os = "macos")))]
+const ID_PREFIX: &str = "noxkit-menu-";
+
+/// The menus attached to the window, with the handlers of their items.
+pub(crate) struct MenuBar {
+    /// Handlers indexed by the number in the native item's id.
+    #[cfg(all(feature = "native-menu", any(target_os = "windows", target_os = "macos")))]
+    actions: Vec<Rc<dyn Fn()>>,
+    /// Dropping it would take the menu bar down with it.
+    #[cfg(all(feature = "native-menu", any(target_os = "windows", target_os = "macos")))]
+    _native: muda::Menu,
+}
+
+impl MenuBar {
+    /// Builds native menus for `menus` and attaches them to `window`.
+    #[cfg(all(feature = "native-menu", any(target_os = "windows", target_os = "macos")))]
+    pub(crate) fn install(menus: &[Menu], window: &Window) -> Self {
+        let native = muda::Menu::new();
+        let mut actions = Vec::new();
+        for menu in menus {
+            if let Err(err) = native.append(&native_submenu(menu, &mut actions)) {
+                tracing::warn!("Failed to add the {} menu: {}", menu.title, err);
+            }
+        }
+
+        #[cfg(target_os = "windows")]
+        {
+            use winit::raw_window_handle::{HasWindowHandle, RawWindowHandle};
+            if let Ok(handle) = window.window_handle()
+                && let RawWindowHandle::Win32(handle) = handle.as_raw()
+            {
+                // SAFETY: the handle is the app's window, which lives as long as the menu bar
+                if let Err(err) = unsafe { native.init_for_hwnd(handle.hwnd.get()) } {
+                    tracing::warn!("Failed to attach the menu bar: {}", err);
+                }
+            }
+        }
+        #[cfg(target_os = "macos")]
+        {
+            let _ = window;
+            native.init_for_nsapp();
+        }
+
+        Self { actions, _native: native }
+    }
+
+    #[cfg(not(all(feature = "native-menu", any(target_os = "windows", target_os = "macos"))))]
+    pub(crate) fn install(menus: &[Menu], _window: &Window) -> Self {
+        let titles: Vec<&str> = menus.iter().map(|menu| menu.title.as_str()).collect();
+        if cfg!(any(target_os = "windows", target_os = "macos")) {
+            tracing::warn!("Menus need the `native-menu` feature; not showing {:?}", titles);
+        } else {
+            tracing::warn!("Native menus are only supported on Windows and macOS; not showing {:?}", titles);
+        }
+        Self {}
+    }
+
+    /// Runs the handlers of items picked since the last call. Returns whether any ran.
+    #[cfg(all(feature = "native-menu", any(target_os = "windows", target_os = "macos")))]
+    pub(crate) fn dispatch(&self) -> bool {
+        let mut selected = false;
+        while let Ok(event) = muda::MenuEvent::receiver().try_recv() {
+            let action = event.id.0.strip_prefix(ID_PREFIX)
+                .and_then(|index| index.parse::<usize>().ok())
+                .and_then(|index| self.actions.get(index));
+            if let Some(action) = action {
+                action();
+                selected = true;
+            }
+        }
+        selected
+    }
+
+    #[cfg(not(all(feature = "native-menu", any(target_os = "windows", target_os = "macos"))))]
+    pub(crate) fn dispatch(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(all(feature = "native-menu", any(target_os = "windows", target_os = "macos")))]
+fn native_submenu(menu: &Menu, actions: &mut Vec<Rc<dyn Fn()>>) -> muda::Submenu {
+    let submenu = muda::Submenu::new(&menu.title, true);
+    for entry in &menu.entries {
+        let appended = match entry {
+            MenuEntry::Item(item) => {
+                let id = format!("{}{}", ID_PREFIX, actions.len());
+                actions.push(item.on_select.clone());
+                submenu.append(&muda::MenuItem::with_id(id, &item.label, item.enabled, None))
+            }
+            MenuEntry::Separator => submenu.append(&muda::PredefinedMenuItem::separator()),
+            MenuEntry::Submenu(child) => submenu.append(&native_submenu(child, actions)),
+        };
+        if let Err(err) = appended {
+            tracing::warn!("Failed to add an item to the {} menu: {}", menu.title, err);
+        }
+    }
+    submenu
+}
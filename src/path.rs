@@ -0,0 +1,298 @@
+use lyon_tessellation::math::point;
+use lyon_tessellation::{BuffersBuilder, FillOptions, FillTessellator, FillVertex, VertexBuffers};
+
+/// One step of a path, with points in absolute coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PathCommand {
+    /// Starts a new subpath at the point.
+    MoveTo([f32; 2]),
+    LineTo([f32; 2]),
+    /// A quadratic Bézier curve with one control point.
+    QuadTo { ctrl: [f32; 2], to: [f32; 2] },
+    /// A cubic Bézier curve with two control points.
+    CubicTo { ctrl1: [f32; 2], ctrl2: [f32; 2], to: [f32; 2] },
+    /// Closes the subpath with a line back to its start.
+    Close,
+}
+
+impl PathCommand {
+    /// The command with every point passed through `f`, e.g. to scale a path.
+    pub fn map_points(self, f: impl Fn([f32; 2]) -> [f32; 2]) -> PathCommand {
+        match self {
+            PathCommand::MoveTo(to) => PathCommand::MoveTo(f(to)),
+            PathCommand::LineTo(to) => PathCommand::LineTo(f(to)),
+            PathCommand::QuadTo { ctrl, to } => PathCommand::QuadTo { ctrl: f(ctrl), to: f(to) },
+            PathCommand::CubicTo { ctrl1, ctrl2, to } => PathCommand::CubicTo { ctrl1: f(ctrl1), ctrl2: f(ctrl2), to: f(to) },
+            PathCommand::Close => PathCommand::Close,
+        }
+    }
+}
+
+/// Which parts of a path that crosses itself or has holes are filled, as in SVG's
+/// `fill-rule`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FillRule {
+    /// Filled wherever the outlines wind around more times one way than the other, so
+    /// overlapping subpaths drawn the same way stay filled.
+    #[default]
+    NonZero,
+    /// Filled where a ray from the point crosses the outline an odd number of times, so
+    /// overlaps cut holes, e.g. the middle of a five-pointed star drawn in one stroke.
+    EvenOdd,
+}
+
+/// Errors from parsing path data with `parse`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PathError {
+    /// A character at this byte offset that isn't a command or part of a number.
+    UnexpectedCharacter(usize, char),
+    /// The command before this byte offset needs more numbers.
+    MissingNumber(usize),
+    /// Numbers at this byte offset without a command to take them.
+    MissingCommand(usize),
+    /// A valid SVG command this parser doesn't handle, such as `A` (elliptical arcs).
+    UnsupportedCommand(char),
+}
+
+impl std::fmt::Display for PathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PathError::UnexpectedCharacter(offset, c) => write!(f, "Unexpected '{}' at {} in path data", c, offset),
+            PathError::MissingNumber(offset) => write!(f, "Expected a number at {} in path data", offset),
+            PathError::MissingCommand(offset) => write!(f, "Expected a command at {} in path data", offset),
+            PathError::UnsupportedCommand(c) => write!(f, "Path command '{}' is not supported", c),
+        }
+    }
+}
+
+impl std::error::Error for PathError {}
+
+/// Parses SVG path data (the `d` attribute) into commands with absolute points.
+///
+/// Supports moveto (`M`), lineto (`L`, `H`, `V`), cubic (`C`, `S`) and quadratic (`Q`, `T`)
+/// curves and closepath (`Z`), each in absolute and relative (lowercase) form, with
+/// repeated arguments continuing the last command as in SVG.
+///
+/// ```
+/// use noxkit::path::{parse, PathCommand};
+///
+/// let square = parse("M 10,10 h 20 v 20 H 10 z").unwrap();
+/// assert_eq!(square, vec![
+///     PathCommand::MoveTo([10.0, 10.0]),
+///     PathCommand::LineTo([30.0, 10.0]),
+///     PathCommand::LineTo([30.0, 30.0]),
+///     PathCommand::LineTo([10.0, 30.0]),
+///     PathCommand::Close,
+/// ]);
+///
+/// // Numbers may run together where a sign or a second dot separates them
+/// assert_eq!(parse("M0 0l5-5.5.5.5").unwrap()[1..], [
+///     PathCommand::LineTo([5.0, -5.5]),
+///     PathCommand::LineTo([5.5, -5.0]),
+/// ]);
+/// assert!(parse("M 0 0 A 5 5 0 0 1 10 10").is_err());
+/// ```
+pub fn parse(d: &str) -> Result<Vec<PathCommand>, PathError> {
+    let mut parser = Parser { src: d, pos: 0 };
+    let mut commands = Vec::new();
+    let mut current = [0.0f32; 2];
+    let mut subpath_start = [0.0f32; 2];
+    // The last curve's second control point, reflected by a following `S` or `T`
+    let mut last_ctrl: Option<(char, [f32; 2])> = None;
+    let mut command: Option<char> = None;
+
+    loop {
+        parser.skip_separators();
+        let Some(c) = parser.peek() else { break };
+        let letter = if c.is_ascii_alphabetic() {
+            parser.pos += 1;
+            c
+        } else if c.is_ascii_digit() || matches!(c, '-' | '+' | '.') {
+            match command {
+                // Pairs after a moveto's first one are linetos
+                Some('M') => 'L',
+                Some('m') => 'l',
+                Some('Z' | 'z') | None => return Err(PathError::MissingCommand(parser.pos)),
+                Some(command) => command,
+            }
+        } else {
+            return Err(PathError::UnexpectedCharacter(parser.pos, c));
+        };
+
+        let origin = if letter.is_ascii_lowercase() { current } else { [0.0; 2] };
+        let pair = |parser: &mut Parser| -> Result<[f32; 2], PathError> {
+            let x = parser.number()?;
+            let y = parser.number()?;
+            Ok([origin[0] + x, origin[1] + y])
+        };
+        let reflected = |kind: char| match last_ctrl {
+            Some((last, ctrl)) if last == kind => [2.0 * current[0] - ctrl[0], 2.0 * current[1] - ctrl[1]],
+            _ => current,
+        };
+        let next = match letter.to_ascii_uppercase() {
+            'M' => {
+                let to = pair(&mut parser)?;
+                subpath_start = to;
+                PathCommand::MoveTo(to)
+            }
+            'L' => PathCommand::LineTo(pair(&mut parser)?),
+            'H' => PathCommand::LineTo([origin[0] + parser.number()?, current[1]]),
+            'V' => PathCommand::LineTo([current[0], origin[1] + parser.number()?]),
+            'C' => PathCommand::CubicTo { ctrl1: pair(&mut parser)?, ctrl2: pair(&mut parser)?, to: pair(&mut parser)? },
+            'S' => PathCommand::CubicTo { ctrl1: reflected('C'), ctrl2: pair(&mut parser)?, to: pair(&mut parser)? },
+            'Q' => PathCommand::QuadTo { ctrl: pair(&mut parser)?, to: pair(&mut parser)? },
+            'T' => PathCommand::QuadTo { ctrl: reflected('Q'), to: pair(&mut parser)? },
+            'Z' => PathCommand::Close,
+            _ => return Err(PathError::UnsupportedCommand(letter)),
+        };
+
+        last_ctrl = match next {
+            PathCommand::CubicTo { ctrl2, .. } => Some(('C', ctrl2)),
+            PathCommand::QuadTo { ctrl, .. } => Some(('Q', ctrl)),
+            _ => None,
+        };
+        current = match next {
+            PathCommand::MoveTo(to) | PathCommand::LineTo(to) | PathCommand::QuadTo { to, .. } | PathCommand::CubicTo { to, .. } => to,
+            PathCommand::Close => subpath_start,
+        };
+        commands.push(next);
+        command = Some(letter);
+    }
+    Ok(commands)
+}
+
+struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.src[self.pos..].chars().next()
+    }
+
+    fn skip_separators(&mut self) {
+        while let Some(c) = self.peek()
+            && (c.is_ascii_whitespace() || c == ',')
+        {
+            self.pos += 1;
+        }
+    }
+
+    /// Reads a number like `-1.5e3`. A sign or a second dot ends the previous number.
+    fn number(&mut self) -> Result<f32, PathError> {
+        self.skip_separators();
+        let bytes = self.src.as_bytes();
+        let start = self.pos;
+        let mut end = start;
+        if matches!(bytes.get(end), Some(b'-' | b'+')) {
+            end += 1;
+        }
+        let digits = |end: &mut usize| {
+            let from = *end;
+            while bytes.get(*end).is_some_and(u8::is_ascii_digit) {
+                *end += 1;
+            }
+            *end > from
+        };
+        let mut any = digits(&mut end);
+        if bytes.get(end) == Some(&b'.') {
+            end += 1;
+            any |= digits(&mut end);
+        }
+        if !any {
+            return Err(PathError::MissingNumber(start));
+        }
+        if matches!(bytes.get(end), Some(b'e' | b'E')) {
+            let mut exponent = end + 1;
+            if matches!(bytes.get(exponent), Some(b'-' | b'+')) {
+                exponent += 1;
+            }
+            if digits(&mut exponent) {
+                end = exponent;
+            }
+        }
+        self.pos = end;
+        self.src[start..end].parse().map_err(|_| PathError::MissingNumber(start))
+    }
+}
+
+/// Corners of the smallest box holding every point of `commands`, control points
+/// included, as `[min_x, min_y, max_x, max_y]`; `None` for an empty path.
+pub(crate) fn bounds(commands: &[PathCommand]) -> Option<[f32; 4]> {
+    let mut points = commands.iter().flat_map(|command| match *command {
+        PathCommand::MoveTo(to) | PathCommand::LineTo(to) => vec![to],
+        PathCommand::QuadTo { ctrl, to } => vec![ctrl, to],
+        PathCommand::CubicTo { ctrl1, ctrl2, to } => vec![ctrl1, ctrl2, to],
+        PathCommand::Close => Vec::new(),
+    });
+    let first = points.next()?;
+    Some(points.fold([first[0], first[1], first[0], first[1]], |[x0, y0, x1, y1], [x, y]| {
+        [x0.min(x), y0.min(y), x1.max(x), y1.max(y)]
+    }))
+}
+
+/// Fills `commands` by `fill_rule` with triangles, flattening curves to within `tolerance`
+/// pixels. Returns the vertices and the triangles' indices into them, or `None` (with a
+/// warning) if the path has too many vertices for 16-bit indices.
+pub(crate) fn tessellate(commands: &[PathCommand], fill_rule: FillRule, tolerance: f32) -> Option<(Vec<[f32; 2]>, Vec<u16>)> {
+    let mut builder = lyon_tessellation::path::Path::builder();
+    let mut open = false;
+    let mut current = point(0.0, 0.0);
+    let mut subpath_start = current;
+    for command in commands {
+        // Lines and curves without a moveto before them start where the last subpath ended
+        if !open && !matches!(command, PathCommand::MoveTo(_) | PathCommand::Close) {
+            builder.begin(current);
+            open = true;
+        }
+        match *command {
+            PathCommand::MoveTo([x, y]) => {
+                if open {
+                    builder.end(false);
+                }
+                current = point(x, y);
+                subpath_start = current;
+                builder.begin(current);
+                open = true;
+            }
+            PathCommand::LineTo([x, y]) => {
+                current = point(x, y);
+                builder.line_to(current);
+            }
+            PathCommand::QuadTo { ctrl, to } => {
+                current = point(to[0], to[1]);
+                builder.quadratic_bezier_to(point(ctrl[0], ctrl[1]), current);
+            }
+            PathCommand::CubicTo { ctrl1, ctrl2, to } => {
+                current = point(to[0], to[1]);
+                builder.cubic_bezier_to(point(ctrl1[0], ctrl1[1]), point(ctrl2[0], ctrl2[1]), current);
+            }
+            PathCommand::Close => {
+                if open {
+                    builder.end(true);
+                    open = false;
+                }
+                current = subpath_start;
+            }
+        }
+    }
+    // Fills close open subpaths anyway
+    if open {
+        builder.end(false);
+    }
+    let path = builder.build();
+
+    let options = FillOptions::tolerance(tolerance).with_fill_rule(match fill_rule {
+        FillRule::NonZero => lyon_tessellation::FillRule::NonZero,
+        FillRule::EvenOdd => lyon_tessellation::FillRule::EvenOdd,
+    });
+    let mut buffers: VertexBuffers<[f32; 2], u16> = VertexBuffers::new();
+    let mut output = BuffersBuilder::new(&mut buffers, |vertex: FillVertex| vertex.position().to_array());
+    let result = FillTessellator::new().tessellate_path(&path, &options, &mut output);
+    if let Err(err) = result {
+        tracing::warn!("Failed to fill a path: {:?}", err);
+        return None;
+    }
+    Some((buffers.vertices, buffers.indices))
+}
@@ -2,6 +2,11 @@ use bytemuck::{Pod, Zeroable};
 use glyphon::{
     FontSystem, SwashCache, TextRenderer, TextAtlas, Cache, Viewport,
 };
+use glyphon::cosmic_text::{Fallback, PlatformFallback};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use unicode_script::Script;
+use crate::path::{self, FillRule, PathCommand};
 
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Pod, Zeroable)]
@@ -11,7 +16,15 @@ pub struct Vertex {
     pub rect_pos: [f32; 2],
     pub rect_size: [f32; 2],
     pub corner_radius: f32,
-    pub shape_type: f32, // 0: rect, 1: rounded rect, 2: circle
+    pub shape_type: f32, // 0: rect, 1: rounded rect, 2: circle, 3: flat, 4: arc
+    pub clip_rect: [f32; 4], // x, y, width, height; zero size disables clipping
+    pub clip_radius: f32,
+    /// Extra width of the fade at SDF shape edges, beyond the pixel always used for
+    /// anti-aliasing. Zero is crisp; larger values soften or blur the edge.
+    pub edge_softness: f32,
+    /// Start angle and sweep of arcs, in radians turning clockwise from 12 o'clock. Arcs
+    /// use `corner_radius` as their thickness.
+    pub arc: [f32; 2],
 }
 
 impl Vertex {
@@ -50,14 +63,274 @@ impl Vertex {
                     shader_location: 5,
                     format: wgpu::VertexFormat::Float32,
                 },
+                wgpu::VertexAttribute {
+                    offset: 48,
+                    shader_location: 6,
+                    format: wgpu::VertexFormat::Float32x4,
+                },
+                wgpu::VertexAttribute {
+                    offset: 64,
+                    shader_location: 7,
+                    format: wgpu::VertexFormat::Float32,
+                },
+                wgpu::VertexAttribute {
+                    offset: 68,
+                    shader_location: 8,
+                    format: wgpu::VertexFormat::Float32,
+                },
+                wgpu::VertexAttribute {
+                    offset: 72,
+                    shader_location: 9,
+                    format: wgpu::VertexFormat::Float32x2,
+                },
             ],
         }
     }
 }
 
+/// A (rounded) rectangle that primitives are clipped to in the fragment shader.
+#[derive(Debug, Clone, Copy)]
+pub struct Clip {
+    pub geometry: crate::view::Geometry,
+    pub radius: f32,
+}
+
+/// A uniform scale followed by a translation, mapping points as `point * scale + translate`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform {
+    pub translate: [f32; 2],
+    pub scale: f32,
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Transform {
+    pub const IDENTITY: Transform = Transform { translate: [0.0, 0.0], scale: 1.0 };
+
+    pub fn apply(&self, point: [f32; 2]) -> [f32; 2] {
+        [point[0] * self.scale + self.translate[0], point[1] * self.scale + self.translate[1]]
+    }
+
+    pub fn apply_geometry(&self, geometry: crate::view::Geometry) -> crate::view::Geometry {
+        let [x, y] = self.apply([geometry.x, geometry.y]);
+        crate::view::Geometry { x, y, width: geometry.width * self.scale, height: geometry.height * self.scale }
+    }
+
+    /// Maps a transformed point back.
+    pub fn invert(&self, point: [f32; 2]) -> [f32; 2] {
+        [(point[0] - self.translate[0]) / self.scale, (point[1] - self.translate[1]) / self.scale]
+    }
+
+    /// The transform that applies `inner` first and then `self`.
+    pub fn then(&self, inner: Transform) -> Transform {
+        Transform { translate: self.apply(inner.translate), scale: self.scale * inner.scale }
+    }
+}
+
+/// An RGBA color in 0..1, convertible to and from the `[f32; 4]` widgets take, with
+/// helpers for deriving related shades such as hover and pressed states.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Color(pub [f32; 4]);
+
+impl Color {
+    pub const fn rgba(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self([r, g, b, a])
+    }
+
+    /// Mixes the color with white by `amount` (0 to 1), keeping its alpha.
+    pub fn lighten(self, amount: f32) -> Self {
+        self.mix_rgb(1.0, amount)
+    }
+
+    /// Mixes the color with black by `amount` (0 to 1), keeping its alpha.
+    pub fn darken(self, amount: f32) -> Self {
+        self.mix_rgb(0.0, amount)
+    }
+
+    /// The color with its red, green and blue multiplied by its alpha, as the renderer
+    /// draws it (see `BLEND`).
+    pub fn premultiplied(self) -> [f32; 4] {
+        let [r, g, b, a] = self.0;
+        [r * a, g * a, b * a, a]
+    }
+
+    /// The color seen where this color is drawn on top of `below`, blended like the
+    /// renderer blends overlapping shapes.
+    ///
+    /// Two 50% shapes stacked on a transparent frame cover 75% of it, with the top one
+    /// contributing twice as much color as the one below:
+    ///
+    /// ```
+    /// use noxkit::Color;
+    ///
+    /// let dim = Color::rgba(0.0, 0.0, 1.0, 0.5);
+    /// let dialog = Color::rgba(1.0, 0.0, 0.0, 0.5);
+    /// let [r, g, b, a] = dialog.over(dim.over(Color::rgba(0.0, 0.0, 0.0, 0.0))).premultiplied();
+    /// let close = |x: f32, y: f32| (x - y).abs() < 1e-6;
+    /// assert!(close(r, 0.5) && close(g, 0.0) && close(b, 0.25) && close(a, 0.75));
+    ///
+    /// // On an opaque frame the result is opaque, as with straight alpha blending
+    /// let [r, g, b, a] = dialog.over(dim.over(Color::rgba(1.0, 1.0, 1.0, 1.0))).0;
+    /// assert!(close(r, 0.75) && close(g, 0.25) && close(b, 0.5) && close(a, 1.0));
+    /// ```
+    pub fn over(self, below: Color) -> Color {
+        let src = self.premultiplied();
+        let dst = below.premultiplied();
+        let [r, g, b, a]: [f32; 4] = std::array::from_fn(|i| src[i] + dst[i] * (1.0 - src[3]));
+        if a <= 0.0 {
+            return Color([0.0; 4]);
+        }
+        Color([r / a, g / a, b / a, a])
+    }
+
+    fn mix_rgb(self, target: f32, amount: f32) -> Self {
+        let amount = amount.clamp(0.0, 1.0);
+        let [r, g, b, a] = self.0;
+        let mix = |c: f32| c + (target - c) * amount;
+        Self([mix(r), mix(g), mix(b), a])
+    }
+}
+
+impl From<[f32; 4]> for Color {
+    fn from(color: [f32; 4]) -> Self {
+        Self(color)
+    }
+}
+
+impl From<Color> for [f32; 4] {
+    fn from(color: Color) -> Self {
+        color.0
+    }
+}
+
+/// What the window shows behind the view.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Background {
+    /// A flat color, applied by clearing the frame.
+    Solid([f32; 4]),
+    /// A two-color linear gradient across the whole window. `angle` is in degrees and follows
+    /// CSS: `180.0` runs from top to bottom, `90.0` from left to right.
+    LinearGradient { from: [f32; 4], to: [f32; 4], angle: f32 },
+    /// RGBA8 pixels (sRGB, row by row) scaled to cover the window, cropping the overflow.
+    Image { width: u32, height: u32, rgba: Vec<u8> },
+}
+
+impl Default for Background {
+    fn default() -> Self {
+        // Near-black for modern look
+        Background::Solid([0.01, 0.01, 0.02, 1.0])
+    }
+}
+
+/// A contiguous run of indices pushed at the same z-index.
+#[derive(Debug, Clone, Copy)]
+struct ZSpan {
+    z_index: i32,
+    start: usize,
+    len: usize,
+}
+
+/// `Vertex::shape_type` for primitives filled without a signed distance field, e.g. triangles.
+const SHAPE_FLAT: f32 = 3.0;
+
+/// `Vertex::shape_type` for ring segments drawn by `push_arc`.
+const SHAPE_ARC: f32 = 4.0;
+
+/// How far SDF shape quads extend past the shape, for the outer half of its anti-aliased edge.
+const AA_MARGIN: f32 = 1.0;
+
+/// How every pipeline blends: shaders output premultiplied alpha, with colors already
+/// multiplied by their alpha (see `Color::premultiplied`), so frames hold premultiplied
+/// colors too. Texture filtering and color interpolation then don't bleed the color of
+/// transparent pixels into their neighbors, and a frame with transparent parts (from
+/// `Renderer`, or read by a post-process shader) composites correctly as is.
+pub const BLEND: wgpu::BlendState = wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING;
+
+/// How long blinking things (like text carets) stay on, then off.
+pub const BLINK_INTERVAL: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// How far, in pixels, the triangles of a filled path may stray from its curves.
+const PATH_TOLERANCE: f32 = 0.1;
+
+/// Size of the GPU vertex buffer the queue is uploaded into.
+pub const MAX_VERTICES: usize = 16384;
+/// Size of the GPU index buffer the queue is uploaded into (one quad per 6 indices).
+pub const MAX_INDICES: usize = 24576;
+
+/// Empties `areas` and returns its memory as a list that can borrow buffers for a different
+/// lifetime. Collecting into a vector of the same element type reuses the allocation.
+fn recycle_text_areas<'b>(mut areas: Vec<glyphon::TextArea<'_>>) -> Vec<glyphon::TextArea<'b>> {
+    areas.clear();
+    areas.into_iter().map(|_| unreachable!()).collect()
+}
+
+/// Moves text areas from layout pixels to the physical pixels glyphon places them in,
+/// unlike the rest of the frame which the projection scales. With `snap`, each area starts
+/// on a whole pixel.
+pub(crate) fn scale_text_areas(areas: &mut [glyphon::TextArea], scale: f32, snap: bool) {
+    if scale == 1.0 && !snap {
+        return;
+    }
+    for area in areas {
+        area.left *= scale;
+        area.top *= scale;
+        if snap {
+            area.left = area.left.round();
+            area.top = area.top.round();
+        }
+        area.scale *= scale;
+        let bounds = &mut area.bounds;
+        bounds.left = (bounds.left as f32 * scale).floor() as i32;
+        bounds.top = (bounds.top as f32 * scale).floor() as i32;
+        bounds.right = (bounds.right as f32 * scale).ceil() as i32;
+        bounds.bottom = (bounds.bottom as f32 * scale).ceil() as i32;
+    }
+}
+
+/// A primitive as pushed to the queue, in window coordinates, for exporting a frame to a
+/// vector format instead of drawing it.
+#[derive(Debug, Clone)]
+pub(crate) enum Shape {
+    /// A rect, with rounded corners if `radius` is above zero.
+    Rect { geometry: crate::view::Geometry, color: [f32; 4], radius: f32 },
+    /// The largest circle centered in `geometry`.
+    Circle { geometry: crate::view::Geometry, color: [f32; 4] },
+    /// A ring segment as pushed by `push_arc`, with angles in degrees.
+    Arc { geometry: crate::view::Geometry, color: [f32; 4], thickness: f32, start_angle: f32, sweep: f32 },
+    LinearGradient { geometry: crate::view::Geometry, from: [f32; 4], to: [f32; 4], angle: f32 },
+    Polygon { points: Vec<[f32; 2]>, color: [f32; 4] },
+    Path { commands: Vec<PathCommand>, fill_rule: FillRule, color: [f32; 4] },
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct RecordedShape {
+    pub shape: Shape,
+    pub z_index: i32,
+    pub clip: Option<Clip>,
+}
+
 pub struct RenderQueue {
     pub vertices: Vec<Vertex>,
     pub indices: Vec<u16>,
+    z_index: i32,
+    spans: Vec<ZSpan>,
+    clip: Option<Clip>,
+    transform: Transform,
+    edge_softness: f32,
+    primitives: usize,
+    dropped: usize,
+    /// Shapes pushed since `start_recording`, including any that didn't fit in the batch.
+    recording: Option<Vec<RecordedShape>>,
+}
+
+impl Default for RenderQueue {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl RenderQueue {
@@ -65,22 +338,295 @@ impl RenderQueue {
         Self {
             vertices: Vec::with_capacity(1024),
             indices: Vec::with_capacity(1536),
+            z_index: 0,
+            spans: Vec::new(),
+            clip: None,
+            transform: Transform::IDENTITY,
+            edge_softness: 0.0,
+            primitives: 0,
+            dropped: 0,
+            recording: None,
+        }
+    }
+
+    /// Number of primitives (shapes, triangles, polygons) queued this frame.
+    pub fn len(&self) -> usize {
+        self.primitives
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Maximum number of rects, rounded rects or circles that fit in one frame's batch.
+    /// Triangles and polygons use up a different share of it.
+    pub fn capacity(&self) -> usize {
+        (MAX_VERTICES / 4).min(MAX_INDICES / 6)
+    }
+
+    /// Number of primitives that didn't fit in the batch this frame. These are not drawn.
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
+
+    /// Whether more primitives were pushed this frame than the batch can hold.
+    pub fn is_over_capacity(&self) -> bool {
+        self.dropped > 0
+    }
+
+    /// Sets the z-index applied to subsequently pushed primitives and returns the previous one.
+    pub fn set_z_index(&mut self, z_index: i32) -> i32 {
+        std::mem::replace(&mut self.z_index, z_index)
+    }
+
+    pub fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    /// Clips subsequently pushed primitives to `clip` and returns the previous clip. `clip` is
+    /// given in the current transform's coordinates and stored transformed, so it stays put
+    /// if the transform changes later.
+    pub fn set_clip(&mut self, clip: Option<Clip>) -> Option<Clip> {
+        let clip = clip.map(|clip| Clip { geometry: self.transform.apply_geometry(clip.geometry), radius: clip.radius * self.transform.scale });
+        std::mem::replace(&mut self.clip, clip)
+    }
+
+    /// Transforms subsequently pushed primitives by `transform` and returns the previous
+    /// transform. Nested views should compose with the current one using `Transform::then`.
+    pub fn set_transform(&mut self, transform: Transform) -> Transform {
+        std::mem::replace(&mut self.transform, transform)
+    }
+
+    pub fn transform(&self) -> Transform {
+        self.transform
+    }
+
+    /// Widens the fade at the edges of subsequently pushed rects, rounded rects and circles
+    /// by `softness` pixels, for soft or glowing shapes, and returns the previous softness.
+    /// Zero, the default, keeps edges crisp with a one pixel anti-aliasing fade.
+    pub fn set_edge_softness(&mut self, softness: f32) -> f32 {
+        std::mem::replace(&mut self.edge_softness, softness.max(0.0))
+    }
+
+    pub fn edge_softness(&self) -> f32 {
+        self.edge_softness
+    }
+
+    /// Reorders the index buffer so primitives draw in ascending z-index.
+    /// The sort is stable, so primitives with equal z keep their submission order.
+    pub fn sort_by_z(&mut self) {
+        if self.spans.windows(2).all(|w| w[0].z_index <= w[1].z_index) {
+            return;
+        }
+        self.spans.sort_by_key(|span| span.z_index);
+        let mut sorted = Vec::with_capacity(self.indices.len());
+        for span in &mut self.spans {
+            let start = sorted.len();
+            sorted.extend_from_slice(&self.indices[span.start..span.start + span.len]);
+            span.start = start;
+        }
+        self.indices = sorted;
+    }
+
+    /// Keeps a copy of every shape pushed from now on, until `take_recording`.
+    pub(crate) fn start_recording(&mut self) {
+        self.recording = Some(Vec::new());
+    }
+
+    /// Stops recording and returns the recorded shapes in submission order.
+    pub(crate) fn take_recording(&mut self) -> Vec<RecordedShape> {
+        self.recording.take().unwrap_or_default()
+    }
+
+    fn record_shape(&mut self, shape: Shape) {
+        let (z_index, clip) = (self.z_index, self.clip);
+        if let Some(recording) = &mut self.recording {
+            recording.push(RecordedShape { shape, z_index, clip });
+        }
+    }
+
+    fn record_span(&mut self, start: usize) {
+        let len = self.indices.len() - start;
+        match self.spans.last_mut() {
+            Some(last) if last.z_index == self.z_index && last.start + last.len == start => last.len += len,
+            _ => self.spans.push(ZSpan { z_index: self.z_index, start, len }),
         }
     }
 
     pub fn push_rect(&mut self, geometry: crate::view::Geometry, color: [f32; 4]) {
-        self.push_raw(geometry, color, 0.0, 0.0);
+        self.push_raw(geometry, color, 0.0, 0.0, [0.0; 2]);
     }
 
     pub fn push_rounded_rect(&mut self, geometry: crate::view::Geometry, color: [f32; 4], radius: f32) {
-        self.push_raw(geometry, color, radius, 1.0);
+        self.push_raw(geometry, color, radius, 1.0, [0.0; 2]);
     }
 
     pub fn push_circle(&mut self, geometry: crate::view::Geometry, color: [f32; 4]) {
-        self.push_raw(geometry, color, 0.0, 2.0);
+        self.push_raw(geometry, color, 0.0, 2.0, [0.0; 2]);
     }
 
-    fn push_raw(&mut self, geometry: crate::view::Geometry, color: [f32; 4], radius: f32, shape: f32) {
+    /// Pushes a segment of a ring `thickness` pixels wide, lining the inside of the largest
+    /// circle centered in `geometry`. It starts at `start_angle` and runs `sweep` degrees,
+    /// with 0 pointing up and angles turning clockwise as in `push_linear_gradient`. Its ends
+    /// are rounded; a sweep of 360 or more is a full ring.
+    pub fn push_arc(&mut self, geometry: crate::view::Geometry, color: [f32; 4], thickness: f32, start_angle: f32, sweep: f32) {
+        let arc = [start_angle.to_radians(), sweep.clamp(0.0, 360.0).to_radians()];
+        self.push_raw(geometry, color, thickness, SHAPE_ARC, arc);
+    }
+
+    /// Pushes a rect filled with a linear gradient from `from` to `to`. `angle` is in degrees,
+    /// as in `Background::LinearGradient`.
+    pub fn push_linear_gradient(&mut self, geometry: crate::view::Geometry, from: [f32; 4], to: [f32; 4], angle: f32) {
+        let geometry = self.transform.apply_geometry(geometry);
+        self.record_shape(Shape::LinearGradient { geometry, from, to, angle });
+        if !self.reserve(4, 6) {
+            return;
+        }
+        let crate::view::Geometry { x, y, width: w, height: h } = geometry;
+        // Gradient direction, with 0 degrees pointing up and angles turning clockwise
+        let (sin, cos) = angle.to_radians().sin_cos();
+        let direction = [sin, -cos];
+        // Half the gradient line's length, chosen so the corners get exactly `from` and `to`
+        let half_length = (w * direction[0].abs() + h * direction[1].abs()) * 0.5;
+        let center = [x + w * 0.5, y + h * 0.5];
+        let color_at = |position: [f32; 2]| {
+            let along = (position[0] - center[0]) * direction[0] + (position[1] - center[1]) * direction[1];
+            let t = if half_length > 0.0 { (along / half_length * 0.5 + 0.5).clamp(0.0, 1.0) } else { 0.0 };
+            std::array::from_fn(|i| from[i] + (to[i] - from[i]) * t)
+        };
+
+        let (clip_rect, clip_radius) = self.clip_params();
+        let start_index = self.vertices.len() as u16;
+        let vertex = |position| Vertex {
+            position,
+            color: Color(color_at(position)).premultiplied(),
+            rect_pos: [x, y],
+            rect_size: [w, h],
+            corner_radius: 0.0,
+            shape_type: SHAPE_FLAT,
+            clip_rect,
+            clip_radius,
+            edge_softness: 0.0,
+            arc: [0.0; 2],
+        };
+        self.vertices.extend_from_slice(&[
+            vertex([x, y]),
+            vertex([x + w, y]),
+            vertex([x, y + h]),
+            vertex([x + w, y + h]),
+        ]);
+
+        let start = self.indices.len();
+        self.indices.extend_from_slice(&[
+            start_index, start_index + 1, start_index + 2,
+            start_index + 2, start_index + 1, start_index + 3,
+        ]);
+        self.record_span(start);
+    }
+
+    /// Whether a primitive of `vertices` and `indices` still fits; counts it as dropped if not.
+    fn reserve(&mut self, vertices: usize, indices: usize) -> bool {
+        if self.vertices.len() + vertices > MAX_VERTICES || self.indices.len() + indices > MAX_INDICES {
+            self.dropped += 1;
+            return false;
+        }
+        self.primitives += 1;
+        true
+    }
+
+    /// Pushes a flat-colored triangle. Edges are not anti-aliased.
+    pub fn push_triangle(&mut self, p0: [f32; 2], p1: [f32; 2], p2: [f32; 2], color: [f32; 4]) {
+        self.push_polygon(&[p0, p1, p2], color);
+    }
+
+    /// Pushes a flat-colored convex polygon, fanned into triangles from its first point.
+    /// Concave polygons draw incorrectly. Edges are not anti-aliased.
+    pub fn push_polygon(&mut self, points: &[[f32; 2]], color: [f32; 4]) {
+        if points.len() < 3 {
+            return;
+        }
+        let points: Vec<[f32; 2]> = points.iter().map(|&point| self.transform.apply(point)).collect();
+        self.record_shape(Shape::Polygon { points: points.clone(), color });
+        if !self.reserve(points.len(), (points.len() - 2) * 3) {
+            return;
+        }
+        let (min, max) = points.iter().fold(([f32::MAX; 2], [f32::MIN; 2]), |(min, max), p| {
+            ([min[0].min(p[0]), min[1].min(p[1])], [max[0].max(p[0]), max[1].max(p[1])])
+        });
+        let (clip_rect, clip_radius) = self.clip_params();
+        let start_index = self.vertices.len() as u16;
+        let color = Color(color).premultiplied();
+        self.vertices.extend(points.iter().map(|&position| Vertex {
+            position,
+            color,
+            rect_pos: min,
+            rect_size: [max[0] - min[0], max[1] - min[1]],
+            corner_radius: 0.0,
+            shape_type: SHAPE_FLAT,
+            clip_rect,
+            clip_radius,
+            edge_softness: 0.0,
+            arc: [0.0; 2],
+        }));
+
+        let start = self.indices.len();
+        for i in 1..points.len() as u16 - 1 {
+            self.indices.extend_from_slice(&[start_index, start_index + i, start_index + i + 1]);
+        }
+        self.record_span(start);
+    }
+
+    /// Pushes a path filled with `color`, with its curves flattened into triangles. Parts
+    /// where it crosses itself are filled by `fill_rule`. Edges are not anti-aliased.
+    pub fn push_path(&mut self, commands: &[PathCommand], fill_rule: FillRule, color: [f32; 4]) {
+        let commands: Vec<PathCommand> = commands.iter().map(|command| command.map_points(|point| self.transform.apply(point))).collect();
+        let Some([min_x, min_y, max_x, max_y]) = path::bounds(&commands) else { return };
+        let Some((points, indices)) = path::tessellate(&commands, fill_rule, PATH_TOLERANCE) else { return };
+        self.record_shape(Shape::Path { commands, fill_rule, color });
+        if points.is_empty() || !self.reserve(points.len(), indices.len()) {
+            return;
+        }
+        let (clip_rect, clip_radius) = self.clip_params();
+        let start_index = self.vertices.len() as u16;
+        let color = Color(color).premultiplied();
+        self.vertices.extend(points.iter().map(|&position| Vertex {
+            position,
+            color,
+            rect_pos: [min_x, min_y],
+            rect_size: [max_x - min_x, max_y - min_y],
+            corner_radius: 0.0,
+            shape_type: SHAPE_FLAT,
+            clip_rect,
+            clip_radius,
+            edge_softness: 0.0,
+            arc: [0.0; 2],
+        }));
+
+        let start = self.indices.len();
+        self.indices.extend(indices.iter().map(|&index| start_index + index));
+        self.record_span(start);
+    }
+
+    fn clip_params(&self) -> ([f32; 4], f32) {
+        match self.clip {
+            Some(clip) => ([clip.geometry.x, clip.geometry.y, clip.geometry.width, clip.geometry.height], clip.radius),
+            None => ([0.0; 4], 0.0),
+        }
+    }
+
+    fn push_raw(&mut self, geometry: crate::view::Geometry, color: [f32; 4], radius: f32, shape: f32, arc: [f32; 2]) {
+        let geometry = self.transform.apply_geometry(geometry);
+        let radius = radius * self.transform.scale;
+        self.record_shape(if shape == 2.0 {
+            Shape::Circle { geometry, color }
+        } else if shape == SHAPE_ARC {
+            Shape::Arc { geometry, color, thickness: radius, start_angle: arc[0].to_degrees(), sweep: arc[1].to_degrees() }
+        } else {
+            Shape::Rect { geometry, color, radius }
+        });
+        if !self.reserve(4, 6) {
+            return;
+        }
         let x = geometry.x;
         let y = geometry.y;
         let w = geometry.width;
@@ -89,23 +635,254 @@ impl RenderQueue {
 
         let rect_pos = [x, y];
         let rect_size = [w, h];
+        let (clip_rect, clip_radius) = self.clip_params();
+        let edge_softness = self.edge_softness * self.transform.scale;
+        let color = Color(color).premultiplied();
+        let vertex = |position| Vertex { position, color, rect_pos, rect_size, corner_radius: radius, shape_type: shape, clip_rect, clip_radius, edge_softness, arc };
 
+        // The shader fades edges out over the pixel they fall in plus any softness, half of
+        // which lies outside the shape, so the quad gets a margin to cover it
+        let m = AA_MARGIN + edge_softness * 0.5;
         self.vertices.extend_from_slice(&[
-            Vertex { position: [x, y], color, rect_pos, rect_size, corner_radius: radius, shape_type: shape },
-            Vertex { position: [x + w, y], color, rect_pos, rect_size, corner_radius: radius, shape_type: shape },
-            Vertex { position: [x, y + h], color, rect_pos, rect_size, corner_radius: radius, shape_type: shape },
-            Vertex { position: [x + w, y + h], color, rect_pos, rect_size, corner_radius: radius, shape_type: shape },
+            vertex([x - m, y - m]),
+            vertex([x + w + m, y - m]),
+            vertex([x - m, y + h + m]),
+            vertex([x + w + m, y + h + m]),
         ]);
 
+        let start = self.indices.len();
         self.indices.extend_from_slice(&[
             start_index, start_index + 1, start_index + 2,
             start_index + 2, start_index + 1, start_index + 3,
         ]);
+        self.record_span(start);
     }
 
     pub fn clear(&mut self) {
         self.vertices.clear();
         self.indices.clear();
+        self.spans.clear();
+        self.z_index = 0;
+        self.clip = None;
+        self.transform = Transform::IDENTITY;
+        self.edge_softness = 0.0;
+        self.primitives = 0;
+        self.dropped = 0;
+        if let Some(recording) = &mut self.recording {
+            recording.clear();
+        }
+    }
+}
+
+/// Statistics about the most recent frame, readable by widgets through `RenderContext::stats`.
+#[derive(Debug, Clone, Copy)]
+pub struct FrameStats {
+    pub fps: f32,
+    /// Time between the starts of the last two frames, in milliseconds.
+    pub frame_time_ms: f32,
+    /// The present mode the surface was actually configured with.
+    pub present_mode: wgpu::PresentMode,
+    /// The backend of the adapter selected at startup.
+    pub backend: Option<wgpu::Backend>,
+    /// Primitives drawn in the last frame.
+    pub primitives: usize,
+    /// Primitives that didn't fit in the render queue last frame and were skipped.
+    pub dropped_primitives: usize,
+    /// Vertices and indices uploaded for the last frame.
+    pub vertices: usize,
+    pub indices: usize,
+    /// Text areas passed to the text renderer last frame.
+    pub text_areas: usize,
+    /// Glyphs in those text areas.
+    pub glyphs: usize,
+    /// Texts shaped last frame because they are new or changed; unchanged text reuses its
+    /// shaped buffer, so this stays at zero while nothing changes.
+    pub shaped_texts: usize,
+    /// Font attributes built last frame. Texts keep theirs until their font settings
+    /// change, so new text alone doesn't add to this.
+    pub built_attrs: usize,
+    /// Frames whose glyphs didn't fit in the glyph atlas even at its largest size and after
+    /// evicting unused glyphs. Their text was not drawn.
+    pub atlas_overflows: usize,
+}
+
+impl Default for FrameStats {
+    fn default() -> Self {
+        Self {
+            fps: 0.0,
+            frame_time_ms: 0.0,
+            present_mode: wgpu::PresentMode::Fifo,
+            backend: None,
+            primitives: 0,
+            dropped_primitives: 0,
+            vertices: 0,
+            indices: 0,
+            text_areas: 0,
+            glyphs: 0,
+            shaped_texts: 0,
+            built_attrs: 0,
+            atlas_overflows: 0,
+        }
+    }
+}
+
+/// Whether `rgba` holds exactly `width` x `height` RGBA8 pixels.
+fn is_rgba_size(width: u32, height: u32, rgba: &[u8]) -> bool {
+    width > 0 && height > 0 && rgba.len() == width as usize * height as usize * 4
+}
+
+/// Uploads RGBA8 sRGB pixels to a new texture and returns a view of it with a linear sampler.
+fn upload_rgba(device: &wgpu::Device, queue: &wgpu::Queue, width: u32, height: u32, rgba: &[u8]) -> (wgpu::TextureView, wgpu::Sampler) {
+    use wgpu::util::DeviceExt;
+
+    let texture = device.create_texture_with_data(
+        queue,
+        &wgpu::TextureDescriptor {
+            label: Some("Image"),
+            size: wgpu::Extent3d { width, height, depth_or_array_layers: 1 },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8UnormSrgb,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        },
+        wgpu::util::TextureDataOrder::LayerMajor,
+        rgba,
+    );
+    let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+        label: Some("Image Sampler"),
+        mag_filter: wgpu::FilterMode::Linear,
+        min_filter: wgpu::FilterMode::Linear,
+        ..Default::default()
+    });
+    (texture.create_view(&wgpu::TextureViewDescriptor::default()), sampler)
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Pod, Zeroable)]
+struct TextureVertex {
+    position: [f32; 2],
+    uv: [f32; 2],
+    rect_pos: [f32; 2],
+    rect_size: [f32; 2],
+    corner_radius: f32,
+    tint: [f32; 4],
+    /// 0 multiplies by `tint`, 1 blends it over (see `TintMode`).
+    tint_mode: f32,
+}
+
+impl TextureVertex {
+    const ATTRIBUTES: [wgpu::VertexAttribute; 7] = wgpu::vertex_attr_array![
+        0 => Float32x2, 1 => Float32x2, 2 => Float32x2, 3 => Float32x2, 4 => Float32, 5 => Float32x4, 6 => Float32,
+    ];
+
+    fn desc() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<TextureVertex>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Vertex,
+            attributes: &Self::ATTRIBUTES,
+        }
+    }
+}
+
+/// An image uploaded to the GPU with `RenderContext::create_texture`, drawn by a view from
+/// its `render` method. Each texture holds the quad it was last placed at, so a texture
+/// shown in several places at once needs one copy per place.
+pub struct Texture {
+    pub width: u32,
+    pub height: u32,
+    bind_group: wgpu::BindGroup,
+    vertex_buffer: wgpu::Buffer,
+    /// Vertices written by the last `place_texture` or `place_texture_slices`.
+    vertex_count: std::cell::Cell<u32>,
+    tint: std::cell::Cell<([f32; 4], TintMode)>,
+}
+
+/// How a texture's tint color combines with its pixels (see `Texture::set_tint`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TintMode {
+    /// Multiplies each pixel by the tint, alpha included: white leaves the image as is,
+    /// gray darkens it and a translucent tint fades it out.
+    #[default]
+    Multiply,
+    /// Blends the tint over the image by the tint's alpha, keeping the image's own alpha,
+    /// e.g. a translucent highlight on a selected icon.
+    Overlay,
+}
+
+impl Texture {
+    /// Tints the texture from its next `place_texture` (or `place_texture_slices`) on, e.g.
+    /// graying out a disabled icon. Textures start with white in `Multiply` mode, no tint.
+    pub fn set_tint(&self, color: [f32; 4], mode: TintMode) {
+        self.tint.set((color, mode));
+    }
+
+    /// Tint and mode as vertex attributes.
+    fn tint_attributes(&self) -> ([f32; 4], f32) {
+        let (color, mode) = self.tint.get();
+        (color, if mode == TintMode::Overlay { 1.0 } else { 0.0 })
+    }
+}
+
+/// Quads a texture can be placed as at once; enough for a nine-patch.
+const MAX_TEXTURE_QUADS: usize = 9;
+
+/// GPU resources for an image background, drawn as a full-window triangle.
+struct BackgroundImage {
+    pipeline: wgpu::RenderPipeline,
+    bind_group: wgpu::BindGroup,
+    uniform_buffer: wgpu::Buffer,
+    size: [f32; 2],
+}
+
+/// GPU resources for a post-process shader, run over the finished frame as a full-window
+/// triangle.
+struct PostProcess {
+    pipeline: wgpu::RenderPipeline,
+    bind_group_layout: wgpu::BindGroupLayout,
+    sampler: wgpu::Sampler,
+    uniform_buffer: wgpu::Buffer,
+    /// The texture the frame is drawn into and the bind group sampling it, recreated when
+    /// the target size changes.
+    frame: Option<(wgpu::Texture, wgpu::TextureView, wgpu::BindGroup)>,
+}
+
+/// Font fallback that tries the app's chosen families first, for every script, then the
+/// platform's defaults.
+struct FallbackChain {
+    families: Vec<&'static str>,
+    common: Vec<&'static str>,
+    platform: PlatformFallback,
+    /// Per-script lists, built on first use. cosmic-text needs `'static` names and asks for
+    /// each script once per font system, so leaking them is bounded.
+    scripts: Mutex<HashMap<Script, &'static [&'static str]>>,
+}
+
+impl FallbackChain {
+    fn new(families: &[&str]) -> Self {
+        let families: Vec<&'static str> = families.iter().map(|family| &*Box::leak(family.to_string().into_boxed_str())).collect();
+        let platform = PlatformFallback;
+        let common = families.iter().chain(platform.common_fallback()).copied().collect();
+        Self { families, common, platform, scripts: Mutex::new(HashMap::new()) }
+    }
+}
+
+impl Fallback for FallbackChain {
+    fn common_fallback(&self) -> &[&'static str] {
+        &self.common
+    }
+
+    fn forbidden_fallback(&self) -> &[&'static str] {
+        self.platform.forbidden_fallback()
+    }
+
+    fn script_fallback(&self, script: Script, locale: &str) -> &[&'static str] {
+        let mut scripts = self.scripts.lock().unwrap();
+        scripts.entry(script).or_insert_with(|| {
+            let list: Vec<&'static str> = self.families.iter().chain(self.platform.script_fallback(script, locale)).copied().collect();
+            Box::leak(list.into_boxed_slice())
+        })
     }
 }
 
@@ -126,7 +903,35 @@ pub struct RenderContext {
     pub text_renderer: TextRenderer,
     pub viewport: Viewport,
     pub debug_buffer: glyphon::Buffer,
+    /// Outlines containers and widgets to show how the view was laid out. Off by default;
+    /// `App::with_debug` turns it on.
     pub debug: bool,
+    /// Draws the frame rate in the top-left corner of an `App`'s window. Off by default;
+    /// `App::with_fps_counter` turns it on.
+    pub show_fps: bool,
+    /// Rounds where each text block starts to whole physical pixels. Glyphs are rasterized
+    /// at quarter-pixel offsets, so text laid out at fractional positions (e.g. centered in
+    /// an odd width) comes out softer than the same text on a pixel boundary. Glyphs within
+    /// a line keep their subpixel spacing either way. Off by default.
+    pub snap_text: bool,
+    /// Lets the glyph atlas reuse the space of glyphs the previous frame didn't draw. The
+    /// atlas doubles in size as it fills and evicts least recently used glyphs first, so
+    /// text with many distinct glyphs keeps fitting. Turning this off keeps every glyph
+    /// rasterized until the atlas is full, then evicts them all at once. On by default.
+    pub evict_unused_glyphs: bool,
+    pub stats: FrameStats,
+    /// The previous frame's text area list, emptied, so the next frame can reuse its memory.
+    text_areas: Vec<glyphon::TextArea<'static>>,
+    redraw_requested: bool,
+    redraw_at: Option<std::time::Instant>,
+    /// When the shared blink clock last started an "on" phase.
+    blink_epoch: std::time::Instant,
+    ime_cursor_area: Option<crate::view::Geometry>,
+    surface_format: wgpu::TextureFormat,
+    background_image: Option<BackgroundImage>,
+    post_process: Option<PostProcess>,
+    texture_pipeline: wgpu::RenderPipeline,
+    texture_bind_group_layout: wgpu::BindGroupLayout,
 }
 
 impl RenderContext {
@@ -135,6 +940,14 @@ impl RenderContext {
         queue: wgpu::Queue,
         surface_config: &wgpu::SurfaceConfiguration,
     ) -> Self {
+        let mut ctx = Self::with_format(device, queue, surface_config.format);
+        ctx.stats.present_mode = surface_config.present_mode;
+        ctx
+    }
+
+    /// Creates a context drawing into textures of `format`, without a window surface, e.g.
+    /// for a `Renderer` embedded in another wgpu app.
+    pub fn with_format(device: wgpu::Device, queue: wgpu::Queue, format: wgpu::TextureFormat) -> Self {
         let shader = device.create_shader_module(wgpu::include_wgsl!("shaders/view.wgsl"));
 
         let uniform_buffer = device.create_buffer(&wgpu::BufferDescriptor {
@@ -170,14 +983,14 @@ impl RenderContext {
         // Pre-allocate buffers for batching (large enough for most UIs)
         let vertex_buffer = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("Batch Vertex Buffer"),
-            size: (std::mem::size_of::<Vertex>() * 16384) as u64, // 16384 vertices
+            size: (std::mem::size_of::<Vertex>() * MAX_VERTICES) as u64,
             usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
             mapped_at_creation: false,
         });
 
         let index_buffer = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("Batch Index Buffer"),
-            size: (std::mem::size_of::<u16>() * 24576) as u64, // 24576 indices (4096 quads)
+            size: (std::mem::size_of::<u16>() * MAX_INDICES) as u64,
             usage: wgpu::BufferUsages::INDEX | wgpu::BufferUsages::COPY_DST,
             mapped_at_creation: false,
         });
@@ -201,8 +1014,8 @@ impl RenderContext {
                 module: &shader,
                 entry_point: Some("fs_main"),
                 targets: &[Some(wgpu::ColorTargetState {
-                    format: surface_config.format,
-                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                    format,
+                    blend: Some(BLEND),
                     write_mask: wgpu::ColorWrites::ALL,
                 })],
                 compilation_options: Default::default(),
@@ -217,11 +1030,65 @@ impl RenderContext {
             cache: None,
         });
 
+        // Textured quads share the projection bind group and add a texture one
+        let texture_shader = device.create_shader_module(wgpu::include_wgsl!("shaders/image.wgsl"));
+        let texture_bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Texture Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        });
+        let texture_pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Texture Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout, &texture_bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        let texture_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Texture Pipeline"),
+            layout: Some(&texture_pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &texture_shader,
+                entry_point: Some("vs_main"),
+                buffers: &[TextureVertex::desc()],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &texture_shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format,
+                    blend: Some(BLEND),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+            cache: None,
+        });
+
         // Initialize glyphon
         let mut font_system = FontSystem::new();
         let swash_cache = SwashCache::new();
         let cache = Cache::new(&device);
-        let mut text_atlas = TextAtlas::new(&device, &queue, &cache, surface_config.format);
+        let mut text_atlas = TextAtlas::new(&device, &queue, &cache, format);
         let text_renderer = TextRenderer::new(
             &mut text_atlas,
             &device,
@@ -246,7 +1113,671 @@ impl RenderContext {
             text_renderer,
             viewport,
             debug_buffer,
-            debug: true,
+            debug: false,
+            show_fps: false,
+            snap_text: false,
+            evict_unused_glyphs: true,
+            stats: FrameStats::default(),
+            text_areas: Vec::new(),
+            redraw_requested: false,
+            redraw_at: None,
+            blink_epoch: std::time::Instant::now(),
+            ime_cursor_area: None,
+            surface_format: format,
+            background_image: None,
+            post_process: None,
+            texture_pipeline,
+            texture_bind_group_layout,
+        }
+    }
+
+    /// Uploads `rgba` (RGBA8 sRGB pixels, row by row) for drawing with `draw_texture`.
+    /// Returns `None` if the pixels don't add up to `width` x `height`.
+    pub fn create_texture(&self, width: u32, height: u32, rgba: &[u8]) -> Option<Texture> {
+        if !is_rgba_size(width, height, rgba) {
+            tracing::warn!("Ignoring texture: expected {}x{} RGBA pixels, got {} bytes", width, height, rgba.len());
+            return None;
+        }
+        let (texture_view, sampler) = upload_rgba(&self.device, &self.queue, width, height, rgba);
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Texture Bind Group"),
+            layout: &self.texture_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(&texture_view) },
+                wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::Sampler(&sampler) },
+            ],
+        });
+        let vertex_buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Texture Vertex Buffer"),
+            size: (std::mem::size_of::<TextureVertex>() * 6 * MAX_TEXTURE_QUADS) as u64,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        Some(Texture {
+            width,
+            height,
+            bind_group,
+            vertex_buffer,
+            vertex_count: std::cell::Cell::new(0),
+            tint: std::cell::Cell::new(([1.0; 4], TintMode::Multiply)),
+        })
+    }
+
+    /// Places `texture` at `geometry` for this frame, scaled to cover it (cropping the
+    /// overflow evenly) and masked to a rounded rect of `radius`. Call during `prepare`;
+    /// the render queue's current transform applies, its clip doesn't.
+    pub fn place_texture(&self, texture: &Texture, geometry: crate::view::Geometry, radius: f32) {
+        let transform = self.render_queue.transform();
+        let crate::view::Geometry { x, y, width: w, height: h } = transform.apply_geometry(geometry);
+        let radius = (radius * transform.scale).min(w * 0.5).min(h * 0.5).max(0.0);
+
+        // Cover: show the centered part of the image with the geometry's aspect ratio
+        let image_aspect = texture.width as f32 / texture.height as f32;
+        let aspect = if h > 0.0 { w / h } else { image_aspect };
+        let (u, v) = if aspect > image_aspect { (1.0, image_aspect / aspect) } else { (aspect / image_aspect, 1.0) };
+        let (u0, v0) = ((1.0 - u) * 0.5, (1.0 - v) * 0.5);
+        let (u1, v1) = (u0 + u, v0 + v);
+
+        let (tint, tint_mode) = texture.tint_attributes();
+        let vertex = |position, uv| TextureVertex { position, uv, rect_pos: [x, y], rect_size: [w, h], corner_radius: radius, tint, tint_mode };
+        let top_left = vertex([x, y], [u0, v0]);
+        let top_right = vertex([x + w, y], [u1, v0]);
+        let bottom_left = vertex([x, y + h], [u0, v1]);
+        let bottom_right = vertex([x + w, y + h], [u1, v1]);
+        let vertices = [top_left, top_right, bottom_left, bottom_left, top_right, bottom_right];
+        self.queue.write_buffer(&texture.vertex_buffer, 0, bytemuck::cast_slice(&vertices));
+        texture.vertex_count.set(vertices.len() as u32);
+    }
+
+    /// Places parts of `texture` for this frame: each slice stretches the region `uv`
+    /// (`[u0, v0, u1, v1]`, 0 to 1 across the image) over its geometry, e.g. the nine regions
+    /// of a `NinePatch`. Slices past the ninth are skipped. Like `place_texture`, the render
+    /// queue's transform applies and its clip doesn't.
+    pub fn place_texture_slices(&self, texture: &Texture, slices: &[(crate::view::Geometry, [f32; 4])]) {
+        let transform = self.render_queue.transform();
+        let slices: Vec<_> = slices.iter().take(MAX_TEXTURE_QUADS).map(|&(geometry, uv)| (transform.apply_geometry(geometry), uv)).collect();
+        // Mask to the slices' bounds rather than each slice, so edges between slices aren't antialiased into seams
+        let (min, max) = slices.iter().fold(([f32::MAX; 2], [f32::MIN; 2]), |(min, max), (g, _)| {
+            ([min[0].min(g.x), min[1].min(g.y)], [max[0].max(g.x + g.width), max[1].max(g.y + g.height)])
+        });
+        let (rect_pos, rect_size) = (min, [max[0] - min[0], max[1] - min[1]]);
+        let (tint, tint_mode) = texture.tint_attributes();
+        let mut vertices = Vec::with_capacity(slices.len() * 6);
+        for (crate::view::Geometry { x, y, width: w, height: h }, [u0, v0, u1, v1]) in slices {
+            let vertex = |position, uv| TextureVertex { position, uv, rect_pos, rect_size, corner_radius: 0.0, tint, tint_mode };
+            let top_left = vertex([x, y], [u0, v0]);
+            let top_right = vertex([x + w, y], [u1, v0]);
+            let bottom_left = vertex([x, y + h], [u0, v1]);
+            let bottom_right = vertex([x + w, y + h], [u1, v1]);
+            vertices.extend([top_left, top_right, bottom_left, bottom_left, top_right, bottom_right]);
+        }
+        if !vertices.is_empty() {
+            self.queue.write_buffer(&texture.vertex_buffer, 0, bytemuck::cast_slice(&vertices));
+        }
+        texture.vertex_count.set(vertices.len() as u32);
+    }
+
+    /// Draws `texture` where `place_texture` (or `place_texture_slices`) last put it. Call from `View::render`; textures
+    /// draw above the batched primitives and below text.
+    pub fn draw_texture<'rp>(&'rp self, texture: &'rp Texture, render_pass: &mut wgpu::RenderPass<'rp>) {
+        render_pass.set_pipeline(&self.texture_pipeline);
+        render_pass.set_bind_group(0, &self.bind_group, &[]);
+        render_pass.set_bind_group(1, &texture.bind_group, &[]);
+        render_pass.set_vertex_buffer(0, texture.vertex_buffer.slice(..));
+        render_pass.draw(0..texture.vertex_count.get(), 0..1);
+    }
+
+    /// Tries `families` in order for characters the requested font lacks, before the
+    /// platform's own fallback fonts, e.g. `&["Noto Color Emoji", "Noto Sans CJK JP"]`.
+    /// Families that aren't installed are skipped. Text already shaped keeps its fonts, so
+    /// set this before the first frame (see `App::with_font_fallbacks`).
+    pub fn set_font_fallbacks(&mut self, families: &[&str]) {
+        let placeholder = FontSystem::new_with_locale_and_db(String::new(), glyphon::fontdb::Database::new());
+        let (locale, db) = std::mem::replace(&mut self.font_system, placeholder).into_locale_and_db();
+        self.font_system = FontSystem::new_with_locale_and_db_and_fallback(locale, db, FallbackChain::new(families));
+    }
+
+    /// Adds a font file's contents (TTF, OTF or a collection) to the fonts text can use,
+    /// e.g. one bundled with the app to fall back on.
+    pub fn load_font_data(&mut self, data: Vec<u8>) {
+        self.font_system.db_mut().load_font_data(data);
+    }
+
+    /// The characters of `text` that no available font has a glyph for, i.e. that would
+    /// draw as empty boxes.
+    pub fn missing_glyphs(&mut self, text: &str) -> Vec<char> {
+        let mut buffer = glyphon::Buffer::new(&mut self.font_system, glyphon::Metrics::new(16.0, 20.0));
+        buffer.set_text(&mut self.font_system, text, &glyphon::Attrs::new().family(glyphon::Family::SansSerif), glyphon::Shaping::Advanced);
+        buffer.shape_until_scroll(&mut self.font_system, false);
+        let lines: Vec<&str> = text.lines().collect();
+        let mut missing = Vec::new();
+        for run in buffer.layout_runs() {
+            for glyph in run.glyphs.iter().filter(|glyph| glyph.glyph_id == 0) {
+                let line = lines.get(run.line_i).copied().unwrap_or_default();
+                missing.extend(line.get(glyph.start..glyph.end).into_iter().flat_map(str::chars));
+            }
+        }
+        missing
+    }
+
+    /// Uploads `rgba` (RGBA8 sRGB pixels, row by row) as the image drawn behind everything
+    /// by `render_background`. Pixels that don't add up to `width` x `height` are ignored.
+    pub fn set_background_image(&mut self, width: u32, height: u32, rgba: &[u8]) {
+        if !is_rgba_size(width, height, rgba) {
+            tracing::warn!("Ignoring background image: expected {}x{} RGBA pixels, got {} bytes", width, height, rgba.len());
+            self.background_image = None;
+            return;
+        }
+        let device = &self.device;
+        let shader = device.create_shader_module(wgpu::include_wgsl!("shaders/background.wgsl"));
+        let (texture_view, sampler) = upload_rgba(device, &self.queue, width, height, rgba);
+        let uniform_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Background Uniform Buffer"),
+            size: std::mem::size_of::<[f32; 4]>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Background Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        });
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Background Bind Group"),
+            layout: &bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: uniform_buffer.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::TextureView(&texture_view) },
+                wgpu::BindGroupEntry { binding: 2, resource: wgpu::BindingResource::Sampler(&sampler) },
+            ],
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Background Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Background Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                buffers: &[],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: self.surface_format,
+                    blend: Some(BLEND),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+            cache: None,
+        });
+
+        self.background_image = Some(BackgroundImage {
+            pipeline,
+            bind_group,
+            uniform_buffer,
+            size: [width as f32, height as f32],
+        });
+    }
+
+    /// Fits the background image to a window of `width` x `height`. Call before the render
+    /// pass that uses `render_background`.
+    pub fn update_background(&self, width: f32, height: f32) {
+        let Some(image) = &self.background_image else { return };
+        if width <= 0.0 || height <= 0.0 {
+            return;
         }
+        // Cover: fill the window along one axis and crop the other evenly on both sides
+        let window_aspect = width / height;
+        let image_aspect = image.size[0] / image.size[1];
+        let scale = if window_aspect > image_aspect {
+            [1.0, image_aspect / window_aspect]
+        } else {
+            [window_aspect / image_aspect, 1.0]
+        };
+        let uniforms = [scale[0], scale[1], (1.0 - scale[0]) * 0.5, (1.0 - scale[1]) * 0.5];
+        self.queue.write_buffer(&image.uniform_buffer, 0, bytemuck::cast_slice(&uniforms));
+    }
+
+    /// Runs `source`, a WGSL fragment shader, over every finished frame, e.g. for a vignette
+    /// or color grading. The frame is drawn into a texture first and the shader's output
+    /// replaces it. `None` goes back to drawing straight to the target.
+    ///
+    /// The source is appended to a prelude declaring `frame` (the drawn frame), its
+    /// `frame_sampler`, `post.size` (the frame size in physical pixels) and the vertex stage.
+    /// It must define the fragment stage as `fs_main(in: PostProcessInput)`, where `in.uv`
+    /// runs from 0,0 at the top-left to 1,1 at the bottom-right:
+    ///
+    /// `frame` holds premultiplied colors, and so should the output (see `BLEND`):
+    ///
+    /// ```wgsl
+    /// @fragment
+    /// fn fs_main(in: PostProcessInput) -> @location(0) vec4<f32> {
+    ///     let color = textureSample(frame, frame_sampler, in.uv);
+    ///     return vec4<f32>(vec3<f32>(dot(color.rgb, vec3<f32>(0.299, 0.587, 0.114))), color.a);
+    /// }
+    /// ```
+    ///
+    /// Returns the compilation error if the shader doesn't compile, keeping the previous one.
+    pub fn set_post_process(&mut self, source: Option<&str>) -> Result<(), wgpu::Error> {
+        let Some(source) = source else {
+            self.post_process = None;
+            return Ok(());
+        };
+        let device = &self.device;
+        device.push_error_scope(wgpu::ErrorFilter::Validation);
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Post Process Shader"),
+            source: wgpu::ShaderSource::Wgsl(format!("{}\n{}", include_str!("shaders/post_process.wgsl"), source).into()),
+        });
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Post Process Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        });
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Post Process Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Post Process Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                buffers: &[],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: self.surface_format,
+                    blend: Some(BLEND),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+            cache: None,
+        });
+        if let Some(err) = pollster::block_on(device.pop_error_scope()) {
+            return Err(err);
+        }
+
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("Post Process Sampler"),
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+        let uniform_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Post Process Uniform Buffer"),
+            size: std::mem::size_of::<[f32; 4]>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        self.post_process = Some(PostProcess { pipeline, bind_group_layout, sampler, uniform_buffer, frame: None });
+        Ok(())
+    }
+
+    /// With a post-process shader set, the texture to draw a `width` x `height` frame into
+    /// before `apply_post_process`; `None` otherwise, to draw straight to the target.
+    pub fn post_process_target(&mut self, width: u32, height: u32) -> Option<wgpu::TextureView> {
+        let post = self.post_process.as_mut()?;
+        let size = wgpu::Extent3d { width: width.max(1), height: height.max(1), depth_or_array_layers: 1 };
+        if post.frame.as_ref().is_none_or(|(texture, _, _)| texture.size() != size) {
+            let texture = self.device.create_texture(&wgpu::TextureDescriptor {
+                label: Some("Post Process Frame"),
+                size,
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: self.surface_format,
+                usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+                view_formats: &[],
+            });
+            let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+            let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Post Process Bind Group"),
+                layout: &post.bind_group_layout,
+                entries: &[
+                    wgpu::BindGroupEntry { binding: 0, resource: post.uniform_buffer.as_entire_binding() },
+                    wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::TextureView(&view) },
+                    wgpu::BindGroupEntry { binding: 2, resource: wgpu::BindingResource::Sampler(&post.sampler) },
+                ],
+            });
+            self.queue.write_buffer(&post.uniform_buffer, 0, bytemuck::cast_slice(&[width as f32, height as f32, 0.0, 0.0]));
+            post.frame = Some((texture, view, bind_group));
+        }
+        post.frame.as_ref().map(|(_, view, _)| view.clone())
+    }
+
+    /// Runs the post-process shader over the frame drawn into `post_process_target`,
+    /// writing the result to `target`, which is first loaded or cleared as `load` says.
+    pub fn apply_post_process(&self, encoder: &mut wgpu::CommandEncoder, target: &wgpu::TextureView, load: wgpu::LoadOp<wgpu::Color>) {
+        let Some(PostProcess { pipeline, frame: Some((_, _, bind_group)), .. }) = &self.post_process else { return };
+        let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Post Process Pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: target,
+                resolve_target: None,
+                ops: wgpu::Operations { load, store: wgpu::StoreOp::Store },
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        rpass.set_pipeline(pipeline);
+        rpass.set_bind_group(0, bind_group, &[]);
+        rpass.draw(0..3, 0..1);
+    }
+
+    /// An empty list to collect this frame's text areas into, reusing the memory of the list
+    /// last passed to `prepare_text` so text-heavy frames don't allocate it again.
+    pub fn take_text_areas<'a>(&mut self) -> Vec<glyphon::TextArea<'a>> {
+        recycle_text_areas(std::mem::take(&mut self.text_areas))
+    }
+
+    /// Uploads the glyphs of this frame's text, which must already be in physical pixels (see
+    /// `scale_text_areas`). If they don't fit in the glyph atlas the frame is drawn without
+    /// text and counted in `stats.atlas_overflows`, rather than failing.
+    pub fn prepare_text(&mut self, text_areas: Vec<glyphon::TextArea>) {
+        self.stats.glyphs = text_areas.iter()
+            .map(|area| area.buffer.layout_runs().map(|run| run.glyphs.len()).sum::<usize>())
+            .sum();
+
+        let mut trimmed = self.evict_unused_glyphs;
+        if trimmed {
+            self.text_atlas.trim();
+        }
+        loop {
+            let result = self.text_renderer.prepare(
+                &self.device,
+                &self.queue,
+                &mut self.font_system,
+                &mut self.text_atlas,
+                &self.viewport,
+                text_areas.iter().cloned(),
+                &mut self.swash_cache,
+            );
+            match result {
+                Ok(()) => break,
+                // Everything drawn so far may be evicted; try once more with only this frame's glyphs
+                Err(glyphon::PrepareError::AtlasFull) if !trimmed => {
+                    self.text_atlas.trim();
+                    trimmed = true;
+                }
+                Err(glyphon::PrepareError::AtlasFull) => {
+                    self.stats.atlas_overflows += 1;
+                    self.text_atlas.trim();
+                    let _ = self.text_renderer.prepare(
+                        &self.device,
+                        &self.queue,
+                        &mut self.font_system,
+                        &mut self.text_atlas,
+                        &self.viewport,
+                        std::iter::empty(),
+                        &mut self.swash_cache,
+                    );
+                    break;
+                }
+            }
+        }
+        self.text_areas = recycle_text_areas(text_areas);
+    }
+
+    /// Draws a prepared frame: the background image, the batched primitives, whatever `view`
+    /// draws itself and finally the text.
+    pub fn draw<'rp>(&'rp self, rpass: &mut wgpu::RenderPass<'rp>, view: &'rp dyn crate::view::View, geometry: crate::view::Geometry) {
+        self.render_background(rpass);
+
+        rpass.set_pipeline(&self.pipeline);
+        rpass.set_bind_group(0, &self.bind_group, &[]);
+
+        // Render batched primitives from queue
+        if !self.render_queue.vertices.is_empty() {
+            let v_len = self.render_queue.vertices.len();
+            let i_len = self.render_queue.indices.len();
+
+            // Safety check to avoid write_buffer overflow
+            let v_data = &self.render_queue.vertices[..v_len.min(MAX_VERTICES)];
+            let i_data = &self.render_queue.indices[..i_len.min(MAX_INDICES)];
+
+            self.queue.write_buffer(&self.vertex_buffer, 0, bytemuck::cast_slice(v_data));
+            self.queue.write_buffer(&self.index_buffer, 0, bytemuck::cast_slice(i_data));
+
+            rpass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+            rpass.set_index_buffer(self.index_buffer.slice(..), wgpu::IndexFormat::Uint16);
+            rpass.draw_indexed(0..i_data.len() as u32, 0, 0..1);
+        }
+
+        // Render widgets (for nested renders if any, though most now use queue)
+        view.render(self, rpass, geometry);
+
+        // Render text
+        self.text_renderer.render(&self.text_atlas, &self.viewport, rpass).unwrap();
+    }
+
+    /// Draws the background image, if any. Leaves a different pipeline bound.
+    pub fn render_background(&self, rpass: &mut wgpu::RenderPass) {
+        let Some(image) = &self.background_image else { return };
+        rpass.set_pipeline(&image.pipeline);
+        rpass.set_bind_group(0, &image.bind_group, &[]);
+        rpass.draw(0..3, 0..1);
+    }
+
+    /// Asks the app to draw another frame after this one, e.g. while an animation is running.
+    /// The event loop keeps polling for as long as some view asks for this every frame.
+    pub fn request_redraw(&mut self) {
+        self.redraw_requested = true;
+    }
+
+    /// Asks the app to draw a frame at `at`, e.g. when a timeout expires, while letting the
+    /// event loop sleep until then. The earliest request made during a frame wins.
+    pub fn request_redraw_at(&mut self, at: std::time::Instant) {
+        self.redraw_at = Some(self.redraw_at.map_or(at, |current| current.min(at)));
+    }
+
+    /// Whether blinking things should be shown this frame. Every caret reads the same clock,
+    /// so they blink in sync, and calling this schedules a redraw for the next transition
+    /// instead of redrawing continuously.
+    pub fn blink_phase(&mut self) -> bool {
+        let interval = BLINK_INTERVAL.as_secs_f64();
+        let phases = self.blink_epoch.elapsed().as_secs_f64() / interval;
+        let next = self.blink_epoch + std::time::Duration::from_secs_f64((phases.floor() + 1.0) * interval);
+        self.request_redraw_at(next);
+        (phases as u64).is_multiple_of(2)
+    }
+
+    /// Restarts the blink clock in its "on" phase, e.g. so a caret stays visible while typing.
+    pub fn restart_blink(&mut self) {
+        self.blink_epoch = std::time::Instant::now();
+    }
+
+    /// Called by a focused text field during `prepare` to enable the IME and place its
+    /// candidate window next to the caret.
+    pub fn set_ime_cursor_area(&mut self, area: crate::view::Geometry) {
+        self.ime_cursor_area = Some(area);
+    }
+
+    /// Returns the IME cursor area requested during the last frame and resets it.
+    pub fn take_ime_cursor_area(&mut self) -> Option<crate::view::Geometry> {
+        self.ime_cursor_area.take()
+    }
+
+    /// Returns whether a redraw was requested during the last frame and resets the flag.
+    pub fn take_redraw_request(&mut self) -> bool {
+        std::mem::take(&mut self.redraw_requested)
+    }
+
+    /// Returns the earliest timed redraw requested during the last frame and resets it.
+    pub fn take_redraw_at(&mut self) -> Option<std::time::Instant> {
+        self.redraw_at.take()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::view::Geometry;
+
+    const SQUARE: Geometry = Geometry { x: 0.0, y: 0.0, width: 10.0, height: 10.0 };
+
+    #[test]
+    fn overflow_is_counted_instead_of_drawn() {
+        let mut queue = RenderQueue::new();
+        for _ in 0..queue.capacity() {
+            queue.push_rect(SQUARE, [1.0; 4]);
+        }
+        assert_eq!(queue.len(), queue.capacity());
+        assert!(!queue.is_over_capacity());
+
+        for _ in 0..3 {
+            queue.push_rect(SQUARE, [1.0; 4]);
+        }
+        assert_eq!(queue.len(), queue.capacity());
+        assert_eq!(queue.dropped(), 3);
+        assert!(queue.is_over_capacity());
+        assert!(queue.vertices.len() <= MAX_VERTICES && queue.indices.len() <= MAX_INDICES);
+
+        queue.clear();
+        assert_eq!(queue.dropped(), 0);
+        assert!(!queue.is_over_capacity());
+    }
+
+    #[test]
+    fn polygons_fan_into_triangles() {
+        let mut queue = RenderQueue::new();
+        let hexagon: Vec<[f32; 2]> = (0..6)
+            .map(|i| {
+                let angle = i as f32 * std::f32::consts::TAU / 6.0;
+                [50.0 + 40.0 * angle.cos(), 50.0 + 40.0 * angle.sin()]
+            })
+            .collect();
+        queue.push_polygon(&hexagon, [1.0; 4]);
+        assert_eq!(queue.vertices.len(), 6);
+        assert_eq!(queue.indices.len(), (6 - 2) * 3);
+        // Every triangle fans out from the first point
+        assert!(queue.indices.chunks(3).all(|triangle| triangle[0] == 0));
+        assert_eq!(queue.len(), 1);
+
+        queue.push_triangle([0.0, 0.0], [10.0, 0.0], [0.0, 10.0], [1.0; 4]);
+        assert_eq!(queue.indices.len(), (6 - 2) * 3 + 3);
+    }
+
+    /// The color of each primitive's first vertex, in the order the index buffer draws them.
+    fn drawn_colors(queue: &RenderQueue) -> Vec<[f32; 4]> {
+        let mut colors: Vec<[f32; 4]> = Vec::new();
+        for &index in &queue.indices {
+            let color = queue.vertices[index as usize].color;
+            if colors.last() != Some(&color) {
+                colors.push(color);
+            }
+        }
+        colors
+    }
+
+    #[test]
+    fn sort_by_z_draws_higher_z_last() {
+        const RED: [f32; 4] = [1.0, 0.0, 0.0, 1.0];
+        const BLUE: [f32; 4] = [0.0, 0.0, 1.0, 1.0];
+        let mut queue = RenderQueue::new();
+        queue.set_z_index(1);
+        queue.push_rect(SQUARE, RED);
+        queue.set_z_index(0);
+        queue.push_rect(SQUARE, BLUE);
+        let red_vertices = 0..queue.vertices.len() / 2;
+
+        queue.sort_by_z();
+        assert_eq!(drawn_colors(&queue), vec![BLUE, RED]);
+        // Red's indices, which point at its vertices, now make up the end of the buffer
+        let half = queue.indices.len() / 2;
+        assert!(queue.indices[half..].iter().all(|&index| red_vertices.contains(&(index as usize))));
+        assert!(queue.indices[..half].iter().all(|&index| !red_vertices.contains(&(index as usize))));
+    }
+
+    #[test]
+    fn sort_by_z_keeps_submission_order_for_equal_z() {
+        const FIRST: [f32; 4] = [1.0, 0.0, 0.0, 1.0];
+        const SECOND: [f32; 4] = [0.0, 1.0, 0.0, 1.0];
+        const RAISED: [f32; 4] = [0.0, 0.0, 1.0, 1.0];
+        let mut queue = RenderQueue::new();
+        queue.set_z_index(2);
+        queue.push_rect(SQUARE, RAISED);
+        queue.set_z_index(0);
+        queue.push_rect(SQUARE, FIRST);
+        queue.push_rect(SQUARE, SECOND);
+
+        queue.sort_by_z();
+        assert_eq!(drawn_colors(&queue), vec![FIRST, SECOND, RAISED]);
     }
 }
@@ -0,0 +1,439 @@
+use crate::view::{View, Geometry, Event};
+use crate::layout::{self, LayoutContext, LayoutError};
+use crate::render::{RenderContext, Color, scale_text_areas, MAX_VERTICES, MAX_INDICES};
+use crate::app::{App, dispatch_hover, dispatch_focus_navigation, hovered_name};
+use crate::svg;
+use glam::Mat4;
+use std::time::Instant;
+
+/// Draws a view into a texture owned by another wgpu app, e.g. a game's HUD composited over
+/// its 3D scene. Unlike `App` it opens no window and runs no event loop: the host feeds it
+/// input with `handle_event`, calls `resize` when the target changes size and calls `render`
+/// whenever `needs_redraw` says so.
+///
+/// Sizes are in physical pixels of the target; events and layout use layout pixels, which
+/// are `ui_scale` physical pixels each.
+///
+/// The target ends up holding premultiplied colors (see `render::BLEND`), so composite it
+/// with `wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING`.
+pub struct Renderer {
+    view: Box<dyn View>,
+    render_ctx: RenderContext,
+    layout_ctx: LayoutContext,
+    root_node: Option<taffy::prelude::NodeId>,
+    size: winit::dpi::PhysicalSize<u32>,
+    clear_color: Option<[f32; 4]>,
+    cursor: Option<(f32, f32)>,
+    hovered_nodes: Vec<taffy::prelude::NodeId>,
+    /// Set when the scale changed since the last layout.
+    needs_layout: bool,
+    dirty: bool,
+    animating: bool,
+    wakeup: Option<Instant>,
+    last_frame: Instant,
+}
+
+impl Renderer {
+    /// Lays `view` out for a `width` x `height` target of `format`, drawing with the host's
+    /// `device` and `queue`.
+    pub fn new(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        format: wgpu::TextureFormat,
+        view: Box<dyn View>,
+        width: u32,
+        height: u32,
+    ) -> Result<Self, LayoutError> {
+        let mut renderer = Self {
+            view,
+            render_ctx: RenderContext::with_format(device.clone(), queue.clone(), format),
+            layout_ctx: LayoutContext::new(),
+            root_node: None,
+            size: winit::dpi::PhysicalSize::new(width, height),
+            clear_color: Some([0.0, 0.0, 0.0, 0.0]),
+            cursor: None,
+            hovered_nodes: Vec::new(),
+            needs_layout: false,
+            dirty: true,
+            animating: false,
+            wakeup: None,
+            last_frame: Instant::now(),
+        };
+        renderer.view.on_init();
+        renderer.view.on_mount();
+        renderer.relayout()?;
+        Ok(renderer)
+    }
+
+    /// Physical pixels per layout pixel, like `App::with_ui_scale`.
+    pub fn with_ui_scale(mut self, ui_scale: f32) -> Self {
+        self.layout_ctx.ui_scale = ui_scale.max(0.1);
+        self.needs_layout = true;
+        self
+    }
+
+    /// How text is shaped, like `App::with_text_shaping`. Text snapping is on the
+    /// `render_context`.
+    pub fn with_text_shaping(mut self, shaping: glyphon::Shaping) -> Self {
+        self.layout_ctx.text_shaping = shaping;
+        self.needs_layout = true;
+        self
+    }
+
+    /// Size of body text, like `App::with_base_font_size`.
+    pub fn with_base_font_size(mut self, size: f32) -> Self {
+        self.layout_ctx.base_font_size = size.max(1.0);
+        self.needs_layout = true;
+        self
+    }
+
+    /// Font families to try for characters the UI font lacks, like
+    /// `App::with_font_fallbacks`.
+    pub fn with_font_fallbacks<S: Into<String>>(mut self, families: impl IntoIterator<Item = S>) -> Self {
+        let families: Vec<String> = families.into_iter().map(Into::into).collect();
+        self.render_ctx.set_font_fallbacks(&families.iter().map(String::as_str).collect::<Vec<_>>());
+        self.needs_layout = true;
+        self
+    }
+
+    /// What the target is cleared to before drawing; transparent by default, for compositing
+    /// the texture afterwards. `None` keeps the target's contents and draws over them.
+    pub fn with_clear_color(mut self, clear_color: Option<[f32; 4]>) -> Self {
+        self.clear_color = clear_color;
+        self
+    }
+
+    /// The context views draw with, e.g. to read `stats` or toggle `debug` outlines.
+    pub fn render_context(&mut self) -> &mut RenderContext {
+        &mut self.render_ctx
+    }
+
+    /// The last layout, e.g. for `named_geometries` or hit testing with `nodes_at`.
+    pub fn layout_context(&self) -> &LayoutContext {
+        &self.layout_ctx
+    }
+
+    /// The target's area in layout pixels.
+    pub fn geometry(&self) -> Geometry {
+        let scale = self.layout_ctx.ui_scale;
+        Geometry { x: 0.0, y: 0.0, width: self.size.width as f32 / scale, height: self.size.height as f32 / scale }
+    }
+
+    /// Lays the view out again for a target of the new size.
+    pub fn resize(&mut self, width: u32, height: u32) -> Result<(), LayoutError> {
+        if self.size == winit::dpi::PhysicalSize::new(width, height) {
+            return Ok(());
+        }
+        self.size = winit::dpi::PhysicalSize::new(width, height);
+        self.relayout()?;
+        self.view.on_resize(self.layout_ctx.window_size.width, self.layout_ctx.window_size.height);
+        Ok(())
+    }
+
+    fn relayout(&mut self) -> Result<(), LayoutError> {
+        // Settings like fonts and shaping aren't part of the layout's key
+        if self.needs_layout {
+            self.layout_ctx.invalidate();
+        }
+        let root_node = App::update_layout(&mut self.view, &mut self.layout_ctx, self.size, &mut self.render_ctx.font_system)?;
+        self.root_node = Some(root_node);
+        self.needs_layout = false;
+        self.dirty = true;
+        Ok(())
+    }
+
+    /// The current frame as an SVG document in layout pixels, e.g. to export a chart to a
+    /// report. Views prepare as they would to draw, but rects, rounded rects, circles,
+    /// gradients, polygons and text come out as vector elements instead of pixels. Textures
+    /// and whatever views draw themselves in `render` are left out.
+    pub fn to_svg(&mut self) -> Result<String, LayoutError> {
+        self.view.on_update();
+        if layout::take_layout_request() {
+            self.needs_layout = true;
+        }
+        if self.needs_layout {
+            self.relayout()?;
+        }
+
+        let geometry = self.geometry();
+        let ctx = &mut self.render_ctx;
+        ctx.render_queue.clear();
+        ctx.render_queue.start_recording();
+        self.view.prepare(ctx, &self.layout_ctx, geometry);
+        let shapes = ctx.render_queue.take_recording();
+        ctx.take_ime_cursor_area();
+
+        let mut text_areas = Vec::new();
+        self.view.collect_text_areas(&self.layout_ctx, geometry, &mut text_areas);
+        // Whatever was prepared here isn't on the target yet
+        self.dirty = true;
+        Ok(svg::write_svg(geometry, self.clear_color, shapes, &text_areas, &ctx.font_system))
+    }
+
+    /// Delivers input translated by the host into layout pixels relative to the target.
+    /// Pointer moves also send `MouseEnter`/`MouseLeave` as the pointer crosses widgets, and
+    /// d-pad presses move focus like in `App`.
+    pub fn handle_event(&mut self, event: &Event) {
+        let geometry = self.geometry();
+        self.view.handle_event(event, &self.layout_ctx, geometry);
+        dispatch_focus_navigation(self.view.as_mut(), &self.layout_ctx, event, geometry);
+        if let Event::MouseMove { x, y } = *event {
+            self.cursor = Some((x, y));
+            dispatch_hover(self.view.as_mut(), &self.layout_ctx, self.root_node, &mut self.hovered_nodes, self.cursor, geometry);
+        }
+        self.dirty = true;
+    }
+
+    /// The `debug_name` of the innermost named widget under the pointer, like
+    /// `App::with_hovered_widget` reports it.
+    pub fn hovered_widget(&self) -> Option<String> {
+        hovered_name(&self.layout_ctx, &self.hovered_nodes)
+    }
+
+    /// Tells widgets the pointer left the target, e.g. when it moved onto the 3D scene.
+    pub fn pointer_left(&mut self) {
+        self.cursor = None;
+        let geometry = self.geometry();
+        dispatch_hover(self.view.as_mut(), &self.layout_ctx, self.root_node, &mut self.hovered_nodes, None, geometry);
+        self.dirty = true;
+    }
+
+    /// Whether the texture is out of date: input arrived, something is animating or a timed
+    /// redraw is due. Hosts that redraw every frame anyway can ignore this.
+    pub fn needs_redraw(&self) -> bool {
+        self.dirty || self.animating || self.wakeup.is_some_and(|wakeup| Instant::now() >= wakeup)
+    }
+
+    /// When the next timed redraw is due, for hosts that sleep between frames.
+    pub fn next_redraw_at(&self) -> Option<Instant> {
+        self.wakeup
+    }
+
+    /// Draws a frame into `target`, which must be `width` x `height` as last set and of the
+    /// format given to `new`, and submits it to the queue.
+    pub fn render(&mut self, target: &wgpu::TextureView) -> Result<(), LayoutError> {
+        self.view.on_update();
+        if layout::take_layout_request() {
+            self.needs_layout = true;
+        }
+        if self.needs_layout {
+            self.relayout()?;
+        }
+
+        let geometry = self.geometry();
+        let ctx = &mut self.render_ctx;
+
+        let now = Instant::now();
+        ctx.stats.frame_time_ms = now.duration_since(self.last_frame).as_secs_f32() * 1000.0;
+        self.last_frame = now;
+
+        ctx.render_queue.clear();
+        ctx.stats.shaped_texts = 0;
+        ctx.stats.built_attrs = 0;
+        self.view.prepare(ctx, &self.layout_ctx, geometry);
+        ctx.render_queue.sort_by_z();
+        ctx.stats.primitives = ctx.render_queue.len();
+        ctx.stats.dropped_primitives = ctx.render_queue.dropped();
+        ctx.stats.vertices = ctx.render_queue.vertices.len().min(MAX_VERTICES);
+        ctx.stats.indices = ctx.render_queue.indices.len().min(MAX_INDICES);
+        // The host owns the window, and with it IME
+        ctx.take_ime_cursor_area();
+
+        let mut text_areas = ctx.take_text_areas();
+        self.view.collect_text_areas(&self.layout_ctx, geometry, &mut text_areas);
+        ctx.stats.text_areas = text_areas.len();
+        scale_text_areas(&mut text_areas, self.layout_ctx.ui_scale, ctx.snap_text);
+
+        ctx.viewport.update(&ctx.queue, glyphon::Resolution { width: self.size.width, height: self.size.height });
+        ctx.prepare_text(text_areas);
+
+        let projection = Mat4::orthographic_lh(0.0, geometry.width, geometry.height, 0.0, -1.0, 1.0);
+        ctx.queue.write_buffer(&ctx.uniform_buffer, 0, bytemuck::cast_slice(&projection.to_cols_array_2d()));
+        ctx.update_background(self.size.width as f32, self.size.height as f32);
+
+        let load = match self.clear_color.map(|color| Color(color).premultiplied()) {
+            Some([r, g, b, a]) => wgpu::LoadOp::Clear(wgpu::Color { r: r as f64, g: g as f64, b: b as f64, a: a as f64 }),
+            None => wgpu::LoadOp::Load,
+        };
+        // A post-process shader reads the frame from its own texture, which starts out empty
+        let post_target = ctx.post_process_target(self.size.width, self.size.height);
+        let frame_load = match (&post_target, load) {
+            (Some(_), wgpu::LoadOp::Load) => wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+            _ => load,
+        };
+        let mut encoder = ctx.device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: Some("NoxKit Renderer") });
+        {
+            let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: None,
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: post_target.as_ref().unwrap_or(target),
+                    resolve_target: None,
+                    ops: wgpu::Operations { load: frame_load, store: wgpu::StoreOp::Store },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            ctx.draw(&mut rpass, self.view.as_ref(), geometry);
+        }
+        if post_target.is_some() {
+            ctx.apply_post_process(&mut encoder, target, load);
+        }
+        ctx.queue.submit(Some(encoder.finish()));
+
+        self.dirty = false;
+        self.animating = ctx.take_redraw_request();
+        self.wakeup = ctx.take_redraw_at();
+        Ok(())
+    }
+}
+
+impl Drop for Renderer {
+    fn drop(&mut self) {
+        self.view.on_unmount();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widgets::{Button, Circle, Column, RoundedRect, Text};
+
+    const FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8UnormSrgb;
+
+    /// A device without a window, or `None` where the machine has no adapter at all, in
+    /// which case the GPU tests pass without checking anything.
+    fn device() -> Option<(wgpu::Device, wgpu::Queue)> {
+        let instance = wgpu::Instance::default();
+        let adapter = crate::app::App::request_adapter(&instance, None, wgpu::PowerPreference::default())
+            .inspect_err(|err| eprintln!("Skipping GPU test: {}", err))
+            .ok()?;
+        pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor::default())).ok()
+    }
+
+    fn target(device: &wgpu::Device, format: wgpu::TextureFormat, width: u32, height: u32) -> wgpu::Texture {
+        device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("NoxKit test target"),
+            size: wgpu::Extent3d { width, height, depth_or_array_layers: 1 },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        })
+    }
+
+    /// The RGBA8 pixel at (`x`, `y`) of `texture`, whose rows must be a multiple of 256 bytes.
+    fn read_pixel(device: &wgpu::Device, queue: &wgpu::Queue, texture: &wgpu::Texture, x: u32, y: u32) -> [u8; 4] {
+        let bytes_per_row = texture.width() * 4;
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("NoxKit test readback"),
+            size: (bytes_per_row * texture.height()) as u64,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+        encoder.copy_texture_to_buffer(
+            texture.as_image_copy(),
+            wgpu::TexelCopyBufferInfo {
+                buffer: &buffer,
+                layout: wgpu::TexelCopyBufferLayout { offset: 0, bytes_per_row: Some(bytes_per_row), rows_per_image: None },
+            },
+            texture.size(),
+        );
+        queue.submit(Some(encoder.finish()));
+        buffer.slice(..).map_async(wgpu::MapMode::Read, |result| result.expect("mapping the readback buffer failed"));
+        device.poll(wgpu::PollType::Wait).unwrap();
+        let pixels = buffer.slice(..).get_mapped_range();
+        let i = (y * bytes_per_row + x * 4) as usize;
+        [pixels[i], pixels[i + 1], pixels[i + 2], pixels[i + 3]]
+    }
+
+    /// Fills the target with a 50% blue rect and a 50% red one on top.
+    struct Stacked {
+        node_id: Option<taffy::prelude::NodeId>,
+    }
+
+    impl View for Stacked {
+        fn layout(&mut self, ctx: &mut LayoutContext) -> Result<taffy::prelude::NodeId, LayoutError> {
+            use taffy::prelude::{Dimension, Size, Style};
+            let node = ctx.taffy.new_leaf(Style { size: Size { width: Dimension::Percent(1.0), height: Dimension::Percent(1.0) }, ..Default::default() })?;
+            self.node_id = Some(node);
+            Ok(node)
+        }
+
+        fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+            let Some(geometry) = layout_ctx.geometry(self.node_id, geometry) else { return };
+            ctx.render_queue.push_rect(geometry, BLUE);
+            ctx.render_queue.push_rect(geometry, RED);
+        }
+
+        fn render<'rp>(&'rp self, _: &'rp RenderContext, _: &mut wgpu::RenderPass<'rp>, _: Geometry) {}
+        fn handle_event(&mut self, _: &Event, _: &LayoutContext, _: Geometry) {}
+    }
+
+    const BLUE: [f32; 4] = [0.0, 0.0, 1.0, 0.5];
+    const RED: [f32; 4] = [1.0, 0.0, 0.0, 0.5];
+
+    #[test]
+    fn stacked_translucent_rects_blend_premultiplied() {
+        let Some((device, queue)) = device() else { return };
+        // A linear format, so the stored values are the blended ones as is
+        let format = wgpu::TextureFormat::Rgba8Unorm;
+        let texture = target(&device, format, 64, 64);
+        let mut renderer = Renderer::new(&device, &queue, format, Box::new(Stacked { node_id: None }), 64, 64).unwrap();
+        renderer.render(&texture.create_view(&Default::default())).unwrap();
+
+        let expected = Color(RED).over(Color(BLUE).over(Color([0.0; 4]))).premultiplied().map(|c| c * 255.0);
+        let pixel = read_pixel(&device, &queue, &texture, 32, 32);
+        // 75% coverage, the red on top weighing twice as much as the blue
+        for (channel, want) in pixel.into_iter().zip(expected) {
+            assert!((channel as f32 - want).abs() <= 2.0, "read {:?}, expected {:?}", pixel, expected);
+        }
+    }
+
+    #[test]
+    fn ui_scale_enlarges_widgets_on_the_target() {
+        let Some((device, queue)) = device() else { return };
+        let texture = target(&device, FORMAT, 600, 400);
+        let view = texture.create_view(&Default::default());
+
+        let laid_out_at = |ui_scale: f32| {
+            let button = Button::new("Save", || {}).debug_name("save");
+            let mut renderer = Renderer::new(&device, &queue, FORMAT, Box::new(button), 600, 400).unwrap().with_ui_scale(ui_scale);
+            renderer.render(&view).unwrap();
+            let save = renderer.layout_context().named_geometries().into_iter()
+                .find_map(|(name, geometry)| (name == "save").then_some(geometry))
+                .unwrap();
+            (save, renderer.geometry(), renderer.layout_context().vw(50.0))
+        };
+
+        let (save, window, half_width) = laid_out_at(1.0);
+        let (scaled_save, scaled_window, scaled_half_width) = laid_out_at(1.5);
+        // The same size in layout pixels, so 1.5 times as many physical pixels
+        assert_eq!((scaled_save.width, scaled_save.height), (save.width, save.height));
+        assert_eq!((window.width, window.height), (600.0, 400.0));
+        assert_eq!((scaled_window.width, scaled_window.height), (400.0, 400.0 / 1.5));
+        // Viewport units follow the window in layout pixels
+        assert_eq!(half_width, 300.0);
+        assert_eq!(scaled_half_width, 200.0);
+    }
+
+    #[test]
+    fn to_svg_exports_shapes_and_text() {
+        let Some((device, queue)) = device() else { return };
+        let counter = Column::default()
+            .child(Text::new("Counter: 3"))
+            .child(Button::new("Increment", || {}))
+            .child(RoundedRect::new([0.0, 0.5, 1.0, 1.0], 20.0))
+            .child(Circle::new([1.0, 0.8, 0.0, 1.0]));
+        let mut renderer = Renderer::new(&device, &queue, FORMAT, Box::new(counter), 400, 300).unwrap();
+        let svg = renderer.to_svg().unwrap();
+
+        assert!(svg.contains("<rect"), "{}", svg);
+        assert!(svg.contains("<circle"), "{}", svg);
+        assert!(svg.contains(">Counter: 3</text>"), "{}", svg);
+        assert!(svg.contains(">Increment</text>"), "{}", svg);
+    }
+}
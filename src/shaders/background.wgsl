@@ -0,0 +1,34 @@
+struct BackgroundUniforms {
+    // Maps the window's 0..1 uv range onto the part of the image that covers it
+    uv_scale: vec2<f32>,
+    uv_offset: vec2<f32>,
+};
+
+@group(0) @binding(0)
+var<uniform> background: BackgroundUniforms;
+@group(0) @binding(1)
+var image: texture_2d<f32>;
+@group(0) @binding(2)
+var image_sampler: sampler;
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+};
+
+// A single triangle covering the whole window
+@vertex
+fn vs_main(@builtin(vertex_index) index: u32) -> VertexOutput {
+    let uv = vec2<f32>(f32((index << 1u) & 2u), f32(index & 2u));
+    var out: VertexOutput;
+    out.clip_position = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+    out.uv = uv * background.uv_scale + background.uv_offset;
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    // Images are uploaded with straight alpha; the blend expects it premultiplied
+    let texel = textureSample(image, image_sampler, in.uv);
+    return vec4<f32>(texel.rgb * texel.a, texel.a);
+}
@@ -0,0 +1,71 @@
+struct GlobalUniforms {
+    view_proj: mat4x4<f32>,
+};
+
+@group(0) @binding(0)
+var<uniform> globals: GlobalUniforms;
+@group(1) @binding(0)
+var image: texture_2d<f32>;
+@group(1) @binding(1)
+var image_sampler: sampler;
+
+struct VertexInput {
+    @location(0) position: vec2<f32>,
+    @location(1) uv: vec2<f32>,
+    @location(2) rect_pos: vec2<f32>,
+    @location(3) rect_size: vec2<f32>,
+    @location(4) corner_radius: f32,
+    @location(5) tint: vec4<f32>,
+    @location(6) tint_mode: f32,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+    @location(1) local_pos: vec2<f32>,
+    @location(2) rect_size: vec2<f32>,
+    @location(3) corner_radius: f32,
+    @location(4) tint: vec4<f32>,
+    @location(5) tint_mode: f32,
+};
+
+@vertex
+fn vs_main(model: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+    out.clip_position = globals.view_proj * vec4<f32>(model.position, 0.0, 1.0);
+    out.uv = model.uv;
+    out.local_pos = model.position - (model.rect_pos + model.rect_size * 0.5);
+    out.rect_size = model.rect_size;
+    out.corner_radius = model.corner_radius;
+    out.tint = model.tint;
+    out.tint_mode = model.tint_mode;
+    return out;
+}
+
+fn sdRoundedBox(p: vec2<f32>, b: vec2<f32>, r: f32) -> f32 {
+    let q = abs(p) - b + r;
+    return length(max(q, vec2<f32>(0.0))) + min(max(q.x, q.y), 0.0) - r;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let texel = textureSample(image, image_sampler, in.uv);
+
+    // Multiply (mode 0) scales every channel; overlay (mode 1) blends the tint over the
+    // color by its alpha and keeps the image's alpha
+    var color = texel * in.tint;
+    if (in.tint_mode > 0.5) {
+        color = vec4<f32>(mix(texel.rgb, in.tint.rgb, in.tint.a), texel.a);
+    }
+    // Images are uploaded with straight alpha; the blend expects it premultiplied
+    color = vec4<f32>(color.rgb * color.a, color.a);
+
+    // Mask to the rounded rect; a radius of half the size gives a circle
+    let dist = sdRoundedBox(in.local_pos, in.rect_size * 0.5, in.corner_radius);
+    let smoothing = fwidth(dist);
+    let alpha = 1.0 - smoothstep(-smoothing, smoothing, dist);
+    if (alpha <= 0.0) {
+        discard;
+    }
+    return color * alpha;
+}
@@ -0,0 +1,31 @@
+// Put in front of post-process shaders set with `RenderContext::set_post_process`, which
+// add an `fs_main(in: PostProcessInput)` fragment entry point that samples `frame`.
+
+struct PostProcessUniforms {
+    // Size of the frame in physical pixels
+    size: vec2<f32>,
+    _padding: vec2<f32>,
+};
+
+@group(0) @binding(0)
+var<uniform> post: PostProcessUniforms;
+@group(0) @binding(1)
+var frame: texture_2d<f32>;
+@group(0) @binding(2)
+var frame_sampler: sampler;
+
+struct PostProcessInput {
+    @builtin(position) position: vec4<f32>,
+    // 0,0 at the top-left corner of the frame, 1,1 at the bottom-right
+    @location(0) uv: vec2<f32>,
+};
+
+// A single triangle covering the whole frame
+@vertex
+fn vs_main(@builtin(vertex_index) index: u32) -> PostProcessInput {
+    let uv = vec2<f32>(f32((index << 1u) & 2u), f32(index & 2u));
+    var out: PostProcessInput;
+    out.position = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+    out.uv = uv;
+    return out;
+}
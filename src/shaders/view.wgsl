@@ -12,6 +12,10 @@ struct VertexInput {
     @location(3) rect_size: vec2<f32>,
     @location(4) corner_radius: f32,
     @location(5) shape_type: f32,
+    @location(6) clip_rect: vec4<f32>,
+    @location(7) clip_radius: f32,
+    @location(8) edge_softness: f32,
+    @location(9) arc: vec2<f32>,
 };
 
 struct VertexOutput {
@@ -21,6 +25,11 @@ struct VertexOutput {
     @location(2) rect_size: vec2<f32>,
     @location(3) corner_radius: f32,
     @location(4) shape_type: f32,
+    @location(5) world_pos: vec2<f32>,
+    @location(6) clip_rect: vec4<f32>,
+    @location(7) clip_radius: f32,
+    @location(8) edge_softness: f32,
+    @location(9) arc: vec2<f32>,
 };
 
 @vertex
@@ -35,6 +44,11 @@ fn vs_main(
     out.rect_size = model.rect_size;
     out.corner_radius = model.corner_radius;
     out.shape_type = model.shape_type;
+    out.world_pos = model.position;
+    out.clip_rect = model.clip_rect;
+    out.clip_radius = model.clip_radius;
+    out.edge_softness = model.edge_softness;
+    out.arc = model.arc;
     return out;
 }
 
@@ -47,6 +61,28 @@ fn sdCircle(p: vec2<f32>, r: f32) -> f32 {
     return length(p) - r;
 }
 
+// A ring of radius `r` and half thickness `th` with rounded ends, running `sweep` radians
+// clockwise from `start`, where 0 points up. Screen y grows downwards.
+fn sdArc(p: vec2<f32>, start: f32, sweep: f32, r: f32, th: f32) -> f32 {
+    // Turn the arc's middle onto +y, mirroring it into one half so both ends look alike
+    let middle = start + sweep * 0.5;
+    let up = vec2<f32>(sin(middle), -cos(middle));
+    let side = vec2<f32>(cos(middle), sin(middle));
+    let q = vec2<f32>(abs(dot(p, side)), dot(p, up));
+    let half_sweep = clamp(sweep * 0.5, 0.0, 3.14159265);
+    let end = vec2<f32>(sin(half_sweep), cos(half_sweep));
+    // Past the end, the distance is to the rounded cap; along the arc, to the ring
+    let past_end = end.y * q.x > end.x * q.y;
+    return select(abs(length(q) - r), length(q - end * r), past_end) - th;
+}
+
+// Fraction of the pixel covered by the shape with signed distance `dist`, for pixels
+// `pixel` units wide. A one pixel ramp centered on the edge gives the exact coverage of
+// straight edges without multisampling.
+fn coverage(dist: f32, pixel: f32) -> f32 {
+    return clamp(0.5 - dist / max(pixel, 1e-4), 0.0, 1.0);
+}
+
 @fragment
 fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     var dist: f32;
@@ -56,17 +92,33 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
         dist = sdRoundedBox(in.local_pos, half_size, 0.0);
     } else if (in.shape_type < 1.5) { // Rounded Rect
         dist = sdRoundedBox(in.local_pos, half_size, in.corner_radius);
-    } else { // Circle
+    } else if (in.shape_type < 2.5) { // Circle
         let radius = min(half_size.x, half_size.y);
         dist = sdCircle(in.local_pos, radius);
+    } else if (in.shape_type < 3.5) { // Flat (triangles, polygons): covered wherever rasterized
+        dist = -1.0;
+    } else { // Arc, with the corner radius as its thickness
+        let half_thickness = in.corner_radius * 0.5;
+        let radius = min(half_size.x, half_size.y) - half_thickness;
+        dist = sdArc(in.local_pos, in.arc.x, in.arc.y, radius, half_thickness);
     }
     
-    let smoothing = fwidth(dist);
-    let alpha = 1.0 - smoothstep(-smoothing, smoothing, dist);
+    // Positions are affine across a primitive, so unlike the distance's own derivatives this
+    // stays exact near corners, where the distance field bends
+    let pixel = length(dpdx(in.world_pos));
+    // Softness widens the ramp on both sides of the edge
+    let flat = in.shape_type > 2.5 && in.shape_type < 3.5;
+    var alpha = select(coverage(dist, pixel + in.edge_softness), 1.0, flat);
+
+    // Clip against the rounded clip rect; a zero-sized clip rect disables clipping
+    let clip_half = in.clip_rect.zw * 0.5;
+    let clip_dist = sdRoundedBox(in.world_pos - (in.clip_rect.xy + clip_half), clip_half, in.clip_radius);
+    alpha = alpha * select(1.0, coverage(clip_dist, pixel), in.clip_rect.z > 0.0);
     
     if (alpha <= 0.0) {
         discard;
     }
     
-    return vec4<f32>(in.color.rgb, in.color.a * alpha);
+    // The color comes premultiplied, so coverage scales all of it
+    return in.color * alpha;
 }
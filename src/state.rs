@@ -1,9 +1,152 @@
-use std::rc::Rc;
-use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+use std::cell::{Cell, OnceCell, RefCell};
+use std::time::{Duration, Instant};
+
+type Listener = Rc<dyn Fn()>;
+type Listeners = Rc<RefCell<Vec<Listener>>>;
+
+/// Re-runs a tracked computation. Signals read while it runs hold on to it.
+type Observer = Rc<Effect>;
+type Observers = Rc<RefCell<Vec<Observer>>>;
+
+/// Undoes what a run of an effect set up: a subscription, or an effect created inside it.
+type Cleanups = Rc<RefCell<Vec<Box<dyn FnOnce()>>>>;
+
+thread_local! {
+    /// The computations currently running, innermost last. `None` marks an untracked section.
+    static TRACKING: RefCell<Vec<Option<Observer>>> = const { RefCell::new(Vec::new()) };
+    /// The cleanups of the effects currently running, innermost last.
+    static OWNER: RefCell<Vec<Cleanups>> = const { RefCell::new(Vec::new()) };
+    /// How many `batch` calls are currently running.
+    static BATCH_DEPTH: Cell<usize> = const { Cell::new(0) };
+    /// Signals that changed inside the current batch, in order, each listed once.
+    static PENDING_SIGNALS: RefCell<Vec<(Listeners, Observers)>> = const { RefCell::new(Vec::new()) };
+    /// Recomputations scheduled inside the current batch, each listed once.
+    static PENDING_JOBS: RefCell<Vec<Rc<dyn Fn()>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Runs `f` with `observer` as the current observer (or untracked for `None`).
+fn with_observer<R>(observer: Option<Observer>, f: impl FnOnce() -> R) -> R {
+    TRACKING.with(|stack| stack.borrow_mut().push(observer));
+    let result = f();
+    TRACKING.with(|stack| stack.borrow_mut().pop());
+    result
+}
+
+/// Subscribes the current observer, if any, to a signal's observer list.
+fn track(observers: &Observers) {
+    let current = TRACKING.with(|stack| stack.borrow().last().cloned().flatten());
+    if let Some(current) = current {
+        let mut list = observers.borrow_mut();
+        if !list.iter().any(|o| Rc::ptr_eq(o, &current)) {
+            list.push(current.clone());
+            current.sources.borrow_mut().push(Rc::downgrade(observers));
+        }
+    }
+}
+
+/// Registers `cleanup` to run before the effect currently running runs again. Outside of
+/// effects, nothing is cleaned up.
+fn on_cleanup(cleanup: impl FnOnce() + 'static) {
+    OWNER.with(|owner| {
+        if let Some(cleanups) = owner.borrow().last() {
+            cleanups.borrow_mut().push(Box::new(cleanup));
+        }
+    });
+}
+
+/// Adds `listener` to a signal, removing it again when the effect that added it re-runs.
+fn add_listener(listeners: &Listeners, listener: Listener) {
+    listeners.borrow_mut().push(listener.clone());
+    let listeners = Rc::downgrade(listeners);
+    on_cleanup(move || {
+        if let Some(listeners) = listeners.upgrade() {
+            listeners.borrow_mut().retain(|l| !Rc::ptr_eq(l, &listener));
+        }
+    });
+}
+
+/// Notifies a changed signal's subscribers, or queues it until the current `batch` ends.
+fn notify(listeners: &Listeners, observers: &Observers) {
+    if BATCH_DEPTH.get() > 0 {
+        PENDING_SIGNALS.with(|pending| {
+            let mut pending = pending.borrow_mut();
+            if !pending.iter().any(|(l, _)| Rc::ptr_eq(l, listeners)) {
+                pending.push((listeners.clone(), observers.clone()));
+            }
+        });
+        return;
+    }
+    notify_now(listeners, observers);
+}
+
+/// Calls listeners and observers outside of any tracking scope, so reads they make are not
+/// attributed to whatever computation triggered the change.
+fn notify_now(listeners: &Listeners, observers: &Observers) {
+    with_observer(None, || {
+        // Both lists can change while they run (effects re-track, and dispose what their last
+        // run subscribed), so iterate over snapshots
+        let listeners = listeners.borrow().clone();
+        for listener in listeners {
+            listener();
+        }
+        let observers = observers.borrow().clone();
+        for observer in observers {
+            observer.run();
+        }
+    });
+}
+
+/// Runs `job` now, or once at the end of the current batch if one is running.
+fn schedule(job: &Rc<dyn Fn()>) {
+    if BATCH_DEPTH.get() == 0 {
+        job();
+        return;
+    }
+    PENDING_JOBS.with(|jobs| {
+        let mut jobs = jobs.borrow_mut();
+        if !jobs.iter().any(|j| Rc::ptr_eq(j, job)) {
+            jobs.push(job.clone());
+        }
+    });
+}
+
+/// Runs `f`, holding back notifications until it returns. Each signal that changed notifies
+/// its subscribers once, and `Computed::derive` values depending on several of them recompute
+/// once, e.g. `batch(|| { first.set(a); last.set(b); })`. Batches can nest; the outermost
+/// one flushes, even when `f` panics.
+pub fn batch<R>(f: impl FnOnce() -> R) -> R {
+    BATCH_DEPTH.set(BATCH_DEPTH.get() + 1);
+    let _end = BatchEnd;
+    f()
+}
+
+/// Ends a `batch` when dropped, so a panic inside one doesn't hold back every later change.
+struct BatchEnd;
+
+impl Drop for BatchEnd {
+    fn drop(&mut self) {
+        if BATCH_DEPTH.get() == 1 {
+            // Listeners may set more signals, so keep batching until nothing is left
+            while let Some((listeners, observers)) = PENDING_SIGNALS.with(|pending| {
+                let mut pending = pending.borrow_mut();
+                (!pending.is_empty()).then(|| pending.remove(0))
+            }) {
+                notify_now(&listeners, &observers);
+            }
+        }
+        BATCH_DEPTH.set(BATCH_DEPTH.get() - 1);
+        if BATCH_DEPTH.get() == 0 {
+            let jobs = PENDING_JOBS.with(|jobs| std::mem::take(&mut *jobs.borrow_mut()));
+            with_observer(None, || jobs.iter().for_each(|job| job()));
+        }
+    }
+}
 
 pub struct Signal<T> {
     value: Rc<RefCell<T>>,
-    listeners: Rc<RefCell<Vec<Box<dyn Fn()>>>>,
+    listeners: Listeners,
+    observers: Observers,
 }
 
 impl<T: Clone> Signal<T> {
@@ -11,13 +154,42 @@ impl<T: Clone> Signal<T> {
         Self {
             value: Rc::new(RefCell::new(value)),
             listeners: Rc::new(RefCell::new(Vec::new())),
+            observers: Rc::new(RefCell::new(Vec::new())),
         }
     }
 
+    /// Returns the current value. Inside `create_computed` or `create_effect` this also
+    /// registers the signal as a dependency.
     pub fn get(&self) -> T {
+        track(&self.observers);
         self.value.borrow().clone()
     }
 
+    /// Borrows the current value without cloning it, tracking the signal like `get`.
+    ///
+    /// The guard must be dropped before the signal changes: calling `set` or `update` while
+    /// it is held, including from a listener or effect the change triggers, panics.
+    ///
+    /// ```
+    /// use noxkit::prelude::*;
+    ///
+    /// #[derive(Clone)]
+    /// struct Document { title: String, lines: Vec<String> }
+    ///
+    /// let doc = create_signal(Document { title: "Notes".into(), lines: vec![String::new(); 10_000] });
+    /// assert_eq!(doc.borrow().title, "Notes");
+    /// assert_eq!(doc.borrow().lines.len(), 10_000);
+    /// ```
+    pub fn borrow(&self) -> std::cell::Ref<'_, T> {
+        track(&self.observers);
+        self.value.borrow()
+    }
+
+    pub fn set(&self, value: T) {
+        *self.value.borrow_mut() = value;
+        self.notify();
+    }
+
     pub fn update<F>(&self, f: F)
     where
         F: FnOnce(&mut T),
@@ -29,44 +201,168 @@ impl<T: Clone> Signal<T> {
         self.notify();
     }
 
+    /// Calls `f` after every change. Subscribing inside an effect (like the render function of
+    /// `App::with_render_fn`) lasts until that effect runs again.
     pub fn subscribe<F>(&self, f: F)
     where
         F: Fn() + 'static,
     {
-        self.listeners.borrow_mut().push(Box::new(f));
+        add_listener(&self.listeners, Rc::new(f));
     }
 
     fn notify(&self) {
-        for listener in self.listeners.borrow().iter() {
-            listener();
-        }
+        notify(&self.listeners, &self.observers);
+    }
+
+    /// How many listeners and effects follow this signal, for tests checking nothing leaks.
+    #[cfg(test)]
+    pub(crate) fn subscriber_count(&self) -> usize {
+        self.listeners.borrow().len() + self.observers.borrow().len()
     }
 }
 
+/// Has a signal schedule `job` whenever it changes.
+fn subscribe_job(listeners: &Listeners, job: &Rc<dyn Fn()>) {
+    let job = job.clone();
+    add_listener(listeners, Rc::new(move || schedule(&job)));
+}
+
 impl<T: Clone> Clone for Signal<T> {
     fn clone(&self) -> Self {
         Self {
             value: self.value.clone(),
             listeners: self.listeners.clone(),
+            observers: self.observers.clone(),
         }
     }
 }
 
+/// A derived value. Values from `create_memo`, `derive` and `map` only notify subscribers when
+/// they actually change, so chains of memos stop propagating as soon as one of them produces
+/// the same output again.
 pub struct Computed<T> {
     value: Rc<RefCell<T>>,
+    listeners: Listeners,
+    observers: Observers,
 }
 
 impl<T: Clone + 'static> Computed<T> {
+    fn from_value(value: T) -> Self {
+        Self {
+            value: Rc::new(RefCell::new(value)),
+            listeners: Rc::new(RefCell::new(Vec::new())),
+            observers: Rc::new(RefCell::new(Vec::new())),
+        }
+    }
+
+    pub fn get(&self) -> T {
+        track(&self.observers);
+        self.value.borrow().clone()
+    }
+
+    pub fn subscribe<F>(&self, f: F)
+    where
+        F: Fn() + 'static,
+    {
+        add_listener(&self.listeners, Rc::new(f));
+    }
+
+    /// Derives a value from the signals (and computeds) that `f` reads, recomputing it
+    /// whenever any of them changes. Subscribers run on every recompute, as `T` needn't be
+    /// comparable.
     pub fn new<F>(f: F) -> Self
     where
         F: Fn() -> T + 'static,
     {
-        let value = Rc::new(RefCell::new(f()));
-        Self { value }
+        let slot: Rc<OnceCell<Computed<T>>> = Rc::new(OnceCell::new());
+        create_effect({
+            let slot = slot.clone();
+            move || {
+                let value = f();
+                match slot.get() {
+                    Some(computed) => computed.set(value),
+                    None => { let _ = slot.set(Computed::from_value(value)); }
+                }
+            }
+        });
+        slot.get().unwrap().clone()
     }
 
-    pub fn get(&self) -> T {
-        self.value.borrow().clone()
+    fn set(&self, value: T) {
+        *self.value.borrow_mut() = value;
+        self.notify();
+    }
+
+    fn notify(&self) {
+        notify(&self.listeners, &self.observers);
+    }
+
+    /// How many listeners and effects follow this signal, for tests checking nothing leaks.
+    #[cfg(test)]
+    pub(crate) fn subscriber_count(&self) -> usize {
+        self.listeners.borrow().len() + self.observers.borrow().len()
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> Computed<T> {
+    /// Stores `value` and notifies subscribers, unless it equals the current value.
+    fn set_if_changed(&self, value: T) {
+        if *self.value.borrow() != value {
+            self.set(value);
+        }
+    }
+
+    /// Derives a value from two signals, listed explicitly instead of tracked, e.g.
+    /// `Computed::derive((&email, &password), |email, password| ...)`. When both change inside
+    /// one `batch`, `f` runs once.
+    pub fn derive<A, B, F>(deps: (&Signal<A>, &Signal<B>), f: F) -> Self
+    where
+        A: Clone + 'static,
+        B: Clone + 'static,
+        F: Fn(A, B) -> T + 'static,
+    {
+        let (a, b) = (deps.0.clone(), deps.1.clone());
+        let computed = Computed::from_value(with_observer(None, || f(a.get(), b.get())));
+        let c = computed.clone();
+        let job: Rc<dyn Fn()> = Rc::new(move || c.set_if_changed(f(a.get(), b.get())));
+        subscribe_job(&deps.0.listeners, &job);
+        subscribe_job(&deps.1.listeners, &job);
+        computed
+    }
+
+    /// Like `derive`, for three signals.
+    pub fn derive3<A, B, C, F>(deps: (&Signal<A>, &Signal<B>, &Signal<C>), f: F) -> Self
+    where
+        A: Clone + 'static,
+        B: Clone + 'static,
+        C: Clone + 'static,
+        F: Fn(A, B, C) -> T + 'static,
+    {
+        let (a, b, c) = (deps.0.clone(), deps.1.clone(), deps.2.clone());
+        let computed = Computed::from_value(with_observer(None, || f(a.get(), b.get(), c.get())));
+        let out = computed.clone();
+        let job: Rc<dyn Fn()> = Rc::new(move || out.set_if_changed(f(a.get(), b.get(), c.get())));
+        subscribe_job(&deps.0.listeners, &job);
+        subscribe_job(&deps.1.listeners, &job);
+        subscribe_job(&deps.2.listeners, &job);
+        computed
+    }
+
+    /// Derives another memo from this one, for chaining `signal -> memo -> memo`.
+    pub fn map<U, F>(&self, f: F) -> Computed<U>
+    where
+        U: Clone + PartialEq + 'static,
+        F: Fn(T) -> U + 'static,
+    {
+        let source = self.clone();
+        let computed = Computed::from_value(f(source.get()));
+
+        let c = computed.clone();
+        self.subscribe(move || {
+            c.set_if_changed(f(source.get()));
+        });
+
+        computed
     }
 }
 
@@ -74,10 +370,14 @@ impl<T: Clone + 'static> Clone for Computed<T> {
     fn clone(&self) -> Self {
         Self {
             value: self.value.clone(),
+            listeners: self.listeners.clone(),
+            observers: self.observers.clone(),
         }
     }
 }
 
+/// Creates a derived value that tracks the signals read in `f`, e.g.
+/// `create_computed(move || a.get() + b.get())`.
 pub fn create_computed<T, F>(f: F) -> Computed<T>
 where
     T: Clone + 'static,
@@ -86,27 +386,540 @@ where
     Computed::new(f)
 }
 
+/// Runs `f` now and again whenever a signal it read changes.
+///
+/// Dependencies are collected on every run, so signals read conditionally are picked up
+/// once they are first read, and signals the last run no longer read are dropped. Each run
+/// also disposes what the previous one set up: subscriptions and effects created while an
+/// effect runs last until it runs again. Top-level effects live as long as the signals they
+/// depend on.
+pub fn create_effect<F>(f: F)
+where
+    F: Fn() + 'static,
+{
+    let effect = Rc::new(Effect {
+        f: Box::new(f),
+        sources: RefCell::new(Vec::new()),
+        cleanups: Rc::new(RefCell::new(Vec::new())),
+        disposed: Cell::new(false),
+    });
+    effect.run();
+    on_cleanup(move || effect.dispose());
+}
+
+/// A computation that re-runs when the signals it read change, see `create_effect`.
+struct Effect {
+    f: Box<dyn Fn()>,
+    /// The observer lists of the signals the last run read.
+    sources: RefCell<Vec<Weak<RefCell<Vec<Observer>>>>>,
+    /// What the last run set up, undone before the next one.
+    cleanups: Cleanups,
+    disposed: Cell<bool>,
+}
+
+impl Effect {
+    fn run(self: &Rc<Self>) {
+        if self.disposed.get() {
+            return;
+        }
+        self.reset();
+        OWNER.with(|owner| owner.borrow_mut().push(self.cleanups.clone()));
+        with_observer(Some(self.clone()), &self.f);
+        OWNER.with(|owner| owner.borrow_mut().pop());
+    }
+
+    /// Leaves the signals the last run read and undoes what it set up.
+    fn reset(self: &Rc<Self>) {
+        for source in self.sources.take() {
+            if let Some(observers) = source.upgrade() {
+                observers.borrow_mut().retain(|o| !Rc::ptr_eq(o, self));
+            }
+        }
+        let cleanups = std::mem::take(&mut *self.cleanups.borrow_mut());
+        cleanups.into_iter().for_each(|cleanup| cleanup());
+    }
+
+    fn dispose(self: &Rc<Self>) {
+        self.disposed.set(true);
+        self.reset();
+    }
+}
+
 pub fn create_signal<T: Clone>(value: T) -> Signal<T> {
     Signal::new(value)
 }
 
 pub fn create_memo<T, S, F>(dependency: &Signal<S>, f: F) -> Computed<T>
 where
-    T: Clone + 'static,
+    T: Clone + PartialEq + 'static,
     S: Clone + 'static,
     F: Fn(S) -> T + 'static,
 {
     let dep = dependency.clone();
-    let initial_val = f(dep.get());
-    let computed_val = Rc::new(RefCell::new(initial_val));
-    
-    let c_val = computed_val.clone();
-    let f = Rc::new(f);
-    
+    let computed = Computed::from_value(f(dep.get()));
+
+    let c = computed.clone();
     dependency.subscribe(move || {
-        let mut val = c_val.borrow_mut();
-        *val = f(dep.get());
+        c.set_if_changed(f(dep.get()));
     });
-    
-    Computed { value: computed_val }
+
+    computed
+}
+
+/// A validation rule: returns an error message when the value is invalid.
+pub type Validator = Box<dyn Fn(&str) -> Option<String>>;
+
+/// A form field: a text value plus validators that keep an error message signal up to date.
+///
+/// Validators run in order on every `set` and on `validate`; the first failure wins.
+/// Cross-field rules are plain validators capturing another field, e.g. a confirmation
+/// field comparing itself against `password.value.get()`.
+pub struct Field {
+    pub value: Signal<String>,
+    pub error: Signal<Option<String>>,
+    validators: Rc<RefCell<Vec<Validator>>>,
+}
+
+impl Field {
+    pub fn new(value: impl Into<String>) -> Self {
+        Self {
+            value: Signal::new(value.into()),
+            error: Signal::new(None),
+            validators: Rc::new(RefCell::new(Vec::new())),
+        }
+    }
+
+    pub fn validator<F>(self, f: F) -> Self
+    where
+        F: Fn(&str) -> Option<String> + 'static,
+    {
+        self.validators.borrow_mut().push(Box::new(f));
+        self
+    }
+
+    pub fn required(self, message: impl Into<String>) -> Self {
+        let message = message.into();
+        self.validator(move |value| value.trim().is_empty().then(|| message.clone()))
+    }
+
+    pub fn min_length(self, min: usize, message: impl Into<String>) -> Self {
+        let message = message.into();
+        self.validator(move |value| (value.chars().count() < min).then(|| message.clone()))
+    }
+
+    /// A pragmatic check (`local@domain.tld`, no whitespace) rather than full RFC 5322.
+    pub fn email(self, message: impl Into<String>) -> Self {
+        let message = message.into();
+        self.validator(move |value| (!is_email(value)).then(|| message.clone()))
+    }
+
+    /// Sets the value and re-runs validation.
+    pub fn set(&self, value: impl Into<String>) {
+        self.value.set(value.into());
+        self.validate();
+    }
+
+    /// Runs all validators against the current value, updates `error` and returns whether it passed.
+    pub fn validate(&self) -> bool {
+        let value = self.value.get();
+        let error = self.validators.borrow().iter().find_map(|validator| validator(&value));
+        let valid = error.is_none();
+        if self.error.get() != error {
+            self.error.set(error);
+        }
+        valid
+    }
+}
+
+impl Clone for Field {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            error: self.error.clone(),
+            validators: self.validators.clone(),
+        }
+    }
+}
+
+/// Validates every field (without short-circuiting, so all errors show) for form submission.
+pub fn validate_all(fields: &[&Field]) -> bool {
+    fields.iter().filter(|field| !field.validate()).count() == 0
+}
+
+fn is_email(value: &str) -> bool {
+    let Some((local, domain)) = value.split_once('@') else { return false };
+    !local.is_empty()
+        && !value.chars().any(char::is_whitespace)
+        && !domain.contains('@')
+        && domain.split('.').count() >= 2
+        && domain.split('.').all(|part| !part.is_empty())
+}
+
+/// A named signal a `StateStore` can snapshot and restore.
+trait PersistedSignal {
+    fn save(&self) -> Result<serde_json::Value, serde_json::Error>;
+    /// Decodes `value` for this signal, returning the `set` to run once every value decoded.
+    fn restore(&self, value: serde_json::Value) -> Result<Box<dyn FnOnce()>, serde_json::Error>;
+}
+
+impl<T> PersistedSignal for Signal<T>
+where
+    T: Clone + serde::Serialize + serde::de::DeserializeOwned + 'static,
+{
+    fn save(&self) -> Result<serde_json::Value, serde_json::Error> {
+        serde_json::to_value(&*self.value.borrow())
+    }
+
+    fn restore(&self, value: serde_json::Value) -> Result<Box<dyn FnOnce()>, serde_json::Error> {
+        let value: T = serde_json::from_value(value)?;
+        let signal = self.clone();
+        Ok(Box::new(move || signal.set(value)))
+    }
+}
+
+/// Errors from saving or restoring a `StateStore`.
+#[derive(Debug)]
+pub enum StateError {
+    Io(std::io::Error),
+    Json(serde_json::Error),
+    /// The stored value under this name doesn't fit the signal's type.
+    Signal(String, serde_json::Error),
+}
+
+impl std::fmt::Display for StateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StateError::Io(err) => write!(f, "Failed to access the state file: {}", err),
+            StateError::Json(err) => write!(f, "Failed to read the state file: {}", err),
+            StateError::Signal(name, err) => write!(f, "Failed to restore \"{}\": {}", name, err),
+        }
+    }
+}
+
+impl std::error::Error for StateError {}
+
+impl From<std::io::Error> for StateError {
+    fn from(err: std::io::Error) -> Self { StateError::Io(err) }
+}
+
+impl From<serde_json::Error> for StateError {
+    fn from(err: serde_json::Error) -> Self { StateError::Json(err) }
+}
+
+/// Groups named signals so they can be saved to and restored from a JSON file, e.g. settings
+/// that should survive a restart:
+///
+/// ```ignore
+/// let store = StateStore::new().signal("dark_mode", &dark_mode).signal("volume", &volume);
+/// let _ = store.load_from("settings.json");
+/// // ... later, on change or on exit
+/// store.save_to("settings.json")?;
+/// ```
+///
+/// Restoring `set`s each signal inside one `batch`, so subscribers and redraws run as usual.
+/// Names missing from the file keep their current value and unknown names are ignored.
+#[derive(Default)]
+pub struct StateStore {
+    signals: Vec<(String, Rc<dyn PersistedSignal>)>,
+}
+
+impl StateStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a signal under `name`, replacing any signal already registered with that name.
+    pub fn signal<T>(mut self, name: impl Into<String>, signal: &Signal<T>) -> Self
+    where
+        T: Clone + serde::Serialize + serde::de::DeserializeOwned + 'static,
+    {
+        let name = name.into();
+        self.signals.retain(|(existing, _)| *existing != name);
+        self.signals.push((name, Rc::new(signal.clone())));
+        self
+    }
+
+    /// Snapshots every signal into a JSON object keyed by name.
+    pub fn to_json(&self) -> Result<String, StateError> {
+        let mut map = serde_json::Map::new();
+        for (name, signal) in &self.signals {
+            map.insert(name.clone(), signal.save()?);
+        }
+        Ok(serde_json::to_string_pretty(&serde_json::Value::Object(map))?)
+    }
+
+    /// Restores signals from a JSON object produced by `to_json`. Nothing is set unless every
+    /// stored value fits its signal.
+    pub fn load_json(&self, json: &str) -> Result<(), StateError> {
+        let mut map: serde_json::Map<String, serde_json::Value> = serde_json::from_str(json)?;
+        let mut restores = Vec::new();
+        for (name, signal) in &self.signals {
+            if let Some(value) = map.remove(name) {
+                let restore = signal.restore(value).map_err(|err| StateError::Signal(name.clone(), err))?;
+                restores.push(restore);
+            }
+        }
+        batch(|| restores.into_iter().for_each(|restore| restore()));
+        Ok(())
+    }
+
+    pub fn save_to(&self, path: impl AsRef<std::path::Path>) -> Result<(), StateError> {
+        std::fs::write(path, self.to_json()?)?;
+        Ok(())
+    }
+
+    pub fn load_from(&self, path: impl AsRef<std::path::Path>) -> Result<(), StateError> {
+        self.load_json(&std::fs::read_to_string(path)?)
+    }
+}
+
+/// How long after an edit the next one still joins the same undo step, by default.
+const HISTORY_COALESCE: Duration = Duration::from_millis(1000);
+
+struct HistoryState<T> {
+    signal: Signal<T>,
+    undo: RefCell<Vec<T>>,
+    redo: RefCell<Vec<T>>,
+    /// The signal's value as of the last recorded change.
+    current: RefCell<T>,
+    last_change: Cell<Option<Instant>>,
+    coalesce: Cell<Duration>,
+    limit: Cell<usize>,
+    /// Set while `undo`/`redo` write the signal, so that write isn't recorded as an edit.
+    restoring: Cell<bool>,
+}
+
+/// Undo and redo for a signal, e.g. the text of an editor.
+///
+/// Every change to the signal is recorded as it happens. Changes that follow each other
+/// closely (within `coalesce`, a second by default) join one undo step, so a burst of
+/// typing undoes as a whole; `checkpoint` starts a new step early. Clones share the history.
+///
+/// ```
+/// use noxkit::prelude::*;
+///
+/// let text = create_signal(String::new());
+/// let history = History::new(&text);
+/// text.set("Hello".into());
+/// history.checkpoint();
+/// text.set("Hello, world".into());
+/// history.undo();
+/// assert_eq!(text.get(), "Hello");
+/// history.redo();
+/// assert_eq!(text.get(), "Hello, world");
+/// ```
+pub struct History<T> {
+    state: Rc<HistoryState<T>>,
+}
+
+impl<T: Clone + PartialEq + 'static> History<T> {
+    pub fn new(signal: &Signal<T>) -> Self {
+        let state = Rc::new(HistoryState {
+            signal: signal.clone(),
+            undo: RefCell::new(Vec::new()),
+            redo: RefCell::new(Vec::new()),
+            current: RefCell::new(signal.value.borrow().clone()),
+            last_change: Cell::new(None),
+            coalesce: Cell::new(HISTORY_COALESCE),
+            limit: Cell::new(usize::MAX),
+            restoring: Cell::new(false),
+        });
+        // Held weakly so the listener left on the signal doesn't keep a dropped history alive
+        let weak = Rc::downgrade(&state);
+        signal.subscribe(move || {
+            if let Some(state) = weak.upgrade() {
+                state.record();
+            }
+        });
+        Self { state }
+    }
+
+    /// Changes closer together than `window` join one undo step; zero records every change
+    /// as its own step.
+    pub fn coalesce(self, window: Duration) -> Self {
+        self.state.coalesce.set(window);
+        self
+    }
+
+    /// Keeps at most `limit` undo steps, forgetting the oldest.
+    pub fn limit(self, limit: usize) -> Self {
+        self.state.limit.set(limit);
+        self
+    }
+
+    /// Makes the next change start a new undo step, e.g. at a word boundary or after a
+    /// paste.
+    pub fn checkpoint(&self) {
+        self.state.last_change.set(None);
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.state.undo.borrow().is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.state.redo.borrow().is_empty()
+    }
+
+    /// Restores the value from before the last undo step. Returns whether there was one.
+    pub fn undo(&self) -> bool {
+        let Some(previous) = self.state.undo.borrow_mut().pop() else { return false };
+        self.state.redo.borrow_mut().push(self.state.current.borrow().clone());
+        self.state.restore(previous);
+        true
+    }
+
+    /// Reapplies the last undone step. Returns whether there was one.
+    pub fn redo(&self) -> bool {
+        let Some(next) = self.state.redo.borrow_mut().pop() else { return false };
+        self.state.undo.borrow_mut().push(self.state.current.borrow().clone());
+        self.state.restore(next);
+        true
+    }
+
+    /// Forgets every step, keeping the current value.
+    pub fn clear(&self) {
+        self.state.undo.borrow_mut().clear();
+        self.state.redo.borrow_mut().clear();
+        self.checkpoint();
+    }
+}
+
+impl<T: Clone + PartialEq> HistoryState<T> {
+    fn record(&self) {
+        let value = self.signal.value.borrow().clone();
+        if self.restoring.get() || *self.current.borrow() == value {
+            return;
+        }
+        let now = Instant::now();
+        let joins_step = self.last_change.get().is_some_and(|last| now.duration_since(last) < self.coalesce.get());
+        let previous = self.current.replace(value);
+        if !joins_step {
+            let mut undo = self.undo.borrow_mut();
+            undo.push(previous);
+            if undo.len() > self.limit.get() {
+                undo.remove(0);
+            }
+        }
+        self.redo.borrow_mut().clear();
+        self.last_change.set(Some(now));
+    }
+
+    fn restore(&self, value: T) {
+        *self.current.borrow_mut() = value.clone();
+        self.last_change.set(None);
+        self.restoring.set(true);
+        self.signal.set(value);
+        self.restoring.set(false);
+    }
+}
+
+impl<T> Clone for History<T> {
+    fn clone(&self) -> Self {
+        Self { state: self.state.clone() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computed_follows_either_signal() {
+        let a = create_signal(1);
+        let b = create_signal(2);
+        let sum = create_computed({
+            let (a, b) = (a.clone(), b.clone());
+            move || a.get() + b.get()
+        });
+        assert_eq!(sum.get(), 3);
+        a.set(10);
+        assert_eq!(sum.get(), 12);
+        b.set(20);
+        assert_eq!(sum.get(), 30);
+    }
+
+    #[test]
+    fn computed_drops_dependencies_it_no_longer_reads() {
+        let use_a = create_signal(true);
+        let a = create_signal(1);
+        let b = create_signal(2);
+        let picked = create_computed({
+            let (use_a, a, b) = (use_a.clone(), a.clone(), b.clone());
+            move || if use_a.get() { a.get() } else { b.get() }
+        });
+        assert_eq!(a.subscriber_count(), 1);
+        use_a.set(false);
+        assert_eq!(picked.get(), 2);
+        assert_eq!(a.subscriber_count(), 0);
+        assert_eq!(b.subscriber_count(), 1);
+    }
+
+    #[test]
+    fn derive_recomputes_once_when_both_change_inside_batch() {
+        let first = create_signal(String::from("Ada"));
+        let last = create_signal(String::from("Lovelace"));
+        let runs = Rc::new(Cell::new(0));
+        let full = Computed::derive((&first, &last), {
+            let runs = runs.clone();
+            move |first, last| {
+                runs.set(runs.get() + 1);
+                format!("{} {}", first, last)
+            }
+        });
+        runs.set(0);
+
+        batch(|| {
+            first.set("Grace".into());
+            last.set("Hopper".into());
+        });
+        assert_eq!(runs.get(), 1);
+        assert_eq!(full.get(), "Grace Hopper");
+    }
+
+    #[test]
+    fn batch_recovers_from_a_panic() {
+        let count = create_signal(0);
+        let seen = Rc::new(Cell::new(0));
+        count.subscribe({
+            let (count, seen) = (count.clone(), seen.clone());
+            move || seen.set(count.get())
+        });
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            batch(|| {
+                count.set(1);
+                panic!("failed halfway through");
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(seen.get(), 1);
+
+        // Later changes aren't held back by the unfinished batch
+        count.set(2);
+        assert_eq!(seen.get(), 2);
+    }
+
+    #[test]
+    fn effect_skips_rerun_when_memo_output_is_unchanged() {
+        let count = create_signal(1);
+        let is_even = create_memo(&count, |n| n % 2 == 0);
+        let runs = Rc::new(Cell::new(0));
+        create_effect({
+            let (is_even, runs) = (is_even.clone(), runs.clone());
+            move || {
+                is_even.get();
+                runs.set(runs.get() + 1);
+            }
+        });
+        assert_eq!(runs.get(), 1);
+
+        // 3 is odd like 1, so the memo keeps its value and the effect stays put
+        count.set(3);
+        assert_eq!(runs.get(), 1);
+
+        count.set(4);
+        assert_eq!(runs.get(), 2);
+        assert!(is_even.get());
+    }
 }
@@ -0,0 +1,223 @@
+use crate::path::{FillRule, PathCommand};
+use crate::render::{RecordedShape, Shape};
+use crate::view::Geometry;
+use std::fmt::Write;
+
+/// Builds an SVG document of `size` layout pixels from recorded shapes and text areas:
+/// shapes first, then text, the order the GPU draws them in.
+pub(crate) fn write_svg(
+    size: Geometry,
+    background: Option<[f32; 4]>,
+    mut shapes: Vec<RecordedShape>,
+    text_areas: &[glyphon::TextArea],
+    font_system: &glyphon::FontSystem,
+) -> String {
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#,
+        w = num(size.width),
+        h = num(size.height),
+    );
+    if let Some(color) = background.filter(|color| color[3] > 0.0) {
+        let _ = writeln!(svg, r#"<rect width="100%" height="100%"{}/>"#, fill(color));
+    }
+
+    // Same stable order as `RenderQueue::sort_by_z`
+    shapes.sort_by_key(|recorded| recorded.z_index);
+    let mut defs = Defs::default();
+    let mut body = String::new();
+    for recorded in &shapes {
+        let clip = recorded.clip.map(|clip| defs.clip(clip.geometry, clip.radius));
+        write_shape(&mut body, &mut defs, &recorded.shape, clip);
+    }
+    for area in text_areas {
+        write_text(&mut body, &mut defs, area, font_system);
+    }
+
+    if !defs.svg.is_empty() {
+        let _ = write!(svg, "<defs>\n{}</defs>\n", defs.svg);
+    }
+    svg.push_str(&body);
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Clip paths and gradients referenced by id from the body.
+#[derive(Default)]
+struct Defs {
+    svg: String,
+    clips: Vec<([u32; 5], usize)>,
+    next_id: usize,
+}
+
+impl Defs {
+    fn id(&mut self) -> usize {
+        self.next_id += 1;
+        self.next_id
+    }
+
+    /// The id of a clip path for the rounded rect, shared between shapes with the same clip.
+    fn clip(&mut self, geometry: Geometry, radius: f32) -> usize {
+        let key = [geometry.x, geometry.y, geometry.width, geometry.height, radius].map(f32::to_bits);
+        if let Some(&(_, id)) = self.clips.iter().find(|(clip, _)| *clip == key) {
+            return id;
+        }
+        let id = self.id();
+        let _ = writeln!(self.svg, r#"<clipPath id="c{id}">{}</clipPath>"#, rect_element(geometry, radius, ""));
+        self.clips.push((key, id));
+        id
+    }
+}
+
+fn write_shape(out: &mut String, defs: &mut Defs, shape: &Shape, clip: Option<usize>) {
+    let clip = clip.map(|id| format!(r#" clip-path="url(#c{id})""#)).unwrap_or_default();
+    let element = match shape {
+        Shape::Rect { geometry, color, radius } => rect_element(*geometry, *radius, &(fill(*color) + &clip)),
+        Shape::Circle { geometry, color } => format!(
+            r#"<circle cx="{}" cy="{}" r="{}"{}{clip}/>"#,
+            num(geometry.x + geometry.width * 0.5),
+            num(geometry.y + geometry.height * 0.5),
+            num(geometry.width.min(geometry.height) * 0.5),
+            fill(*color),
+        ),
+        Shape::Arc { geometry, color, thickness, start_angle, sweep } => {
+            let center = [geometry.x + geometry.width * 0.5, geometry.y + geometry.height * 0.5];
+            let radius = (geometry.width.min(geometry.height) - thickness) * 0.5;
+            let mut stroke = format!(r#" fill="none" stroke="{}" stroke-width="{}" stroke-linecap="round""#, hex(*color), num(*thickness));
+            if color[3] < 1.0 {
+                let _ = write!(stroke, r#" stroke-opacity="{}""#, num(color[3]));
+            }
+            if *sweep >= 360.0 {
+                format!(r#"<circle cx="{}" cy="{}" r="{}"{stroke}{clip}/>"#, num(center[0]), num(center[1]), num(radius))
+            } else {
+                // Angles turn clockwise from 12 o'clock, as in `RenderQueue::push_arc`
+                let point = |angle: f32| {
+                    let (sin, cos) = angle.to_radians().sin_cos();
+                    format!("{} {}", num(center[0] + radius * sin), num(center[1] - radius * cos))
+                };
+                format!(
+                    r#"<path d="M {} A {r} {r} 0 {} 1 {}"{stroke}{clip}/>"#,
+                    point(*start_angle),
+                    u8::from(*sweep > 180.0),
+                    point(start_angle + sweep),
+                    r = num(radius),
+                )
+            }
+        }
+        Shape::LinearGradient { geometry, from, to, angle } => {
+            // The same gradient line as `RenderQueue::push_linear_gradient`
+            let (sin, cos) = angle.to_radians().sin_cos();
+            let direction = [sin, -cos];
+            let half_length = (geometry.width * direction[0].abs() + geometry.height * direction[1].abs()) * 0.5;
+            let center = [geometry.x + geometry.width * 0.5, geometry.y + geometry.height * 0.5];
+            let id = defs.id();
+            let _ = writeln!(
+                defs.svg,
+                r#"<linearGradient id="g{id}" gradientUnits="userSpaceOnUse" x1="{}" y1="{}" x2="{}" y2="{}"><stop offset="0" stop-color="{}" stop-opacity="{}"/><stop offset="1" stop-color="{}" stop-opacity="{}"/></linearGradient>"#,
+                num(center[0] - direction[0] * half_length),
+                num(center[1] - direction[1] * half_length),
+                num(center[0] + direction[0] * half_length),
+                num(center[1] + direction[1] * half_length),
+                hex(*from),
+                num(from[3]),
+                hex(*to),
+                num(to[3]),
+            );
+            rect_element(*geometry, 0.0, &format!(r#" fill="url(#g{id})"{clip}"#))
+        }
+        Shape::Polygon { points, color } => {
+            let points: Vec<String> = points.iter().map(|[x, y]| format!("{},{}", num(*x), num(*y))).collect();
+            format!(r#"<polygon points="{}"{}{clip}/>"#, points.join(" "), fill(*color))
+        }
+        Shape::Path { commands, fill_rule, color } => {
+            let point = |[x, y]: [f32; 2]| format!("{} {}", num(x), num(y));
+            let d: Vec<String> = commands.iter().map(|command| match *command {
+                PathCommand::MoveTo(to) => format!("M {}", point(to)),
+                PathCommand::LineTo(to) => format!("L {}", point(to)),
+                PathCommand::QuadTo { ctrl, to } => format!("Q {} {}", point(ctrl), point(to)),
+                PathCommand::CubicTo { ctrl1, ctrl2, to } => format!("C {} {} {}", point(ctrl1), point(ctrl2), point(to)),
+                PathCommand::Close => "Z".to_string(),
+            }).collect();
+            let rule = match fill_rule {
+                FillRule::NonZero => "",
+                FillRule::EvenOdd => r#" fill-rule="evenodd""#,
+            };
+            format!(r#"<path d="{}"{}{rule}{clip}/>"#, d.join(" "), fill(*color))
+        }
+    };
+    out.push_str(&element);
+    out.push('\n');
+}
+
+/// One `<text>` per laid-out line, clipped to the area's bounds.
+fn write_text(out: &mut String, defs: &mut Defs, area: &glyphon::TextArea, font_system: &glyphon::FontSystem) {
+    let bounds = area.bounds;
+    let clip = defs.clip(
+        Geometry {
+            x: bounds.left as f32,
+            y: bounds.top as f32,
+            width: (bounds.right - bounds.left) as f32,
+            height: (bounds.bottom - bounds.top) as f32,
+        },
+        0.0,
+    );
+    for run in area.buffer.layout_runs() {
+        let (Some(first), Some(last)) = (run.glyphs.first(), run.glyphs.last()) else { continue };
+        let start = run.glyphs.iter().map(|glyph| glyph.start).min().unwrap_or(first.start);
+        let end = run.glyphs.iter().map(|glyph| glyph.end).max().unwrap_or(last.end);
+        let left = run.glyphs.iter().map(|glyph| glyph.x).fold(f32::MAX, f32::min);
+        let right = run.glyphs.iter().map(|glyph| glyph.x + glyph.w).fold(f32::MIN, f32::max);
+        let color = first.color_opt.unwrap_or(area.default_color);
+        let family = font_system.db().face(first.font_id)
+            .and_then(|face| face.families.first())
+            .map(|(family, _)| format!("{}, sans-serif", escape(family)))
+            .unwrap_or_else(|| "sans-serif".to_string());
+        // Right-to-left lines start at their right edge
+        let (x, direction) = if run.rtl { (right, r#" direction="rtl""#) } else { (left, "") };
+        let _ = writeln!(
+            out,
+            r#"<text x="{}" y="{}" font-family="{family}" font-size="{}"{}{direction} xml:space="preserve" clip-path="url(#c{clip})">{}</text>"#,
+            num(area.left + x * area.scale),
+            num(area.top + run.line_y * area.scale),
+            num(first.font_size * area.scale),
+            fill([color.r(), color.g(), color.b(), color.a()].map(|c| c as f32 / 255.0)),
+            escape(&run.text[start..end]),
+        );
+    }
+}
+
+fn rect_element(geometry: Geometry, radius: f32, attributes: &str) -> String {
+    let radius = if radius > 0.0 { format!(r#" rx="{}""#, num(radius)) } else { String::new() };
+    format!(
+        r#"<rect x="{}" y="{}" width="{}" height="{}"{radius}{attributes}/>"#,
+        num(geometry.x),
+        num(geometry.y),
+        num(geometry.width.max(0.0)),
+        num(geometry.height.max(0.0)),
+    )
+}
+
+fn fill(color: [f32; 4]) -> String {
+    if color[3] >= 1.0 {
+        format!(r#" fill="{}""#, hex(color))
+    } else {
+        format!(r#" fill="{}" fill-opacity="{}""#, hex(color), num(color[3]))
+    }
+}
+
+fn hex(color: [f32; 4]) -> String {
+    let [r, g, b, _] = color.map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8);
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+/// Formats a coordinate with at most two decimals and no trailing zeros.
+fn num(value: f32) -> String {
+    let text = format!("{:.2}", value);
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+    if text == "-0" { "0".to_string() } else { text.to_string() }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
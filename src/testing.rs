@@ -0,0 +1,259 @@
+use crate::view::{View, Geometry, Event, Key, Modifiers, GamepadButton};
+use crate::layout::{self, LayoutContext, LayoutError};
+use crate::app::{App, dispatch_hover, dispatch_focus_navigation, hovered_name};
+
+/// Drives a view with synthetic input and no window or GPU, for testing interactive widgets.
+///
+/// The harness lays the view out at a fixed size in layout pixels and dispatches events the
+/// way `App` does, including `MouseEnter`/`MouseLeave` as the pointer moves and a new layout
+/// after `request_layout`. Nothing is drawn, so state that widgets only compute in `prepare`
+/// stays unset; use `Renderer` for that.
+///
+/// ```
+/// use noxkit::prelude::*;
+/// use noxkit::testing::TestHarness;
+///
+/// let clicks = create_signal(0);
+/// let button = Button::new("Save", {
+///     let clicks = clicks.clone();
+///     move || clicks.update(|n| *n += 1)
+/// })
+/// .debug_name("save");
+/// let mut harness = TestHarness::new(Box::new(button), 400.0, 300.0).unwrap();
+///
+/// let save = harness.find_named("save").unwrap();
+/// harness.click(save.x + save.width / 2.0, save.y + save.height / 2.0);
+/// assert_eq!(clicks.get(), 1);
+/// ```
+pub struct TestHarness {
+    view: Box<dyn View>,
+    layout_ctx: LayoutContext,
+    font_system: glyphon::FontSystem,
+    root_node: Option<taffy::prelude::NodeId>,
+    size: (f32, f32),
+    modifiers: Modifiers,
+    hovered_nodes: Vec<taffy::prelude::NodeId>,
+    cursor: Option<(f32, f32)>,
+}
+
+impl TestHarness {
+    /// Mounts `view` and lays it out in a `width` x `height` window.
+    pub fn new(view: Box<dyn View>, width: f32, height: f32) -> Result<Self, LayoutError> {
+        let mut harness = Self {
+            view,
+            layout_ctx: LayoutContext::new(),
+            font_system: glyphon::FontSystem::new(),
+            root_node: None,
+            size: (width, height),
+            modifiers: Modifiers::default(),
+            hovered_nodes: Vec::new(),
+            cursor: None,
+        };
+        harness.view.on_init();
+        harness.view.on_mount();
+        harness.relayout()?;
+        Ok(harness)
+    }
+
+    /// Lays the view out again for a window of the new size.
+    pub fn resize(&mut self, width: f32, height: f32) -> Result<(), LayoutError> {
+        self.size = (width, height);
+        self.relayout()?;
+        self.view.on_resize(self.layout_ctx.window_size.width, self.layout_ctx.window_size.height);
+        Ok(())
+    }
+
+    /// Lays the view out again with `size` as the base font size, like
+    /// `App::with_base_font_size`.
+    ///
+    /// ```
+    /// use noxkit::prelude::*;
+    /// use noxkit::testing::TestHarness;
+    ///
+    /// let mut harness = TestHarness::new(Box::new(Text::new("Hello").debug_name("hello")), 400.0, 300.0).unwrap();
+    /// harness.set_base_font_size(20.0).unwrap();
+    /// // Unsized text follows the base, with its default 1.5 line height
+    /// assert_eq!(harness.find_named("hello").unwrap().height, 30.0);
+    /// ```
+    pub fn set_base_font_size(&mut self, size: f32) -> Result<(), LayoutError> {
+        self.layout_ctx.base_font_size = size.max(1.0);
+        self.relayout()
+    }
+
+    fn relayout(&mut self) -> Result<(), LayoutError> {
+        let size = winit::dpi::PhysicalSize::new(self.size.0.ceil() as u32, self.size.1.ceil() as u32);
+        self.root_node = Some(App::update_layout(&mut self.view, &mut self.layout_ctx, size, &mut self.font_system)?);
+        Ok(())
+    }
+
+    /// The window's area in layout pixels.
+    pub fn geometry(&self) -> Geometry {
+        Geometry { x: 0.0, y: 0.0, width: self.layout_ctx.window_size.width, height: self.layout_ctx.window_size.height }
+    }
+
+    /// The last layout, e.g. for `named_geometries` or `dump_tree`.
+    pub fn layout_context(&self) -> &LayoutContext {
+        &self.layout_ctx
+    }
+
+    /// Where the node given `debug_name(name)` was laid out, in window coordinates.
+    pub fn find_named(&self, name: &str) -> Option<Geometry> {
+        self.layout_ctx.find_named(name)
+    }
+
+    pub fn view(&self) -> &dyn View {
+        self.view.as_ref()
+    }
+
+    pub fn view_mut(&mut self) -> &mut dyn View {
+        self.view.as_mut()
+    }
+
+    /// Delivers `event` to the view as is, moving focus for d-pad presses like `App`, then
+    /// lays out again if a widget asked for it.
+    pub fn send(&mut self, event: &Event) {
+        let geometry = self.geometry();
+        self.view.handle_event(event, &self.layout_ctx, geometry);
+        dispatch_focus_navigation(self.view.as_mut(), &self.layout_ctx, event, geometry);
+        self.view.on_update();
+        if layout::take_layout_request() {
+            self.layout_ctx.invalidate();
+            // The size and tree laid out fine before, so this only fails if a widget broke
+            self.relayout().expect("relayout after an event failed");
+        }
+    }
+
+    /// Moves the pointer to (`x`, `y`), sending `MouseMove` and any enter/leave events.
+    pub fn move_to(&mut self, x: f32, y: f32) {
+        self.send(&Event::MouseMove { x, y });
+        self.cursor = Some((x, y));
+        let geometry = self.geometry();
+        dispatch_hover(self.view.as_mut(), &self.layout_ctx, self.root_node, &mut self.hovered_nodes, self.cursor, geometry);
+    }
+
+    /// The `debug_name` of the innermost named widget under the pointer, as
+    /// `App::with_hovered_widget` reports it.
+    ///
+    /// ```
+    /// use noxkit::prelude::*;
+    /// use noxkit::testing::TestHarness;
+    ///
+    /// let view = Column::default()
+    ///     .child(Row::default().child(Button::new("+", || {}).debug_name("Increment button")).debug_name("toolbar"));
+    /// let mut harness = TestHarness::new(Box::new(view), 400.0, 300.0).unwrap();
+    ///
+    /// let button = harness.find_named("Increment button").unwrap();
+    /// harness.move_to(button.x + 2.0, button.y + 2.0);
+    /// assert_eq!(harness.hovered_widget().as_deref(), Some("Increment button"));
+    /// harness.move_to(button.x + button.width + 8.0, button.y + 2.0);
+    /// assert_eq!(harness.hovered_widget().as_deref(), Some("toolbar"));
+    /// ```
+    pub fn hovered_widget(&self) -> Option<String> {
+        hovered_name(&self.layout_ctx, &self.hovered_nodes)
+    }
+
+    /// Moves the pointer to (`x`, `y`) and clicks there, sending `MouseDown`, `MouseClick`
+    /// and `MouseUp` in the order `App` does.
+    pub fn click(&mut self, x: f32, y: f32) {
+        self.move_to(x, y);
+        self.send(&Event::MouseDown { x, y });
+        self.send(&Event::MouseClick { x, y });
+        self.send(&Event::MouseUp { x, y });
+    }
+
+    /// Clicks twice at (`x`, `y`), the second click followed by `DoubleClick` as in `App`.
+    pub fn double_click(&mut self, x: f32, y: f32) {
+        self.click(x, y);
+        self.move_to(x, y);
+        self.send(&Event::MouseDown { x, y });
+        self.send(&Event::MouseClick { x, y });
+        self.send(&Event::DoubleClick { x, y });
+        self.send(&Event::MouseUp { x, y });
+    }
+
+    /// Presses at (`x`, `y`) and holds until a `LongPress`, without waiting out the real
+    /// long press time, then releases.
+    ///
+    /// ```
+    /// use noxkit::prelude::*;
+    /// use noxkit::testing::TestHarness;
+    ///
+    /// let selecting = create_signal(false);
+    /// let item = Interactive::new(Text::new("Photo").debug_name("photo")).on_long_press({
+    ///     let selecting = selecting.clone();
+    ///     move |_, _| selecting.set(true)
+    /// });
+    /// let mut harness = TestHarness::new(Box::new(item), 400.0, 300.0).unwrap();
+    ///
+    /// let photo = harness.find_named("photo").unwrap();
+    /// harness.click(photo.x + 2.0, photo.y + 2.0);
+    /// assert!(!selecting.get());
+    /// harness.long_press(photo.x + 2.0, photo.y + 2.0);
+    /// assert!(selecting.get());
+    /// ```
+    pub fn long_press(&mut self, x: f32, y: f32) {
+        self.move_to(x, y);
+        self.send(&Event::MouseDown { x, y });
+        self.send(&Event::MouseClick { x, y });
+        self.send(&Event::LongPress { x, y });
+        self.send(&Event::MouseUp { x, y });
+    }
+
+    /// Presses and releases shift, ctrl, alt or logo, e.g. around a click for shift-click.
+    pub fn set_modifiers(&mut self, modifiers: Modifiers) {
+        self.modifiers = modifiers;
+        self.send(&Event::ModifiersChanged(modifiers));
+    }
+
+    /// Presses `key` with the modifiers currently held.
+    pub fn key(&mut self, key: Key) {
+        self.send(&Event::KeyDown { key, modifiers: self.modifiers });
+    }
+
+    /// Presses and releases `button` on a game controller. D-pad presses move focus.
+    ///
+    /// ```
+    /// use noxkit::prelude::*;
+    /// use noxkit::testing::TestHarness;
+    ///
+    /// let picked = create_signal(String::new());
+    /// let button = |label: &'static str| -> Box<dyn View> {
+    ///     let picked = picked.clone();
+    ///     Box::new(Button::new(label, move || picked.set(label.to_string())))
+    /// };
+    /// let grid = Column::new(vec![
+    ///     Box::new(Row::new(vec![button("A1"), button("A2")])),
+    ///     Box::new(Row::new(vec![button("B1"), button("B2")])),
+    /// ]);
+    /// let mut harness = TestHarness::new(Box::new(grid), 400.0, 300.0).unwrap();
+    ///
+    /// // Nothing has focus yet, so the first press focuses the first button
+    /// harness.gamepad(GamepadButton::DPadDown);
+    /// harness.gamepad(GamepadButton::DPadRight);
+    /// harness.gamepad(GamepadButton::DPadDown);
+    /// harness.gamepad(GamepadButton::South);
+    /// assert_eq!(picked.get(), "B2");
+    /// ```
+    pub fn gamepad(&mut self, button: GamepadButton) {
+        self.send(&Event::GamepadButton { button, pressed: true });
+        self.send(&Event::GamepadButton { button, pressed: false });
+    }
+
+    /// Types `text` one key at a time, as a keyboard without an input method would. Line
+    /// breaks press `Enter` and tabs `Tab`.
+    pub fn type_text(&mut self, text: &str) {
+        for c in text.chars() {
+            self.key(match c {
+                '\n' => Key::Enter,
+                '\t' => Key::Tab,
+                c => Key::Character(c),
+            });
+        }
+    }
+}
+
+impl Drop for TestHarness {
+    fn drop(&mut self) {
+        self.view.on_unmount();
+    }
+}
@@ -1,7 +1,8 @@
-use crate::layout::LayoutContext;
+use crate::layout::{LayoutContext, LayoutError};
 use crate::render::RenderContext;
+use std::path::PathBuf;
 
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
 pub struct Geometry {
     pub x: f32,
     pub y: f32,
@@ -13,26 +14,413 @@ impl Geometry {
     pub fn contains(&self, px: f32, py: f32) -> bool {
         px >= self.x && px <= self.x + self.width && py >= self.y && py <= self.y + self.height
     }
+
+    /// Converts a point in window coordinates, like those events carry, to coordinates
+    /// relative to this geometry's top-left corner.
+    pub fn to_local(&self, px: f32, py: f32) -> (f32, f32) {
+        (px - self.x, py - self.y)
+    }
+}
+
+/// A key press, translated from winit. Printable input arrives as `Character`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    Character(char),
+    Enter,
+    Tab,
+    Backspace,
+    Delete,
+    Escape,
+    ArrowLeft,
+    ArrowRight,
+    ArrowUp,
+    ArrowDown,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+}
+
+/// Where a touch point is in its lifetime, translated from winit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TouchPhase {
+    Started,
+    Moved,
+    Ended,
+    Cancelled,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Modifiers {
+    pub shift: bool,
+    pub ctrl: bool,
+    pub alt: bool,
+    pub logo: bool,
+}
+
+#[derive(Debug, Clone)]
 pub enum Event {
     MouseClick { x: f32, y: f32 },
     MouseMove { x: f32, y: f32 },
     MouseDown { x: f32, y: f32 },
     MouseUp { x: f32, y: f32 },
+    /// A second click on the same spot soon after the first (see
+    /// `App::with_double_click_time`), sent after the second click's `MouseClick`.
+    DoubleClick { x: f32, y: f32 },
+    /// The mouse button was held down without moving for the long press time (see
+    /// `App::with_long_press_time`). Sent while the button is still down; the `MouseUp`
+    /// follows whenever it is released.
+    LongPress { x: f32, y: f32 },
+    KeyDown { key: Key, modifiers: Modifiers },
+    /// Text being composed by an input method, with the IME caret/selection as byte offsets.
+    /// An empty `text` clears the composition.
+    ImePreedit { text: String, cursor: Option<(usize, usize)> },
+    /// Text confirmed by an input method, to be inserted at the caret.
+    ImeCommit(String),
+    /// A file dragged from the OS is over the window at the cursor position.
+    FileHovered { x: f32, y: f32 },
+    /// The file drag left the window or was cancelled.
+    FileHoverCancelled,
+    /// Files dropped onto the window. Winit reports each file separately; the app collects
+    /// the files of one drop gesture into a single event.
+    FileDropped { paths: Vec<PathBuf>, x: f32, y: f32 },
+    /// A finger on a touchscreen. `id` stays the same from `Started` to `Ended`/`Cancelled`.
+    Touch { id: u64, phase: TouchPhase, x: f32, y: f32 },
+    /// The pointer moved onto the widget laid out as `node`. Sent once per crossing, to the
+    /// whole tree; widgets compare `node` with their own node id.
+    MouseEnter { node: taffy::prelude::NodeId, x: f32, y: f32 },
+    /// The pointer moved off the widget laid out as `node`, or left the window.
+    MouseLeave { node: taffy::prelude::NodeId, x: f32, y: f32 },
+    /// A mouse wheel or trackpad scroll at the cursor position, in pixels. Positive values
+    /// move the content up/left, i.e. advance the scroll offset.
+    Scroll { dx: f32, dy: f32, x: f32, y: f32 },
+    /// A trackpad pinch step. `scale` is the zoom factor for this step (above 1 zooms in),
+    /// `center` the point to keep fixed, i.e. the cursor position.
+    Pinch { scale: f32, center: (f32, f32) },
+    /// The window gained or lost keyboard focus, e.g. to dim it or pause animations while
+    /// the user is in another app.
+    WindowFocus { focused: bool },
+    /// Shift, ctrl, alt or logo was pressed or released. Sent to the whole tree, so widgets
+    /// can tell e.g. a shift-click from a plain click.
+    ModifiersChanged(Modifiers),
+    /// A game controller button was pressed or released. Needs the `gamepad` feature, with
+    /// which the d-pad also moves focus (see `FocusMoved`).
+    GamepadButton { button: GamepadButton, pressed: bool },
+    /// A game controller stick or trigger moved, to a `value` in -1..1 (0..1 for triggers).
+    GamepadAxis { axis: GamepadAxis, value: f32 },
+    /// The d-pad moved focus to the widget laid out as `node`. Sent to the whole tree;
+    /// focusable widgets (see `LayoutContext::set_focusable`) take or drop focus by
+    /// comparing `node` with their own node id.
+    FocusMoved { node: taffy::prelude::NodeId },
+}
+
+/// A game controller button, named by position like gilrs: `South` is A on an Xbox pad.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GamepadButton {
+    South,
+    East,
+    North,
+    West,
+    LeftBumper,
+    RightBumper,
+    LeftTrigger,
+    RightTrigger,
+    Select,
+    Start,
+    LeftStick,
+    RightStick,
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GamepadAxis {
+    LeftStickX,
+    LeftStickY,
+    RightStickX,
+    RightStickY,
+    LeftTrigger,
+    RightTrigger,
+}
+
+impl Event {
+    /// The pointer position this event happened at, if it has one.
+    pub fn position(&self) -> Option<(f32, f32)> {
+        match *self {
+            Event::MouseClick { x, y }
+            | Event::MouseMove { x, y }
+            | Event::MouseDown { x, y }
+            | Event::MouseUp { x, y }
+            | Event::DoubleClick { x, y }
+            | Event::LongPress { x, y }
+            | Event::FileHovered { x, y }
+            | Event::FileDropped { x, y, .. }
+            | Event::Touch { x, y, .. }
+            | Event::MouseEnter { x, y, .. }
+            | Event::MouseLeave { x, y, .. }
+            | Event::Scroll { x, y, .. } => Some((x, y)),
+            Event::Pinch { center, .. } => Some(center),
+            Event::KeyDown { .. }
+            | Event::ImePreedit { .. }
+            | Event::ImeCommit(_)
+            | Event::FileHoverCancelled
+            | Event::WindowFocus { .. }
+            | Event::ModifiersChanged(_)
+            | Event::GamepadButton { .. }
+            | Event::GamepadAxis { .. }
+            | Event::FocusMoved { .. } => None,
+        }
+    }
+
+    /// A copy of this event with its pointer position passed through `f`, e.g. to convert
+    /// it into the coordinates of a zoomed child.
+    pub fn map_position(&self, f: impl Fn(f32, f32) -> (f32, f32)) -> Event {
+        let mut event = self.clone();
+        match &mut event {
+            Event::MouseClick { x, y }
+            | Event::MouseMove { x, y }
+            | Event::MouseDown { x, y }
+            | Event::MouseUp { x, y }
+            | Event::DoubleClick { x, y }
+            | Event::LongPress { x, y }
+            | Event::FileHovered { x, y }
+            | Event::FileDropped { x, y, .. }
+            | Event::Touch { x, y, .. }
+            | Event::MouseEnter { x, y, .. }
+            | Event::MouseLeave { x, y, .. }
+            | Event::Scroll { x, y, .. } => (*x, *y) = f(*x, *y),
+            Event::Pinch { center, .. } => *center = f(center.0, center.1),
+            Event::KeyDown { .. }
+            | Event::ImePreedit { .. }
+            | Event::ImeCommit(_)
+            | Event::FileHoverCancelled
+            | Event::WindowFocus { .. }
+            | Event::ModifiersChanged(_)
+            | Event::GamepadButton { .. }
+            | Event::GamepadAxis { .. }
+            | Event::FocusMoved { .. } => {}
+        }
+        event
+    }
+}
+
+/// Hover/press/focus state machine shared by interactive widgets.
+///
+/// Feed every event through `handle_event` with the widget's resolved geometry; the
+/// returned `InteractionChange` reports which flags flipped and whether a click landed.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Interaction {
+    pub hovered: bool,
+    pub pressed: bool,
+    pub focused: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct InteractionChange {
+    pub hover_changed: bool,
+    pub press_changed: bool,
+    pub focus_changed: bool,
+    pub clicked: bool,
+    pub double_clicked: bool,
+    pub long_pressed: bool,
+}
+
+impl Interaction {
+    pub fn handle_event(&mut self, event: &Event, geometry: Geometry) -> InteractionChange {
+        let before = *self;
+        let mut clicked = false;
+        let mut double_clicked = false;
+        let mut long_pressed = false;
+
+        match event {
+            Event::MouseMove { x, y } => {
+                self.hovered = geometry.contains(*x, *y);
+                // Dragging off the widget cancels the press
+                if !self.hovered {
+                    self.pressed = false;
+                }
+            }
+            Event::MouseDown { x, y } => {
+                let inside = geometry.contains(*x, *y);
+                self.pressed = inside;
+                self.focused = inside;
+            }
+            Event::MouseUp { .. } => {
+                self.pressed = false;
+            }
+            Event::MouseClick { x, y } => {
+                clicked = geometry.contains(*x, *y);
+            }
+            Event::DoubleClick { x, y } => {
+                double_clicked = geometry.contains(*x, *y);
+            }
+            Event::LongPress { x, y } => {
+                long_pressed = geometry.contains(*x, *y);
+            }
+            Event::KeyDown { .. }
+            | Event::ImePreedit { .. }
+            | Event::ImeCommit(_)
+            | Event::FileHovered { .. }
+            | Event::FileHoverCancelled
+            | Event::FileDropped { .. }
+            | Event::Touch { .. }
+            | Event::MouseEnter { .. }
+            | Event::MouseLeave { .. }
+            | Event::Scroll { .. }
+            | Event::Pinch { .. }
+            | Event::WindowFocus { .. }
+            | Event::ModifiersChanged(_)
+            | Event::GamepadButton { .. }
+            | Event::GamepadAxis { .. }
+            | Event::FocusMoved { .. } => {}
+        }
+
+        InteractionChange {
+            hover_changed: before.hovered != self.hovered,
+            press_changed: before.pressed != self.pressed,
+            focus_changed: before.focused != self.focused,
+            clicked,
+            double_clicked,
+            long_pressed,
+        }
+    }
 }
 
+/// A node in the UI tree.
+///
+/// `App` calls the lifecycle hooks on the root view as below, and containers forward them
+/// to their children:
+///
+/// 1. `on_init`, then `on_mount`, once the view becomes the root: at startup, or when
+///    `Commands::set_root` or an `App::with_render_fn` rebuild swaps it in. Views that swap
+///    subtrees (like `Responsive`) also mount and unmount them as they come and go.
+/// 2. `layout`, at startup, after a resize that changed the window's size (resizes to the
+///    same size keep the last layout) and on the next frame or event after `request_layout`.
+///    `on_resize` follows every resize, once the layout is up to date.
+/// 3. Every frame: `on_update`, then `prepare`, `collect_text_areas` and `render`.
+///    `handle_event` runs between frames as input arrives.
+/// 4. `on_unmount`, once, when the app exits or another root replaces this one.
+///
+/// ```
+/// use noxkit::prelude::*;
+/// use noxkit::layout::LayoutContext;
+/// use noxkit::view::Geometry;
+/// use noxkit::testing::TestHarness;
+/// use taffy::prelude::*;
+/// use std::{cell::RefCell, rc::Rc};
+///
+/// // Records each call it gets
+/// struct Recorder(Rc<RefCell<Vec<&'static str>>>);
+///
+/// impl View for Recorder {
+///     fn layout(&mut self, ctx: &mut LayoutContext) -> Result<NodeId, LayoutError> {
+///         self.0.borrow_mut().push("layout");
+///         Ok(ctx.taffy.new_leaf(Style::default())?)
+///     }
+///     fn render<'rp>(&'rp self, _: &'rp noxkit::render::RenderContext, _: &mut wgpu::RenderPass<'rp>, _: Geometry) {}
+///     fn handle_event(&mut self, _: &Event, _: &LayoutContext, _: Geometry) { self.0.borrow_mut().push("event"); }
+///     fn on_init(&mut self) { self.0.borrow_mut().push("init"); }
+///     fn on_mount(&mut self) { self.0.borrow_mut().push("mount"); }
+///     fn on_update(&mut self) { self.0.borrow_mut().push("update"); }
+///     fn on_unmount(&mut self) { self.0.borrow_mut().push("unmount"); }
+///     fn on_resize(&mut self, _: f32, _: f32) { self.0.borrow_mut().push("resize"); }
+/// }
+///
+/// let calls = Rc::new(RefCell::new(Vec::new()));
+/// let mut harness = TestHarness::new(Box::new(Recorder(calls.clone())), 400.0, 300.0).unwrap();
+/// assert_eq!(*calls.borrow(), ["init", "mount", "layout"]);
+///
+/// calls.borrow_mut().clear();
+/// harness.resize(400.0, 300.0).unwrap();
+/// harness.resize(500.0, 300.0).unwrap();
+/// assert_eq!(*calls.borrow(), ["resize", "layout", "resize"]);
+///
+/// calls.borrow_mut().clear();
+/// request_layout();
+/// harness.key(Key::Character('x'));
+/// assert_eq!(*calls.borrow(), ["event", "update", "layout"]);
+///
+/// drop(harness);
+/// assert_eq!(calls.borrow().last(), Some(&"unmount"));
+/// ```
 pub trait View {
-    fn layout(&mut self, ctx: &mut LayoutContext) -> taffy::prelude::NodeId;
+    /// Builds this view's layout nodes and returns the root one. Errors from taffy should
+    /// be propagated with `?` so `App` can report them instead of panicking.
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<taffy::prelude::NodeId, LayoutError>;
     fn prepare(&mut self, _ctx: &mut RenderContext, _layout_ctx: &LayoutContext, _geometry: Geometry) {}
     fn collect_text_areas<'a>(&'a self, _layout_ctx: &LayoutContext, _geometry: Geometry, _areas: &mut Vec<glyphon::TextArea<'a>>) {}
     fn render<'rp>(&'rp self, ctx: &'rp RenderContext, render_pass: &mut wgpu::RenderPass<'rp>, geometry: Geometry);
     fn handle_event(&mut self, event: &Event, layout_ctx: &LayoutContext, geometry: Geometry);
+    /// The z-index this view draws at, if it sets one, so containers can send the pointer to
+    /// whichever child is drawn on top. Wrappers should forward their child's.
+    fn drawn_z_index(&self) -> Option<i32> {
+        None
+    }
 
-    // Lifecycle hooks
+    // Lifecycle hooks, in the order described on the trait
     fn on_init(&mut self) {}
     fn on_mount(&mut self) {}
     fn on_update(&mut self) {}
     fn on_unmount(&mut self) {}
+    /// The window was resized to `width` x `height` logical pixels and the layout has
+    /// already been recomputed, so size-dependent caches can be reset here.
+    fn on_resize(&mut self, _width: f32, _height: f32) {}
+}
+
+/// Anything that can stand in a container's child list: a single view, or a `Fragment`
+/// whose children are spliced in as siblings. `view!` converts every child through this.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be used as a view",
+    label = "expected a widget, a `Fragment` or another `View`"
+)]
+pub trait IntoViews {
+    fn into_views(self) -> Vec<Box<dyn View>>;
+}
+
+impl<V: View + 'static> IntoViews for V {
+    fn into_views(self) -> Vec<Box<dyn View>> {
+        vec![Box::new(self)]
+    }
+}
+
+impl IntoViews for Vec<Box<dyn View>> {
+    fn into_views(self) -> Vec<Box<dyn View>> {
+        self
+    }
+}
+
+/// Lets already-boxed views (e.g. returned from a helper as `Box<dyn View>`) be used
+/// anywhere a view is expected, including as `view!` children.
+impl<V: View + ?Sized> View for Box<V> {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<taffy::prelude::NodeId, LayoutError> {
+        (**self).layout(ctx)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        (**self).prepare(ctx, layout_ctx, geometry);
+    }
+
+    fn collect_text_areas<'a>(&'a self, layout_ctx: &LayoutContext, geometry: Geometry, areas: &mut Vec<glyphon::TextArea<'a>>) {
+        (**self).collect_text_areas(layout_ctx, geometry, areas);
+    }
+
+    fn render<'rp>(&'rp self, ctx: &'rp RenderContext, render_pass: &mut wgpu::RenderPass<'rp>, geometry: Geometry) {
+        (**self).render(ctx, render_pass, geometry);
+    }
+
+    fn handle_event(&mut self, event: &Event, layout_ctx: &LayoutContext, geometry: Geometry) {
+        (**self).handle_event(event, layout_ctx, geometry);
+    }
+
+    fn drawn_z_index(&self) -> Option<i32> {
+        (**self).drawn_z_index()
+    }
+
+    fn on_init(&mut self) { (**self).on_init(); }
+    fn on_mount(&mut self) { (**self).on_mount(); }
+    fn on_update(&mut self) { (**self).on_update(); }
+    fn on_unmount(&mut self) { (**self).on_unmount(); }
+    fn on_resize(&mut self, width: f32, height: f32) { (**self).on_resize(width, height); }
 }
@@ -1,17 +1,380 @@
-use crate::view::{View, Geometry, Event};
-use crate::layout::LayoutContext;
-use crate::render::RenderContext;
+use crate::view::{View, IntoViews, Geometry, Event, Interaction, Key, Modifiers, GamepadButton};
+use crate::layout::{LayoutContext, LayoutError, EdgeInsets, TextMeasure, TextDirection, request_layout};
+use crate::render::{RenderContext, FrameStats, Clip, Color, Texture, TintMode, Transform};
+use crate::state::{Signal, Computed, Field, create_signal};
+use crate::app::Commands;
+use crate::animation::Easing;
+use crate::path::{self, FillRule, PathCommand, PathError};
 use taffy::prelude::*;
+use std::time::{Duration, Instant};
+use std::path::PathBuf;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use unicode_segmentation::UnicodeSegmentation;
 
+/// Lays children out top to bottom, centered horizontally.
+///
+/// Children are drawn in order, so where they overlap, later ones are on top unless an
+/// earlier one has a higher `z_index`. Pointer input goes only to the topmost child under
+/// the pointer, so overlapping buttons don't both light up or fire:
+///
+/// ```
+/// use noxkit::prelude::*;
+/// use noxkit::layout::LayoutContext;
+/// use noxkit::view::Geometry;
+/// use noxkit::testing::TestHarness;
+/// use taffy::prelude::*;
+///
+/// // Pulls its button up over the one before it
+/// struct Overlap(Button);
+///
+/// impl View for Overlap {
+///     fn layout(&mut self, ctx: &mut LayoutContext) -> Result<NodeId, LayoutError> {
+///         let node = self.0.layout(ctx)?;
+///         let margin = taffy::prelude::Rect { top: length(-50.0), ..ctx.taffy.style(node)?.margin };
+///         let style = Style { margin, ..ctx.taffy.style(node)?.clone() };
+///         ctx.taffy.set_style(node, style)?;
+///         Ok(node)
+///     }
+///     fn render<'rp>(&'rp self, ctx: &'rp noxkit::render::RenderContext, pass: &mut wgpu::RenderPass<'rp>, geometry: Geometry) {
+///         self.0.render(ctx, pass, geometry);
+///     }
+///     fn handle_event(&mut self, event: &Event, layout_ctx: &LayoutContext, geometry: Geometry) {
+///         self.0.handle_event(event, layout_ctx, geometry);
+///     }
+/// }
+///
+/// let clicked = create_signal(Vec::new());
+/// let button = |label: &'static str| {
+///     let clicked = clicked.clone();
+///     Button::new(label, move || clicked.update(|c| c.push(label))).debug_name(label)
+/// };
+/// let column = Column::default().child(button("below")).child(Overlap(button("above")));
+/// let mut harness = TestHarness::new(Box::new(column), 400.0, 300.0).unwrap();
+///
+/// let (below, above) = (harness.find_named("below").unwrap(), harness.find_named("above").unwrap());
+/// let (x, y) = (above.x + above.width / 2.0, above.y + 2.0);
+/// assert!(below.contains(x, y));
+/// harness.click(x, y);
+/// assert_eq!(clicked.get(), vec!["above"]);
+///
+/// // Raised, the first button is on top instead
+/// let column = Column::default().child(button("below").z_index(1)).child(Overlap(button("above")));
+/// let mut harness = TestHarness::new(Box::new(column), 400.0, 300.0).unwrap();
+/// clicked.set(Vec::new());
+/// harness.click(x, y);
+/// assert_eq!(clicked.get(), vec!["below"]);
+/// ```
 pub struct Column {
     pub children: Vec<Box<dyn View>>,
+    pub padding: EdgeInsets,
+    pub z_index: Option<i32>,
+    direction: FlexDirection,
+    align_items: AlignItems,
+    /// Lays children out from the far end: bottom-up, or against the text direction in a row.
+    reverse: bool,
+    text_direction: Option<TextDirection>,
+    debug_name: Option<String>,
+    /// The child the mouse button went down on, which gets the pointer until it comes up.
+    captured: Option<usize>,
     node_id: Option<NodeId>,
 }
 
 impl Column {
     pub fn new(children: Vec<Box<dyn View>>) -> Self {
-        Self { children, node_id: None }
+        Self {
+            children,
+            padding: EdgeInsets::symmetric(16.0, 24.0),
+            z_index: None,
+            direction: FlexDirection::Column,
+            align_items: AlignItems::Center, // Material-like centering
+            reverse: false,
+            text_direction: None,
+            debug_name: None,
+            captured: None,
+            node_id: None,
+        }
+    }
+
+    /// Adds `child` after the existing children, boxing it. With `children` and `default`,
+    /// this builds trees in plain Rust where `view!` gets in the way, e.g. for children that
+    /// depend on conditions or come from loops.
+    ///
+    /// ```
+    /// use noxkit::prelude::*;
+    /// use noxkit::testing::TestHarness;
+    ///
+    /// let signed_in = true;
+    /// let mut column = Column::default().child(Text::new("Inbox").debug_name("title"));
+    /// if signed_in {
+    ///     column = column.child(Row::default().children(["Reply", "Archive"].map(|label| Button::new(label, || {}))));
+    /// }
+    ///
+    /// // The same tree as
+    /// let from_macro = view! {
+    ///     Column {
+    ///         Text("Inbox").debug_name("title"),
+    ///         Row {
+    ///             Button("Reply", || {}),
+    ///             Button("Archive", || {})
+    ///         }
+    ///     }
+    /// };
+    /// let built = TestHarness::new(Box::new(column), 400.0, 300.0).unwrap();
+    /// let expanded = TestHarness::new(Box::new(from_macro), 400.0, 300.0).unwrap();
+    /// assert_eq!(built.layout_context().dump_tree(), expanded.layout_context().dump_tree());
+    /// ```
+    pub fn child(mut self, child: impl IntoViews) -> Self {
+        self.children.extend(child.into_views());
+        self
+    }
+
+    /// Adds every view in `children` after the existing ones, e.g. one per item of a list.
+    pub fn children<V: IntoViews>(mut self, children: impl IntoIterator<Item = V>) -> Self {
+        self.children.extend(children.into_iter().flat_map(IntoViews::into_views));
+        self
+    }
+
+    pub fn padding(mut self, padding: EdgeInsets) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Draws this column and its children at `z_index` unless a child sets its own.
+    pub fn z_index(mut self, z_index: i32) -> Self {
+        self.z_index = Some(z_index);
+        self
+    }
+
+    /// Lays this container and everything in it out in `direction`, whatever the app uses.
+    pub fn text_direction(mut self, direction: TextDirection) -> Self {
+        self.text_direction = Some(direction);
+        self
+    }
+
+    /// Sets how children line up across the column, e.g. `AlignItems::Start` to put them
+    /// against the left edge. Defaults to centered.
+    pub fn align_items(mut self, align_items: AlignItems) -> Self {
+        self.align_items = align_items;
+        self
+    }
+
+    /// Stacks the children bottom-up, packed against the bottom edge, e.g. for a chat log
+    /// that grows upwards from its newest message.
+    ///
+    /// ```
+    /// use noxkit::prelude::*;
+    /// use noxkit::testing::TestHarness;
+    ///
+    /// let column = Column::new(vec![
+    ///     Box::new(Text::new("first").debug_name("first")),
+    ///     Box::new(Text::new("second").debug_name("second")),
+    /// ])
+    /// .reverse(true);
+    /// let harness = TestHarness::new(Box::new(column), 400.0, 300.0).unwrap();
+    ///
+    /// let (first, second) = (harness.find_named("first").unwrap(), harness.find_named("second").unwrap());
+    /// assert!(second.y < first.y);
+    /// assert!(first.y + first.height > 250.0);
+    /// ```
+    pub fn reverse(mut self, reverse: bool) -> Self {
+        self.reverse = reverse;
+        self
+    }
+
+    /// Names this widget for layout inspection (see `LayoutContext::named_geometries`).
+    pub fn debug_name(mut self, name: impl Into<String>) -> Self {
+        self.debug_name = Some(name.into());
+        self
+    }
+}
+
+impl Column {
+    /// Index of the child drawn on top at `(x, y)`: of those whose box contains it, the one
+    /// with the highest z-index, or the last one among equals.
+    fn child_at(&self, layout_ctx: &LayoutContext, my_geo: Geometry, x: f32, y: f32) -> Option<usize> {
+        let nodes = layout_ctx.taffy.children(self.node_id?).ok()?;
+        let z_index = |index: usize| self.children[index].drawn_z_index().or(self.z_index).unwrap_or(0);
+        nodes.iter().enumerate()
+            .filter(|&(_, &node)| layout_ctx.geometry(Some(node), my_geo).is_some_and(|geo| geo.contains(x, y)))
+            .max_by_key(|&(index, _)| z_index(index))
+            .map(|(index, _)| index)
+    }
+}
+
+impl Default for Column {
+    /// A column with no children yet, to add them with `child` and `children`.
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+/// Lays children out left to right, centered vertically. Unlike `Column` it has no
+/// padding by default and is only as tall as its content.
+pub struct Row(Column);
+
+impl Row {
+    pub fn new(children: Vec<Box<dyn View>>) -> Self {
+        Self(Column { padding: EdgeInsets::all(0.0), direction: FlexDirection::Row, ..Column::new(children) })
+    }
+
+    /// Adds `child` after the existing children (see `Column::child`).
+    pub fn child(self, child: impl IntoViews) -> Self {
+        Self(self.0.child(child))
+    }
+
+    pub fn children<V: IntoViews>(self, children: impl IntoIterator<Item = V>) -> Self {
+        Self(self.0.children(children))
+    }
+
+    pub fn padding(self, padding: EdgeInsets) -> Self {
+        Self(self.0.padding(padding))
+    }
+
+    pub fn z_index(self, z_index: i32) -> Self {
+        Self(self.0.z_index(z_index))
+    }
+
+    /// Runs this row (and lays out its children) in `direction`, whatever the app uses.
+    pub fn text_direction(self, direction: TextDirection) -> Self {
+        Self(self.0.text_direction(direction))
+    }
+
+    /// Runs the row against the text direction: right to left, packed against the right
+    /// edge, in a left-to-right UI.
+    pub fn reverse(self, reverse: bool) -> Self {
+        Self(self.0.reverse(reverse))
+    }
+
+    /// Sets how children line up vertically. `AlignItems::Baseline` puts the first line of
+    /// text in each child on the same baseline, e.g. for a large number next to a small
+    /// unit; children without text sit on it with their bottom edge.
+    ///
+    /// ```
+    /// use noxkit::prelude::*;
+    /// use noxkit::testing::TestHarness;
+    /// use taffy::prelude::AlignItems;
+    ///
+    /// let row = Row::new(vec![
+    ///     Box::new(Text::new("42").size(48.0).debug_name("value")),
+    ///     Box::new(Text::new("km/h").size(14.0).debug_name("unit")),
+    /// ])
+    /// .align_items(AlignItems::Baseline);
+    /// let harness = TestHarness::new(Box::new(row), 400.0, 300.0).unwrap();
+    ///
+    /// // Lower than centering would put it, level with the bottom of the digits
+    /// let (value, unit) = (harness.find_named("value").unwrap(), harness.find_named("unit").unwrap());
+    /// assert!(unit.y - value.y > (value.height - unit.height) / 2.0);
+    /// ```
+    pub fn align_items(self, align_items: AlignItems) -> Self {
+        Self(self.0.align_items(align_items))
+    }
+
+    pub fn debug_name(self, name: impl Into<String>) -> Self {
+        Self(self.0.debug_name(name))
+    }
+}
+
+impl Default for Row {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl View for Row {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<NodeId, LayoutError> { self.0.layout(ctx) }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        self.0.prepare(ctx, layout_ctx, geometry);
+    }
+
+    fn collect_text_areas<'a>(&'a self, layout_ctx: &LayoutContext, geometry: Geometry, areas: &mut Vec<glyphon::TextArea<'a>>) {
+        self.0.collect_text_areas(layout_ctx, geometry, areas);
+    }
+
+    fn render<'rp>(&'rp self, ctx: &'rp RenderContext, render_pass: &mut wgpu::RenderPass<'rp>, geometry: Geometry) {
+        self.0.render(ctx, render_pass, geometry);
+    }
+
+    fn handle_event(&mut self, event: &Event, layout_ctx: &LayoutContext, geometry: Geometry) {
+        self.0.handle_event(event, layout_ctx, geometry);
     }
+
+    fn on_init(&mut self) { self.0.on_init(); }
+    fn on_mount(&mut self) { self.0.on_mount(); }
+    fn on_update(&mut self) { self.0.on_update(); }
+    fn on_unmount(&mut self) { self.0.on_unmount(); }
+    fn on_resize(&mut self, width: f32, height: f32) { self.0.on_resize(width, height); }
+    fn drawn_z_index(&self) -> Option<i32> { self.0.drawn_z_index() }
+}
+
+/// A group of sibling views with no layout of its own. Used as a container's child, its
+/// children are spliced into the container in its place, so a helper can return several
+/// widgets with `view! { Fragment { a, b } }`.
+pub struct Fragment {
+    pub children: Vec<Box<dyn View>>,
+}
+
+impl Fragment {
+    pub fn new(children: Vec<Box<dyn View>>) -> Self {
+        Self { children }
+    }
+
+    /// Adds `child` after the existing children.
+    pub fn child(mut self, child: impl IntoViews) -> Self {
+        self.children.extend(child.into_views());
+        self
+    }
+
+    /// Adds every view in `children` after the existing ones.
+    pub fn children<V: IntoViews>(mut self, children: impl IntoIterator<Item = V>) -> Self {
+        self.children.extend(children.into_iter().flat_map(IntoViews::into_views));
+        self
+    }
+}
+
+impl Default for Fragment {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl IntoViews for Fragment {
+    fn into_views(self) -> Vec<Box<dyn View>> {
+        self.children
+    }
+}
+
+/// Runs `f` with the render queue's z-index set to `z_index` (if any), restoring it afterwards.
+fn with_z_index(ctx: &mut RenderContext, z_index: Option<i32>, f: impl FnOnce(&mut RenderContext)) {
+    match z_index {
+        Some(z) => {
+            let previous = ctx.render_queue.set_z_index(z);
+            f(ctx);
+            ctx.render_queue.set_z_index(previous);
+        }
+        None => f(ctx),
+    }
+}
+
+/// Runs `f` with shape edges softened by `softness` pixels, restoring the previous softness.
+fn with_edge_softness(ctx: &mut RenderContext, softness: f32, f: impl FnOnce(&mut RenderContext)) {
+    let previous = ctx.render_queue.set_edge_softness(softness);
+    f(ctx);
+    ctx.render_queue.set_edge_softness(previous);
+}
+
+/// Makes the app redraw whenever `source` changes, for a widget property that follows it.
+fn redraw_on_change<T: Clone + 'static>(source: Computed<T>) -> Computed<T> {
+    source.subscribe(|| Commands::new().request_redraw());
+    source
+}
+
+/// Converts an `[r, g, b, a]` color in 0..1 to glyphon's 8-bit color.
+fn to_glyphon_color(color: [f32; 4]) -> glyphon::Color {
+    let [r, g, b, a] = color.map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8);
+    glyphon::Color::rgba(r, g, b, a)
 }
 
 fn render_outline_helper(ctx: &mut RenderContext, geometry: Geometry, color: [f32; 4]) {
@@ -28,65 +391,77 @@ fn render_outline_helper(ctx: &mut RenderContext, geometry: Geometry, color: [f3
 }
 
 impl View for Column {
-    fn layout(&mut self, ctx: &mut LayoutContext) -> NodeId {
-        let child_nodes: Vec<NodeId> = self.children.iter_mut()
-            .map(|child| child.layout(ctx))
-            .collect();
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<NodeId, LayoutError> {
+        let (child_nodes, direction) = ctx.with_direction(self.text_direction, |ctx| {
+            let child_nodes: Vec<NodeId> = self.children.iter_mut()
+                .map(|child| child.layout(ctx))
+                .collect::<Result<_, _>>()?;
+            Ok::<_, LayoutError>((child_nodes, ctx.direction()))
+        })?;
         
+        let is_row = self.direction == FlexDirection::Row;
+        // Right-to-left rows start from the right edge, and `reverse` flips either kind.
+        // `Start` is always the left (or top) edge, so reversed containers pack towards
+        // `FlexStart` instead
+        let reversed = if is_row { (direction == TextDirection::Rtl) != self.reverse } else { self.reverse };
+        let flex_direction = match (is_row, reversed) {
+            (true, false) => FlexDirection::Row,
+            (true, true) => FlexDirection::RowReverse,
+            (false, false) => FlexDirection::Column,
+            (false, true) => FlexDirection::ColumnReverse,
+        };
         let node = ctx.taffy.new_with_children(
             Style {
                 display: Display::Flex,
-                flex_direction: FlexDirection::Column,
-                align_items: Some(AlignItems::Center), // Material-like centering
-                justify_content: Some(JustifyContent::Start),
+                flex_direction,
+                align_items: Some(self.align_items),
+                justify_content: Some(if reversed { JustifyContent::FlexStart } else { JustifyContent::Start }),
                 size: Size {
                     width: Dimension::Percent(1.0),
-                    height: Dimension::Percent(1.0),
+                    height: if is_row || self.reverse { Dimension::Auto } else { Dimension::Percent(1.0) },
                 },
-                padding: taffy::prelude::Rect {
-                    left: length(16.0),
-                    right: length(16.0),
-                    top: length(24.0),
-                    bottom: length(24.0),
+                // A bottom-up column still fills its parent, but grows past it rather than
+                // overflowing upwards, where a scroll view couldn't reach
+                min_size: Size {
+                    width: Dimension::Auto,
+                    height: if !is_row && self.reverse { Dimension::Percent(1.0) } else { Dimension::Auto },
                 },
+                padding: self.padding.to_taffy(),
                 gap: Size {
-                    width: length(0.0),
-                    height: length(16.0), // More breathing room
+                    width: length(if is_row { 16.0 } else { 0.0 }),
+                    height: length(if is_row { 0.0 } else { 16.0 }), // More breathing room
                 },
                 ..Default::default()
             },
             &child_nodes,
-        ).unwrap();
+        )?;
         self.node_id = Some(node);
-        node
+        // Taffy only lines rows up on baselines
+        if is_row && self.align_items == AlignItems::Baseline {
+            ctx.align_baselines(node);
+        }
+        if let Some(name) = &self.debug_name {
+            ctx.set_debug_name(node, name.clone());
+        }
+        Ok(node)
     }
 
     fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
-        let node_layout = layout_ctx.taffy.layout(self.node_id.unwrap()).unwrap();
-        let my_geo = Geometry {
-            x: geometry.x + node_layout.location.x,
-            y: geometry.y + node_layout.location.y,
-            width: node_layout.size.width,
-            height: node_layout.size.height,
-        };
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
 
-        for child in self.children.iter_mut() {
-            child.prepare(ctx, layout_ctx, my_geo);
-        }
-        
-        if ctx.debug {
-            render_outline_helper(ctx, my_geo, [1.0, 0.0, 0.0, 1.0]);
-        }
+        with_z_index(ctx, self.z_index, |ctx| {
+            for child in self.children.iter_mut() {
+                child.prepare(ctx, layout_ctx, my_geo);
+            }
+
+            if ctx.debug {
+                render_outline_helper(ctx, my_geo, [1.0, 0.0, 0.0, 1.0]);
+            }
+        });
     }
 
     fn collect_text_areas<'a>(&'a self, layout_ctx: &LayoutContext, geometry: Geometry, areas: &mut Vec<glyphon::TextArea<'a>>) {
-        let node_layout = layout_ctx.taffy.layout(self.node_id.unwrap()).unwrap();
-        let my_geo = Geometry {
-            x: geometry.x + node_layout.location.x,
-            y: geometry.y + node_layout.location.y,
-            width: node_layout.size.width,
-            height: node_layout.size.height,
-        };
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
 
         for child in self.children.iter() {
             child.collect_text_areas(layout_ctx, my_geo, areas);
@@ -100,19 +475,44 @@ impl View for Column {
     }
 
     fn handle_event(&mut self, event: &Event, layout_ctx: &LayoutContext, geometry: Geometry) {
-        let node_layout = layout_ctx.taffy.layout(self.node_id.unwrap()).unwrap();
-        let my_geo = Geometry {
-            x: geometry.x + node_layout.location.x,
-            y: geometry.y + node_layout.location.y,
-            width: node_layout.size.width,
-            height: node_layout.size.height,
-        };
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
 
+        // Clicks stop at the topmost child under the pointer. If none is, e.g. because the
+        // pointer is over content drawn outside its child's box, everyone hears it as usual
+        if let Event::MouseClick { x, y } | Event::DoubleClick { x, y } | Event::LongPress { x, y } = *event
+            && let Some(index) = self.child_at(layout_ctx, my_geo, x, y)
+        {
+            self.children[index].handle_event(event, layout_ctx, my_geo);
+            return;
+        }
+        // Presses, moves and releases reach the topmost child, or the one the button went down
+        // on while it is held so drags carry on over its siblings. The others hear the
+        // pointer as somewhere off the window, so they still drop hover, press and focus
+        let target = match *event {
+            Event::MouseDown { x, y } => {
+                self.captured = self.child_at(layout_ctx, my_geo, x, y);
+                self.captured
+            }
+            Event::MouseMove { x, y } => self.captured.or_else(|| self.child_at(layout_ctx, my_geo, x, y)),
+            Event::MouseUp { x, y } => self.captured.take().or_else(|| self.child_at(layout_ctx, my_geo, x, y)),
+            _ => None,
+        };
+        if let Some(target) = target.filter(|&target| target < self.children.len()) {
+            let elsewhere = event.map_position(|_, _| (f32::INFINITY, f32::INFINITY));
+            for (index, child) in self.children.iter_mut().enumerate() {
+                child.handle_event(if index == target { event } else { &elsewhere }, layout_ctx, my_geo);
+            }
+            return;
+        }
         for child in self.children.iter_mut() {
             child.handle_event(event, layout_ctx, my_geo);
         }
     }
 
+    fn drawn_z_index(&self) -> Option<i32> {
+        self.z_index
+    }
+
     fn on_init(&mut self) {
         for child in &mut self.children {
             child.on_init();
@@ -136,14 +536,84 @@ impl View for Column {
             child.on_unmount();
         }
     }
+
+    fn on_resize(&mut self, width: f32, height: f32) {
+        for child in &mut self.children {
+            child.on_resize(width, height);
+        }
+    }
+}
+
+/// A drop shadow drawn under text by rendering the same glyphs again, offset and tinted.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TextShadow {
+    pub offset: [f32; 2],
+    /// Spread in pixels. Approximated by a few extra copies around the offset, not a true blur.
+    pub blur: f32,
+    pub color: [f32; 4],
+}
+
+impl TextShadow {
+    /// Offsets (relative to the text) and color of each copy to draw, bottom-most first.
+    fn copies(&self) -> Vec<([f32; 2], [f32; 4])> {
+        let [dx, dy] = self.offset;
+        if self.blur <= 0.0 {
+            return vec![([dx, dy], self.color)];
+        }
+        let b = self.blur;
+        let spread = [[-b, 0.0], [b, 0.0], [0.0, -b], [0.0, b], [0.0, 0.0]];
+        // Copies overlap near the glyph centers, so split the alpha between them
+        let mut color = self.color;
+        color[3] = (color[3] * 2.0 / spread.len() as f32).min(color[3]);
+        spread.iter().map(|[sx, sy]| ([dx + sx, dy + sy], color)).collect()
+    }
 }
 
 pub struct Text {
     pub text: String,
+    /// Size in effect at the last layout; follows the app's `base_font_size` unless set
+    /// with `size`.
     pub font_size: f32,
+    /// Multiple of the app's `base_font_size` this text is laid out at, unless sized in pixels.
+    relative_size: Option<f32>,
+    pub color: [f32; 4],
+    color_binding: Option<Computed<[f32; 4]>>,
+    pub ellipsis: bool,
+    pub shadow: Option<TextShadow>,
+    /// Extra space between letters, in em.
+    pub letter_spacing: f32,
+    line_height: LineHeight,
+    /// Direction in effect at the last layout; right-to-left text lines up on the right.
+    direction: TextDirection,
+    /// Overrides the app's `text_shaping` for this text.
+    shaping: Option<glyphon::Shaping>,
+    /// Shaping in effect at the last layout.
+    resolved_shaping: glyphon::Shaping,
+    debug_name: Option<String>,
     buffer: Option<glyphon::Buffer>,
     node_id: Option<NodeId>,
-    last_text: Option<String>,
+    /// Text, size and spacing the buffer was last shaped for.
+    last_shaped: Option<(String, ShapeKey)>,
+    /// Font attributes to shape with, and the letter spacing they were built for.
+    attrs: Option<(f32, glyphon::Attrs<'static>)>,
+}
+
+/// Line height of a `Text`, either in pixels or relative to its font size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum LineHeight {
+    Absolute(f32),
+    Relative(f32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ShapeKey {
+    width: f32,
+    height: f32,
+    font_size: f32,
+    line_height: f32,
+    letter_spacing: f32,
+    direction: TextDirection,
+    shaping: glyphon::Shaping,
 }
 
 impl Text {
@@ -151,42 +621,252 @@ impl Text {
         Self { 
             text: text.into(),
             font_size: 16.0,
+            relative_size: Some(1.0),
+            color: [1.0, 1.0, 1.0, 1.0],
+            color_binding: None,
+            ellipsis: false,
+            shadow: None,
+            letter_spacing: 0.0,
+            // Material/Android standard: 16dp text, 24dp line height
+            line_height: LineHeight::Relative(1.5),
+            direction: TextDirection::Ltr,
+            shaping: None,
+            resolved_shaping: glyphon::Shaping::Advanced,
+            debug_name: None,
             buffer: None,
             node_id: None,
-            last_text: None,
+            last_shaped: None,
+            attrs: None,
         }
     }
-}
 
-impl View for Text {
-    fn layout(&mut self, ctx: &mut LayoutContext) -> NodeId {
-        let node = ctx.taffy.new_leaf(Style::default()).unwrap();
-        self.node_id = Some(node);
-        node
+    /// Sets the font size in pixels, ignoring the app's `base_font_size`.
+    pub fn size(mut self, font_size: f32) -> Self {
+        self.font_size = font_size;
+        self.relative_size = None;
+        self
     }
 
-    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
-        let node_layout = layout_ctx.taffy.layout(self.node_id.unwrap()).unwrap();
-        let my_geo = Geometry {
-            x: geometry.x + node_layout.location.x,
-            y: geometry.y + node_layout.location.y,
-            width: node_layout.size.width,
-            height: node_layout.size.height,
-        };
+    /// Sets the font size as a multiple of the app's `base_font_size`, e.g. 1.25 for a title.
+    /// Defaults to 1.0.
+    pub fn relative_size(mut self, factor: f32) -> Self {
+        self.relative_size = Some(factor);
+        self
+    }
+
+    pub fn color(mut self, color: [f32; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Takes the color from `color` on every frame, so it follows signals without the tree
+    /// being rebuilt, e.g. when switching themes.
+    pub fn bind_color(mut self, color: Computed<[f32; 4]>) -> Self {
+        self.color_binding = Some(redraw_on_change(color));
+        self
+    }
+
+    /// Keeps the text on one line, cutting it short with "…" when it doesn't fit. The text
+    /// may then shrink below its natural width in a row.
+    pub fn ellipsis(mut self, ellipsis: bool) -> Self {
+        self.ellipsis = ellipsis;
+        self
+    }
+
+    /// Draws a shadow under the text to keep it legible over busy backgrounds.
+    pub fn shadow(mut self, offset: [f32; 2], blur: f32, color: [f32; 4]) -> Self {
+        self.shadow = Some(TextShadow { offset, blur, color });
+        self
+    }
+
+    pub fn debug_name(mut self, name: impl Into<String>) -> Self {
+        self.debug_name = Some(name.into());
+        self
+    }
+
+    /// Sets the distance between baselines in pixels, e.g. for dense tables.
+    pub fn line_height(mut self, line_height: f32) -> Self {
+        self.line_height = LineHeight::Absolute(line_height);
+        self
+    }
+
+    /// Sets the line height as a multiple of the font size. Defaults to 1.5.
+    pub fn line_height_factor(mut self, factor: f32) -> Self {
+        self.line_height = LineHeight::Relative(factor);
+        self
+    }
+
+    /// Adds space between letters, in em (so 0.05 at 20px is 1px).
+    pub fn letter_spacing(mut self, letter_spacing: f32) -> Self {
+        self.letter_spacing = letter_spacing;
+        self
+    }
+
+    /// Shapes this text with `shaping` instead of the app's `text_shaping`. `Basic` is
+    /// cheaper for long plain labels but skips ligatures, kerning and complex scripts.
+    pub fn shaping(mut self, shaping: glyphon::Shaping) -> Self {
+        self.shaping = Some(shaping);
+        self
+    }
+
+    /// Byte index into `text` of the caret position nearest a point relative to the text's
+    /// top-left corner (see `Geometry::to_local`), e.g. to place a caret where the user
+    /// clicked. `None` until the text has been drawn once. Points past an ellipsis land
+    /// where the text was cut.
+    pub fn index_at(&self, local_x: f32, local_y: f32) -> Option<usize> {
+        let buffer = self.buffer.as_ref()?;
+        let index = buffer_index_at(buffer, local_x, local_y)?;
+        match buffer.lines.first() {
+            Some(line) if self.ellipsis && line.text() != self.text => Some(index.min(line.text().len() - '…'.len_utf8())),
+            _ => Some(index),
+        }
+    }
+
+    /// Distance from the top of the text to the baseline of its first line, e.g. to line an
+    /// icon up with it. `None` until the text has been drawn once.
+    pub fn baseline(&self) -> Option<f32> {
+        self.buffer.as_ref()?.layout_runs().next().map(|run| run.line_y)
+    }
+
+    fn resolved_line_height(&self) -> f32 {
+        match self.line_height {
+            LineHeight::Absolute(line_height) => line_height,
+            LineHeight::Relative(factor) => self.font_size * factor,
+        }
+    }
+
+    /// Builds the font attributes again if the font changed since they were last built, and
+    /// returns whether it did. New text alone reuses them, so a text updated every frame
+    /// doesn't rebuild them.
+    fn update_attrs(&mut self) -> bool {
+        if self.attrs.as_ref().is_some_and(|(letter_spacing, _)| *letter_spacing == self.letter_spacing) {
+            return false;
+        }
+        let attrs = glyphon::Attrs::new().family(glyphon::Family::SansSerif).letter_spacing(self.letter_spacing);
+        self.attrs = Some((self.letter_spacing, attrs));
+        true
+    }
 
+    /// Shapes the text into its buffer for a box of `geometry`'s size, unless it already was
+    /// for the same text and settings, counting the work in `stats`.
+    fn shape(&mut self, font_system: &mut glyphon::FontSystem, geometry: Geometry, stats: &mut FrameStats) {
+        let line_height = self.resolved_line_height();
+        let metrics = glyphon::Metrics::new(self.font_size, line_height);
         if self.buffer.is_none() {
-            // Material/Android standard: 16dp text, 24dp line height
-            self.buffer = Some(glyphon::Buffer::new(&mut ctx.font_system, glyphon::Metrics::new(self.font_size, self.font_size * 1.5)));
+            let mut buffer = glyphon::Buffer::new(font_system, metrics);
+            if self.ellipsis {
+                buffer.set_wrap(font_system, glyphon::Wrap::None);
+            }
+            self.buffer = Some(buffer);
         }
-        
+
+        if self.update_attrs() {
+            stats.built_attrs += 1;
+        }
+        let attrs = &self.attrs.as_ref().unwrap().1;
         let buffer = self.buffer.as_mut().unwrap();
-        
-        if self.last_text.as_ref() != Some(&self.text) {
-            buffer.set_text(&mut ctx.font_system, &self.text, &glyphon::Attrs::new().family(glyphon::Family::SansSerif), glyphon::Shaping::Advanced);
-            buffer.set_size(&mut ctx.font_system, Some(my_geo.width), Some(my_geo.height));
-            buffer.shape_until_scroll(&mut ctx.font_system, false);
-            self.last_text = Some(self.text.clone());
+
+        let key = ShapeKey {
+            width: geometry.width,
+            height: geometry.height,
+            font_size: self.font_size,
+            line_height,
+            letter_spacing: self.letter_spacing,
+            direction: self.direction,
+            shaping: self.resolved_shaping,
+        };
+        // Compared in place, so unchanged text costs no allocation per frame
+        if self.last_shaped.as_ref().is_some_and(|(text, last)| *text == self.text && *last == key) {
+            return;
+        }
+        stats.shaped_texts += 1;
+        buffer.set_metrics(font_system, metrics);
+        let display = if self.ellipsis {
+            ellipsize(buffer, font_system, &self.text, attrs, self.resolved_shaping, geometry.width)
+        } else {
+            self.text.clone()
+        };
+        buffer.set_text(font_system, &display, attrs, self.resolved_shaping);
+        // Bidi reordering happens while shaping; only the paragraph alignment follows the UI
+        if self.direction == TextDirection::Rtl {
+            for line in buffer.lines.iter_mut() {
+                line.set_align(Some(glyphon::cosmic_text::Align::Right));
+            }
+        }
+        buffer.set_size(font_system, Some(geometry.width), Some(geometry.height));
+        buffer.shape_until_scroll(font_system, false);
+        self.last_shaped = Some((self.text.clone(), key));
+    }
+}
+
+/// Byte index into the buffer's whole text of the caret position nearest (x, y), relative to
+/// the buffer's top-left corner.
+fn buffer_index_at(buffer: &glyphon::Buffer, x: f32, y: f32) -> Option<usize> {
+    let cursor = buffer.hit(x, y)?;
+    let before: usize = buffer.lines[..cursor.line].iter()
+        .map(|line| line.text().len() + line.ending().as_str().len())
+        .sum();
+    Some(before + cursor.index)
+}
+
+/// Returns `text`, shortened with a trailing "…" if it is wider than `max_width` on one line.
+fn ellipsize(buffer: &mut glyphon::Buffer, font_system: &mut glyphon::FontSystem, text: &str, attrs: &glyphon::Attrs, shaping: glyphon::Shaping, max_width: f32) -> String {
+    buffer.set_size(font_system, None, None);
+    let mut shape = |buffer: &mut glyphon::Buffer, text: &str| {
+        buffer.set_text(font_system, text, attrs, shaping);
+        buffer.shape_until_scroll(font_system, false);
+        buffer.layout_runs().map(|run| run.line_w).fold(0.0f32, f32::max)
+    };
+
+    if shape(buffer, text) <= max_width {
+        return text.to_string();
+    }
+    let ellipsis_width = shape(buffer, "…");
+    shape(buffer, text);
+    let cut = buffer.layout_runs().next()
+        .and_then(|run| run.glyphs.iter()
+            .take_while(|glyph| glyph.x + glyph.w <= max_width - ellipsis_width)
+            .map(|glyph| glyph.end)
+            .max())
+        .unwrap_or(0);
+    format!("{}…", text[..cut].trim_end())
+}
+
+impl View for Text {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<NodeId, LayoutError> {
+        if let Some(factor) = self.relative_size {
+            self.font_size = ctx.base_font_size * factor;
+        }
+        let style = if self.ellipsis {
+            Style { min_size: Size { width: length(0.0), height: Dimension::Auto }, ..Default::default() }
+        } else {
+            Style::default()
+        };
+        let measure = TextMeasure {
+            text: self.text.clone(),
+            font_size: self.font_size,
+            line_height: self.resolved_line_height(),
+            letter_spacing: self.letter_spacing,
+            single_line: self.ellipsis,
+            shaping: self.shaping.unwrap_or(ctx.text_shaping),
+            baseline: None,
+        };
+        self.resolved_shaping = measure.shaping;
+        let node = ctx.taffy.new_leaf_with_context(style, measure)?;
+        self.node_id = Some(node);
+        self.direction = ctx.direction();
+        if let Some(name) = &self.debug_name {
+            ctx.set_debug_name(node, name.clone());
+        }
+        Ok(node)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        if let Some(binding) = &self.color_binding {
+            self.color = binding.get();
         }
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        self.shape(&mut ctx.font_system, my_geo, &mut ctx.stats);
 
         if ctx.debug {
             render_outline_helper(ctx, my_geo, [0.0, 1.0, 0.0, 1.0]);
@@ -194,29 +874,28 @@ impl View for Text {
     }
 
     fn collect_text_areas<'a>(&'a self, layout_ctx: &LayoutContext, geometry: Geometry, areas: &mut Vec<glyphon::TextArea<'a>>) {
-        let node_layout = layout_ctx.taffy.layout(self.node_id.unwrap()).unwrap();
-        let my_geo = Geometry {
-            x: geometry.x + node_layout.location.x,
-            y: geometry.y + node_layout.location.y,
-            width: node_layout.size.width,
-            height: node_layout.size.height,
-        };
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
 
         if let Some(buffer) = &self.buffer {
-            areas.push(glyphon::TextArea {
+            let area = |[dx, dy]: [f32; 2], color| glyphon::TextArea {
                 buffer,
-                left: my_geo.x,
-                top: my_geo.y,
+                left: my_geo.x + dx,
+                top: my_geo.y + dy,
                 scale: 1.0,
                 bounds: glyphon::TextBounds {
-                    left: my_geo.x as i32,
-                    top: my_geo.y as i32,
-                    right: (my_geo.x + my_geo.width) as i32,
-                    bottom: (my_geo.y + my_geo.height) as i32,
+                    left: (my_geo.x + dx.min(0.0)) as i32,
+                    top: (my_geo.y + dy.min(0.0)) as i32,
+                    right: (my_geo.x + my_geo.width + dx.max(0.0)).ceil() as i32,
+                    bottom: (my_geo.y + my_geo.height + dy.max(0.0)).ceil() as i32,
                 },
-                default_color: glyphon::Color::rgb(255, 255, 255),
+                default_color: to_glyphon_color(color),
                 custom_glyphs: &[],
-            });
+            };
+            // Areas are drawn in order, so the shadow goes first to end up underneath
+            if let Some(shadow) = &self.shadow {
+                areas.extend(shadow.copies().into_iter().map(|(offset, color)| area(offset, color)));
+            }
+            areas.push(area([0.0, 0.0], self.color));
         }
     }
 
@@ -227,232 +906,5017 @@ impl View for Text {
     }
 }
 
-pub struct Button {
-    pub text: String,
-    pub on_click: Box<dyn FnMut()>,
-    text_view: Text,
+/// A Material top app bar: an optional leading view (menu/back), a title that is cut short
+/// with "…" when space runs out, and trailing actions, on a colored bar with a drop shadow.
+///
+/// Meant to be the first child of an unpadded `Column`, e.g.
+/// `Column::new(..).padding(EdgeInsets::all(0.0))`, so it spans the window.
+pub struct AppBar {
+    pub title: Text,
+    pub leading: Option<Box<dyn View>>,
+    pub actions: Vec<Box<dyn View>>,
+    pub color: [f32; 4],
+    pub height: f32,
+    /// Shadow depth in pixels; 0 draws no shadow.
+    pub elevation: f32,
+    pub z_index: Option<i32>,
     node_id: Option<NodeId>,
-    hovered: bool,
-    pressed: bool,
+    title_node: Option<NodeId>,
 }
 
-impl Button {
-    pub fn new(text: impl Into<String>, on_click: impl FnMut() + 'static) -> Self {
-        let t = text.into();
-        let mut text_view = Text::new(t.clone());
-        text_view.font_size = 14.0; // Material buttons often use slightly smaller text
+impl AppBar {
+    pub fn new(title: impl Into<String>) -> Self {
         Self {
-            text: t,
-            on_click: Box::new(on_click),
-            text_view,
+            title: Text::new(title).relative_size(1.25).ellipsis(true),
+            leading: None,
+            actions: Vec::new(),
+            color: [0.247, 0.317, 0.709, 1.0],
+            height: 64.0,
+            elevation: 4.0,
+            // Above the content that follows, so the shadow isn't drawn over
+            z_index: Some(1),
             node_id: None,
-            hovered: false,
-            pressed: false,
+            title_node: None,
         }
     }
-}
 
-impl View for Button {
-    fn layout(&mut self, ctx: &mut LayoutContext) -> NodeId {
-        let text_node = self.text_view.layout(ctx);
-        let node = ctx.taffy.new_with_children(
-            Style {
-                padding: taffy::prelude::Rect {
-                    left: length(24.0),
-                    right: length(24.0),
-                    top: length(10.0),
-                    bottom: length(10.0),
-                },
-                justify_content: Some(JustifyContent::Center),
-                align_items: Some(AlignItems::Center),
-                ..Default::default()
-            },
-            &[text_node],
-        ).unwrap();
-        self.node_id = Some(node);
-        node
+    pub fn leading(mut self, leading: impl View + 'static) -> Self {
+        self.leading = Some(Box::new(leading));
+        self
     }
 
-    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
-        let node_layout = layout_ctx.taffy.layout(self.node_id.unwrap()).unwrap();
-        let my_geo = Geometry {
-            x: geometry.x + node_layout.location.x,
-            y: geometry.y + node_layout.location.y,
-            width: node_layout.size.width,
-            height: node_layout.size.height,
-        };
+    pub fn action(mut self, action: impl View + 'static) -> Self {
+        self.actions.push(Box::new(action));
+        self
+    }
 
-        // Modern Material Design colors (Primary/Indigo)
-        let mut color = [0.247, 0.317, 0.709, 1.0]; 
-        if self.pressed {
-            color = [0.188, 0.247, 0.623, 1.0];
-        } else if self.hovered {
-            color = [0.301, 0.380, 0.780, 1.0];
-        }
+    pub fn color(mut self, color: [f32; 4]) -> Self {
+        self.color = color;
+        self
+    }
 
-        ctx.render_queue.push_rounded_rect(my_geo, color, 8.0); // Rounded corners
-        self.text_view.prepare(ctx, layout_ctx, my_geo); // Note: using my_geo as parent
-        
-        if ctx.debug {
-            render_outline_helper(ctx, my_geo, [1.0, 1.0, 0.0, 1.0]);
-        }
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
     }
 
-    fn collect_text_areas<'a>(&'a self, layout_ctx: &LayoutContext, geometry: Geometry, areas: &mut Vec<glyphon::TextArea<'a>>) {
-        let node_layout = layout_ctx.taffy.layout(self.node_id.unwrap()).unwrap();
-        let my_geo = Geometry {
-            x: geometry.x + node_layout.location.x,
-            y: geometry.y + node_layout.location.y,
-            width: node_layout.size.width,
-            height: node_layout.size.height,
-        };
-        self.text_view.collect_text_areas(layout_ctx, my_geo, areas);
+    pub fn elevation(mut self, elevation: f32) -> Self {
+        self.elevation = elevation;
+        self
     }
 
-    fn render<'rp>(&'rp self, ctx: &'rp RenderContext, render_pass: &mut wgpu::RenderPass<'rp>, geometry: Geometry) {
-        self.text_view.render(ctx, render_pass, geometry);
+    pub fn z_index(mut self, z_index: i32) -> Self {
+        self.z_index = Some(z_index);
+        self
     }
 
-    fn handle_event(&mut self, event: &Event, layout_ctx: &LayoutContext, geometry: Geometry) {
-        let node_layout = layout_ctx.taffy.layout(self.node_id.unwrap()).unwrap();
-        let my_geo = Geometry {
-            x: geometry.x + node_layout.location.x,
-            y: geometry.y + node_layout.location.y,
-            width: node_layout.size.width,
-            height: node_layout.size.height,
-        };
+    fn children(&self) -> impl Iterator<Item = &Box<dyn View>> {
+        self.leading.iter().chain(self.actions.iter())
+    }
 
-        match event {
-            Event::MouseClick { x, y } => {
-                if my_geo.contains(*x, *y) {
-                    (self.on_click)();
-                }
-            }
-            Event::MouseMove { x, y } => {
-                self.hovered = my_geo.contains(*x, *y);
+    fn children_mut(&mut self) -> impl Iterator<Item = &mut Box<dyn View>> {
+        self.leading.iter_mut().chain(self.actions.iter_mut())
+    }
+}
+
+impl View for AppBar {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<NodeId, LayoutError> {
+        let text_node = self.title.layout(ctx)?;
+        let rtl = ctx.direction() == TextDirection::Rtl;
+        // The title takes the space left over by the leading view and actions
+        let title_node = ctx.taffy.new_with_children(
+            Style {
+                flex_grow: 1.0,
+                flex_shrink: 1.0,
+                min_size: Size { width: length(0.0), height: Dimension::Auto },
+                align_items: Some(AlignItems::Center),
+                justify_content: rtl.then_some(JustifyContent::End),
+                ..Default::default()
+            },
+            &[text_node],
+        )?;
+        self.title_node = Some(title_node);
+
+        let mut nodes = Vec::new();
+        if let Some(leading) = &mut self.leading {
+            nodes.push(leading.layout(ctx)?);
+        }
+        nodes.push(title_node);
+        for action in &mut self.actions {
+            nodes.push(action.layout(ctx)?);
+        }
+        // Only the title gives up space; the leading view and actions keep their size
+        for &node in nodes.iter().filter(|&&node| node != title_node) {
+            let style = Style { flex_shrink: 0.0, ..ctx.taffy.style(node)?.clone() };
+            ctx.taffy.set_style(node, style)?;
+        }
+
+        let node = ctx.taffy.new_with_children(
+            Style {
+                display: Display::Flex,
+                flex_direction: if rtl { FlexDirection::RowReverse } else { FlexDirection::Row },
+                align_items: Some(AlignItems::Center),
+                flex_shrink: 0.0,
+                size: Size { width: Dimension::Percent(1.0), height: length(self.height) },
+                padding: EdgeInsets::symmetric(16.0, 0.0).to_taffy(),
+                gap: Size { width: length(8.0), height: length(0.0) },
+                ..Default::default()
+            },
+            &nodes,
+        )?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        let Some(title_geo) = layout_ctx.geometry(self.title_node, my_geo) else { return };
+
+        with_z_index(ctx, self.z_index, |ctx| {
+            // Soft shadow: bands below the bar, fading out with distance
+            let bands = self.elevation.ceil() as usize;
+            for i in 0..bands {
+                let alpha = 0.25 * (1.0 - i as f32 / bands as f32);
+                ctx.render_queue.push_rect(
+                    Geometry { x: my_geo.x, y: my_geo.y + my_geo.height + i as f32, width: my_geo.width, height: 1.0 },
+                    [0.0, 0.0, 0.0, alpha],
+                );
+            }
+            ctx.render_queue.push_rect(my_geo, self.color);
+
+            self.title.prepare(ctx, layout_ctx, title_geo);
+            for child in self.children_mut() {
+                child.prepare(ctx, layout_ctx, my_geo);
+            }
+
+            if ctx.debug {
+                render_outline_helper(ctx, my_geo, [1.0, 0.0, 1.0, 1.0]);
+            }
+        });
+    }
+
+    fn collect_text_areas<'a>(&'a self, layout_ctx: &LayoutContext, geometry: Geometry, areas: &mut Vec<glyphon::TextArea<'a>>) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        let Some(title_geo) = layout_ctx.geometry(self.title_node, my_geo) else { return };
+        self.title.collect_text_areas(layout_ctx, title_geo, areas);
+        for child in self.children() {
+            child.collect_text_areas(layout_ctx, my_geo, areas);
+        }
+    }
+
+    fn render<'rp>(&'rp self, ctx: &'rp RenderContext, render_pass: &mut wgpu::RenderPass<'rp>, geometry: Geometry) {
+        for child in self.children() {
+            child.render(ctx, render_pass, geometry);
+        }
+    }
+
+    fn handle_event(&mut self, event: &Event, layout_ctx: &LayoutContext, geometry: Geometry) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        for child in self.children_mut() {
+            child.handle_event(event, layout_ctx, my_geo);
+        }
+    }
+
+    fn on_init(&mut self) { self.children_mut().for_each(|child| child.on_init()); }
+    fn on_mount(&mut self) { self.children_mut().for_each(|child| child.on_mount()); }
+    fn on_update(&mut self) { self.children_mut().for_each(|child| child.on_update()); }
+    fn on_unmount(&mut self) { self.children_mut().for_each(|child| child.on_unmount()); }
+    fn on_resize(&mut self, width: f32, height: f32) {
+        self.title.on_resize(width, height);
+        self.children_mut().for_each(|child| child.on_resize(width, height));
+    }
+
+    fn drawn_z_index(&self) -> Option<i32> {
+        self.z_index
+    }
+}
+
+/// A Material ripple expanding from the press point, in coordinates local to its button.
+struct Ripple {
+    origin: (f32, f32),
+    started: Instant,
+}
+
+const RIPPLE_DURATION: Duration = Duration::from_millis(450);
+
+const BUTTON_HOVER_LIGHTEN: f32 = 0.1;
+const BUTTON_PRESS_DARKEN: f32 = 0.1;
+/// Gap between a button and the ring shown while the d-pad focuses it.
+const FOCUS_RING_OFFSET: f32 = 3.0;
+
+/// A clickable button. It can take focus from the d-pad and is then pressed with a game
+/// controller's A (`GamepadButton::South`) button.
+pub struct Button {
+    pub text: String,
+    pub on_click: Box<dyn FnMut()>,
+    /// Fill color; hover and press shades are derived from it.
+    pub color: [f32; 4],
+    color_binding: Option<Computed<[f32; 4]>>,
+    pub radius: f32,
+    pub padding: EdgeInsets,
+    pub z_index: Option<i32>,
+    /// Extra blur at the button's edges, in pixels; 0 is crisp.
+    pub edge_softness: f32,
+    text_view: Text,
+    debug_name: Option<String>,
+    node_id: Option<NodeId>,
+    interaction: Interaction,
+    /// Whether focus came from the d-pad, which shows a ring around the button.
+    focus_visible: bool,
+    ripples: Vec<Ripple>,
+}
+
+impl Button {
+    pub fn new(text: impl Into<String>, on_click: impl FnMut() + 'static) -> Self {
+        let t = text.into();
+        // Material buttons often use slightly smaller text
+        let text_view = Text::new(t.clone()).relative_size(0.875);
+        Self {
+            text: t,
+            on_click: Box::new(on_click),
+            // Material primary (indigo)
+            color: [0.247, 0.317, 0.709, 1.0],
+            color_binding: None,
+            radius: 8.0,
+            padding: EdgeInsets::symmetric(24.0, 10.0),
+            z_index: None,
+            edge_softness: 0.0,
+            text_view,
+            debug_name: None,
+            node_id: None,
+            interaction: Interaction::default(),
+            focus_visible: false,
+            ripples: Vec::new(),
+        }
+    }
+
+    /// Fills the button with `color`, lightened while hovered and darkened while pressed.
+    pub fn color(mut self, color: [f32; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Takes the color from `color` on every frame, so it follows signals without the tree
+    /// being rebuilt, e.g. when switching themes.
+    pub fn bind_color(mut self, color: Computed<[f32; 4]>) -> Self {
+        self.color_binding = Some(redraw_on_change(color));
+        self
+    }
+
+    /// Corner radius; values past half the button's height produce a pill shape.
+    pub fn radius(mut self, radius: f32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    pub fn padding(mut self, padding: EdgeInsets) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    pub fn z_index(mut self, z_index: i32) -> Self {
+        self.z_index = Some(z_index);
+        self
+    }
+
+    /// Softens the button's edges by `softness` pixels, e.g. for a glowing look.
+    pub fn edge_softness(mut self, softness: f32) -> Self {
+        self.edge_softness = softness;
+        self
+    }
+
+    pub fn debug_name(mut self, name: impl Into<String>) -> Self {
+        self.debug_name = Some(name.into());
+        self
+    }
+}
+
+impl View for Button {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<NodeId, LayoutError> {
+        let text_node = self.text_view.layout(ctx)?;
+        let node = ctx.taffy.new_with_children(
+            Style {
+                padding: self.padding.to_taffy(),
+                justify_content: Some(JustifyContent::Center),
+                align_items: Some(AlignItems::Center),
+                ..Default::default()
+            },
+            &[text_node],
+        )?;
+        self.node_id = Some(node);
+        ctx.set_focusable(node, self.interaction.focused);
+        if let Some(name) = &self.debug_name {
+            ctx.set_debug_name(node, name.clone());
+        }
+        Ok(node)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        if let Some(binding) = &self.color_binding {
+            self.color = binding.get();
+        }
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+
+        let base = Color::from(self.color);
+        let color: [f32; 4] = if self.interaction.pressed {
+            base.darken(BUTTON_PRESS_DARKEN).into()
+        } else if self.interaction.hovered {
+            base.lighten(BUTTON_HOVER_LIGHTEN).into()
+        } else {
+            self.color
+        };
+
+        self.ripples.retain(|ripple| ripple.started.elapsed() < RIPPLE_DURATION);
+        // The SDF breaks down past half the shortest side, so clamp there (a pill)
+        let radius = self.radius.min(my_geo.width * 0.5).min(my_geo.height * 0.5).max(0.0);
+
+        with_z_index(ctx, self.z_index, |ctx| {
+            if self.focus_visible && self.interaction.focused {
+                let ring = Geometry {
+                    x: my_geo.x - FOCUS_RING_OFFSET,
+                    y: my_geo.y - FOCUS_RING_OFFSET,
+                    width: my_geo.width + 2.0 * FOCUS_RING_OFFSET,
+                    height: my_geo.height + 2.0 * FOCUS_RING_OFFSET,
+                };
+                let [r, g, b, a] = self.color;
+                ctx.render_queue.push_rounded_rect(ring, [r, g, b, a * 0.45], radius + FOCUS_RING_OFFSET);
+            }
+            with_edge_softness(ctx, self.edge_softness, |ctx| ctx.render_queue.push_rounded_rect(my_geo, color, radius));
+
+            // Ripples grow to cover the farthest corner while fading out
+            let previous_clip = ctx.render_queue.set_clip(Some(Clip { geometry: my_geo, radius }));
+            for ripple in &self.ripples {
+                let t = ripple.started.elapsed().as_secs_f32() / RIPPLE_DURATION.as_secs_f32();
+                let (ox, oy) = ripple.origin;
+                let max_radius = ox.max(my_geo.width - ox).hypot(oy.max(my_geo.height - oy));
+                let radius = max_radius * Easing::CubicOut.apply(t);
+                ctx.render_queue.push_circle(
+                    Geometry {
+                        x: my_geo.x + ox - radius,
+                        y: my_geo.y + oy - radius,
+                        width: radius * 2.0,
+                        height: radius * 2.0,
+                    },
+                    [1.0, 1.0, 1.0, 0.3 * (1.0 - t)],
+                );
+            }
+            ctx.render_queue.set_clip(previous_clip);
+            if !self.ripples.is_empty() {
+                ctx.request_redraw();
+            }
+
+            self.text_view.prepare(ctx, layout_ctx, my_geo); // Note: using my_geo as parent
+
+            if ctx.debug {
+                render_outline_helper(ctx, my_geo, [1.0, 1.0, 0.0, 1.0]);
+            }
+        });
+    }
+
+    fn collect_text_areas<'a>(&'a self, layout_ctx: &LayoutContext, geometry: Geometry, areas: &mut Vec<glyphon::TextArea<'a>>) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        self.text_view.collect_text_areas(layout_ctx, my_geo, areas);
+    }
+
+    fn render<'rp>(&'rp self, ctx: &'rp RenderContext, render_pass: &mut wgpu::RenderPass<'rp>, geometry: Geometry) {
+        self.text_view.render(ctx, render_pass, geometry);
+    }
+
+    fn handle_event(&mut self, event: &Event, layout_ctx: &LayoutContext, geometry: Geometry) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+
+        let change = self.interaction.handle_event(event, my_geo);
+        if let Event::MouseDown { x, y } = event
+            && change.press_changed
+            && self.interaction.pressed
+        {
+            self.ripples.push(Ripple { origin: my_geo.to_local(*x, *y), started: Instant::now() });
+        }
+        if change.focus_changed {
+            // Clicks move focus too, so d-pad navigation carries on from the clicked button
+            if self.interaction.focused {
+                layout_ctx.set_focused(self.node_id);
+            } else if layout_ctx.focused() == self.node_id {
+                layout_ctx.set_focused(None);
+            }
+            self.focus_visible = false;
+        }
+        if change.clicked {
+            (self.on_click)();
+        }
+
+        match event {
+            Event::FocusMoved { node } => {
+                self.interaction.focused = self.node_id == Some(*node);
+                self.focus_visible = self.interaction.focused;
+            }
+            Event::GamepadButton { button: GamepadButton::South, pressed: true } if self.interaction.focused => {
+                let center = (my_geo.width / 2.0, my_geo.height / 2.0);
+                self.ripples.push(Ripple { origin: center, started: Instant::now() });
+                (self.on_click)();
+            }
+            _ => {}
+        }
+    }
+
+    fn on_init(&mut self) { self.text_view.on_init(); }
+    fn on_mount(&mut self) { self.text_view.on_mount(); }
+    fn on_update(&mut self) { self.text_view.on_update(); }
+    fn on_unmount(&mut self) { self.text_view.on_unmount(); }
+    fn on_resize(&mut self, width: f32, height: f32) { self.text_view.on_resize(width, height); }
+
+    fn drawn_z_index(&self) -> Option<i32> {
+        self.z_index
+    }
+}
+
+const CHIP_CLOSE_SIZE: f32 = 18.0;
+
+/// A compact pill with a label, e.g. a filter tag or an email recipient.
+///
+/// Chips can carry a leading icon glyph, be selectable (clicking toggles `selected`) and
+/// show a trailing "×" that calls `on_delete`. The "×" has its own hit region, so clicking
+/// it doesn't also count as a click on the chip.
+pub struct Chip {
+    pub label: String,
+    pub selected: bool,
+    pub z_index: Option<i32>,
+    icon: Option<Text>,
+    label_view: Text,
+    close_view: Option<Text>,
+    on_select: Option<Box<dyn FnMut(bool)>>,
+    on_delete: Option<Box<dyn FnMut()>>,
+    node_id: Option<NodeId>,
+    close_node: Option<NodeId>,
+    interaction: Interaction,
+    close_interaction: Interaction,
+}
+
+impl Chip {
+    pub fn new(label: impl Into<String>) -> Self {
+        let label = label.into();
+        Self {
+            label_view: Text::new(label.clone()).relative_size(0.875),
+            label,
+            selected: false,
+            z_index: None,
+            icon: None,
+            close_view: None,
+            on_select: None,
+            on_delete: None,
+            node_id: None,
+            close_node: None,
+            interaction: Interaction::default(),
+            close_interaction: Interaction::default(),
+        }
+    }
+
+    /// Shows `glyph` (e.g. an emoji or symbol) before the label.
+    pub fn icon(mut self, glyph: impl Into<String>) -> Self {
+        self.icon = Some(Text::new(glyph).relative_size(0.875));
+        self
+    }
+
+    pub fn selected(mut self, selected: bool) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Makes the chip selectable: clicking it toggles `selected` and calls `f` with the new state.
+    pub fn on_select(mut self, f: impl FnMut(bool) + 'static) -> Self {
+        self.on_select = Some(Box::new(f));
+        self
+    }
+
+    /// Adds a trailing "×" that calls `f` when clicked.
+    pub fn on_delete(mut self, f: impl FnMut() + 'static) -> Self {
+        self.close_view = Some(Text::new("×").relative_size(0.875));
+        self.on_delete = Some(Box::new(f));
+        self
+    }
+
+    pub fn z_index(mut self, z_index: i32) -> Self {
+        self.z_index = Some(z_index);
+        self
+    }
+
+    fn texts(&self) -> impl Iterator<Item = &Text> {
+        self.icon.iter().chain(std::iter::once(&self.label_view))
+    }
+}
+
+impl View for Chip {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<NodeId, LayoutError> {
+        let mut children = Vec::new();
+        if let Some(icon) = &mut self.icon {
+            children.push(icon.layout(ctx)?);
+        }
+        children.push(self.label_view.layout(ctx)?);
+        self.close_node = match &mut self.close_view {
+            Some(close_view) => {
+                let text_node = close_view.layout(ctx)?;
+                let node = ctx.taffy.new_with_children(
+                    Style {
+                        size: Size { width: length(CHIP_CLOSE_SIZE), height: length(CHIP_CLOSE_SIZE) },
+                        justify_content: Some(JustifyContent::Center),
+                        align_items: Some(AlignItems::Center),
+                        flex_shrink: 0.0,
+                        ..Default::default()
+                    },
+                    &[text_node],
+                )?;
+                children.push(node);
+                Some(node)
+            }
+            None => None,
+        };
+        let trailing = if self.close_node.is_some() { 6.0 } else { 12.0 };
+        let node = ctx.taffy.new_with_children(
+            Style {
+                flex_direction: FlexDirection::Row,
+                align_items: Some(AlignItems::Center),
+                gap: Size { width: length(6.0), height: zero() },
+                padding: taffy::Rect { left: length(12.0), right: length(trailing), top: length(4.0), bottom: length(4.0) },
+                ..Default::default()
+            },
+            &children,
+        )?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        let close_geo = layout_ctx.geometry(self.close_node, my_geo);
+
+        let mut color = if self.selected { [0.247, 0.317, 0.709, 1.0] } else { [0.22, 0.23, 0.28, 1.0] };
+        // Only the chip body gives hover feedback while the pointer is on it, not on the "×"
+        if self.interaction.hovered && !self.close_interaction.hovered {
+            color = [color[0] + 0.06, color[1] + 0.06, color[2] + 0.08, 1.0];
+        }
+
+        with_z_index(ctx, self.z_index, |ctx| {
+            ctx.render_queue.push_rounded_rect(my_geo, color, my_geo.height * 0.5);
+            if let Some(close_geo) = close_geo
+                && self.close_interaction.hovered
+            {
+                ctx.render_queue.push_circle(close_geo, [1.0, 1.0, 1.0, 0.15]);
+            }
+
+            if let Some(icon) = &mut self.icon {
+                icon.prepare(ctx, layout_ctx, my_geo);
+            }
+            self.label_view.prepare(ctx, layout_ctx, my_geo);
+            if let (Some(close_view), Some(close_geo)) = (&mut self.close_view, close_geo) {
+                close_view.prepare(ctx, layout_ctx, close_geo);
+            }
+
+            if ctx.debug {
+                render_outline_helper(ctx, my_geo, [1.0, 1.0, 0.0, 1.0]);
+            }
+        });
+    }
+
+    fn collect_text_areas<'a>(&'a self, layout_ctx: &LayoutContext, geometry: Geometry, areas: &mut Vec<glyphon::TextArea<'a>>) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        for text in self.texts() {
+            text.collect_text_areas(layout_ctx, my_geo, areas);
+        }
+        if let (Some(close_view), Some(close_geo)) = (&self.close_view, layout_ctx.geometry(self.close_node, my_geo)) {
+            close_view.collect_text_areas(layout_ctx, close_geo, areas);
+        }
+    }
+
+    fn render<'rp>(&'rp self, _: &'rp RenderContext, _: &mut wgpu::RenderPass<'rp>, _: Geometry) {}
+
+    fn handle_event(&mut self, event: &Event, layout_ctx: &LayoutContext, geometry: Geometry) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+
+        let body = self.interaction.handle_event(event, my_geo);
+        if let Some(close_geo) = layout_ctx.geometry(self.close_node, my_geo) {
+            let close = self.close_interaction.handle_event(event, close_geo);
+            if close.clicked {
+                if let Some(on_delete) = &mut self.on_delete {
+                    on_delete();
+                }
+                return;
+            }
+        }
+        if body.clicked
+            && let Some(on_select) = &mut self.on_select
+        {
+            self.selected = !self.selected;
+            on_select(self.selected);
+        }
+    }
+
+    fn drawn_z_index(&self) -> Option<i32> {
+        self.z_index
+    }
+}
+
+const INPUT_PADDING: f32 = 8.0;
+const MASK_CHAR: char = '•';
+const ERROR_FONT_SIZE: f32 = 12.0;
+const ERROR_GAP: f32 = 4.0;
+
+/// An editable text field. Clicking focuses it; while focused it receives key presses.
+///
+/// Navigation: Left/Right move by character (by word with Ctrl), Home/End move to the
+/// start/end of the line (Home first stops at the first non-whitespace character),
+/// Ctrl+Home/End jump to the start/end of the text and Up/Down move between the lines as
+/// drawn, wrapped ones included, keeping to the caret's horizontal position.
+/// Ctrl+V (Cmd+V on macOS) pastes from the `clipboard`, with line breaks turned into spaces
+/// in single-line fields.
+///
+/// ```
+/// use noxkit::prelude::*;
+/// use noxkit::testing::TestHarness;
+///
+/// let name = create_signal("Ada ".to_string());
+/// let field = TextInput::bind(name.clone()).debug_name("name");
+/// let mut harness = TestHarness::new(Box::new(field), 400.0, 300.0).unwrap();
+/// let field = harness.find_named("name").unwrap();
+/// harness.click(field.x + 5.0, field.y + 5.0);
+///
+/// noxkit::clipboard::set_text("Love\nlace");
+/// harness.set_modifiers(Modifiers { ctrl: true, ..Default::default() });
+/// harness.key(Key::Character('v'));
+/// harness.set_modifiers(Modifiers::default());
+/// harness.type_text("!");
+/// assert_eq!(name.get(), "Ada Love lace!");
+/// ```
+pub struct TextInput {
+    pub value: String,
+    /// Size in effect at the last layout; follows the app's `base_font_size` unless set
+    /// with `size`.
+    pub font_size: f32,
+    /// Multiple of the app's `base_font_size`, unless sized in pixels.
+    relative_size: Option<f32>,
+    /// Number of visible lines; more than one makes Enter insert a newline.
+    pub rows: usize,
+    /// Draws every character (grapheme cluster, so an emoji with modifiers is one) as a
+    /// bullet, e.g. for passwords. `value` keeps the real text.
+    pub secure: bool,
+    /// Dimmed hint shown while the field is empty and unfocused. Never part of `value`.
+    pub placeholder: Option<String>,
+    /// Validation message shown in red below the field while it is `Some`.
+    pub error: Option<Signal<Option<String>>>,
+    on_change: Box<dyn FnMut(&str)>,
+    /// Signal the value is shown from and edits are written to, set with `bind`.
+    binding: Option<Signal<String>>,
+    cursor: usize,
+    /// Where the caret was horizontally when a run of Up/Down presses started, relative to
+    /// the text's left edge.
+    preferred_x: Option<f32>,
+    /// IME composition shown at the caret until committed: text and IME caret offset.
+    preedit: Option<(String, Option<(usize, usize)>)>,
+    interaction: Interaction,
+    /// Set by input so the next frame restarts the caret blink, keeping it visible while typing.
+    caret_moved: bool,
+    debug_name: Option<String>,
+    buffer: Option<glyphon::Buffer>,
+    placeholder_buffer: Option<glyphon::Buffer>,
+    error_buffer: Option<glyphon::Buffer>,
+    node_id: Option<NodeId>,
+    last_text: Option<String>,
+    last_error: Option<String>,
+}
+
+impl TextInput {
+    pub fn new(value: impl Into<String>, on_change: impl FnMut(&str) + 'static) -> Self {
+        let value = value.into();
+        Self {
+            cursor: value.len(),
+            value,
+            font_size: 16.0,
+            relative_size: Some(1.0),
+            rows: 1,
+            secure: false,
+            placeholder: None,
+            error: None,
+            on_change: Box::new(on_change),
+            binding: None,
+            preferred_x: None,
+            preedit: None,
+            interaction: Interaction::default(),
+            caret_moved: false,
+            debug_name: None,
+            buffer: None,
+            placeholder_buffer: None,
+            error_buffer: None,
+            node_id: None,
+            last_text: None,
+            last_error: None,
+        }
+    }
+
+    /// A field bound to a form `Field`: edits update and validate it, and its error shows below.
+    pub fn for_field(field: &Field) -> Self {
+        let target = field.clone();
+        Self::new(field.value.get(), move |value| target.set(value)).error(field.error.clone())
+    }
+
+    /// A field showing `value` and writing edits back to it. Setting the signal from
+    /// elsewhere, e.g. a "clear" button, updates the field on the next frame.
+    ///
+    /// ```
+    /// use noxkit::prelude::*;
+    /// use noxkit::testing::TestHarness;
+    ///
+    /// let name = create_signal("Ada".to_string());
+    /// let field = TextInput::bind(name.clone()).debug_name("name");
+    /// let mut harness = TestHarness::new(Box::new(field), 400.0, 300.0).unwrap();
+    ///
+    /// let field = harness.find_named("name").unwrap();
+    /// harness.click(field.x + 5.0, field.y + 5.0);
+    /// harness.type_text("!");
+    /// assert_eq!(name.get(), "Ada!");
+    ///
+    /// // The field follows outside changes, and edits go on from there
+    /// name.set(String::new());
+    /// harness.type_text("Grace");
+    /// assert_eq!(name.get(), "Grace");
+    /// ```
+    pub fn bind(value: Signal<String>) -> Self {
+        value.subscribe(|| Commands::new().request_redraw());
+        let mut input = Self::new(value.get(), |_| {});
+        input.binding = Some(value);
+        input
+    }
+
+    /// Called with the new value after every edit, along with writing any `bind` signal.
+    pub fn on_change(mut self, on_change: impl FnMut(&str) + 'static) -> Self {
+        self.on_change = Box::new(on_change);
+        self
+    }
+
+    pub fn error(mut self, error: Signal<Option<String>>) -> Self {
+        self.error = Some(error);
+        self
+    }
+
+    pub fn debug_name(mut self, name: impl Into<String>) -> Self {
+        self.debug_name = Some(name.into());
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the font size in pixels, ignoring the app's `base_font_size`.
+    pub fn size(mut self, font_size: f32) -> Self {
+        self.font_size = font_size;
+        self.relative_size = None;
+        self
+    }
+
+    pub fn multiline(mut self, rows: usize) -> Self {
+        self.rows = rows.max(1);
+        self
+    }
+
+    pub fn secure(mut self, secure: bool) -> Self {
+        self.secure = secure;
+        self
+    }
+
+    pub fn is_focused(&self) -> bool {
+        self.interaction.focused
+    }
+
+    /// The caret position as a byte index into `value`.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    fn line_height(&self) -> f32 {
+        self.font_size * 1.5
+    }
+
+    /// Height of the input box itself, excluding the error line below it.
+    fn box_height(&self) -> f32 {
+        self.rows as f32 * self.line_height() + INPUT_PADDING * 2.0
+    }
+
+    fn shows_placeholder(&self) -> bool {
+        self.placeholder.is_some() && self.value.is_empty() && !self.interaction.focused
+    }
+
+    /// The value with any IME composition spliced in at the caret.
+    fn composed_text(&self) -> String {
+        let mut text = self.value.clone();
+        if let Some((preedit, _)) = &self.preedit {
+            text.insert_str(self.cursor, preedit);
+        }
+        text
+    }
+
+    /// The caret as a byte index into `composed_text`.
+    fn composed_cursor(&self) -> usize {
+        match &self.preedit {
+            Some((preedit, ime_cursor)) => self.cursor + ime_cursor.map_or(preedit.len(), |(_, end)| end),
+            None => self.cursor,
+        }
+    }
+
+    /// The text as drawn: `composed`, or a bullet per grapheme cluster when masked.
+    fn display_text(&self, composed: &str) -> String {
+        if self.secure { composed.graphemes(true).map(|_| MASK_CHAR).collect() } else { composed.to_string() }
+    }
+
+    /// Maps a byte index into `composed` onto the drawn text, which differs when masked.
+    fn display_index(&self, composed: &str, index: usize) -> usize {
+        if self.secure {
+            composed[..index].graphemes(true).count() * MASK_CHAR.len_utf8()
+        } else {
+            index
+        }
+    }
+
+    /// Maps a byte index into the drawn text back onto `value`, undoing `display_index`.
+    fn value_index(&self, index: usize) -> usize {
+        if self.secure {
+            let graphemes = index / MASK_CHAR.len_utf8();
+            self.value.grapheme_indices(true).nth(graphemes).map_or(self.value.len(), |(i, _)| i)
+        } else {
+            index
+        }
+    }
+
+    /// Where Up/Down take the caret: the spot on the laid-out line above or below nearest
+    /// `preferred_x`. None on the first or last line, or before the value has been laid out.
+    fn vertical_target(&mut self, up: bool) -> Option<usize> {
+        let display = self.display_text(&self.value);
+        let buffer = self.buffer.as_ref().filter(|_| self.last_text.as_ref() == Some(&display))?;
+        let (x, top, height) = caret_position(buffer, &display, self.display_index(&self.value, self.cursor));
+        let x = *self.preferred_x.get_or_insert(x);
+        let y = if up { top - height / 2.0 } else { top + height * 1.5 };
+        if !buffer.layout_runs().any(|run| if up { run.line_top < top } else { run.line_top > top }) {
+            return None;
+        }
+        Some(self.value_index(buffer_index_at(buffer, x, y)?))
+    }
+
+    fn insert(&mut self, text: &str) {
+        self.value.insert_str(self.cursor, text);
+        self.cursor += text.len();
+        self.changed();
+    }
+
+    fn delete_range(&mut self, start: usize, end: usize) {
+        if start < end {
+            self.value.replace_range(start..end, "");
+            self.cursor = start;
+            self.changed();
+        }
+    }
+
+    /// Inserts the clipboard's text at the caret.
+    fn paste(&mut self) {
+        let Some(text) = crate::clipboard::get_text() else { return };
+        let text = text.replace("\r\n", "\n");
+        let text = if self.rows > 1 { text } else { text.replace('\n', " ") };
+        let text: String = text.chars().filter(|&c| c == '\n' || !c.is_control()).collect();
+        if !text.is_empty() {
+            self.insert(&text);
+        }
+    }
+
+    fn changed(&mut self) {
+        if let Some(binding) = &self.binding {
+            binding.set(self.value.clone());
+        }
+        (self.on_change)(&self.value);
+    }
+
+    /// Takes the bound signal's value if something other than this field changed it. Edits
+    /// made here leave the two equal, so they don't come back as outside changes.
+    fn sync_binding(&mut self) {
+        let Some(binding) = &self.binding else { return };
+        let value = binding.borrow();
+        if *value != self.value {
+            self.value = value.clone();
+            self.cursor = self.value.len();
+            self.preferred_x = None;
+            self.preedit = None;
+        }
+    }
+
+    fn handle_key(&mut self, key: Key, modifiers: Modifiers) {
+        let text = self.value.as_str();
+        // Word jumps would reveal where the spaces are in a masked value, so jump the whole line
+        let (word_start, word_end) = if self.secure {
+            (line_start as fn(&str, usize) -> usize, line_end as fn(&str, usize) -> usize)
+        } else {
+            (word_start as fn(&str, usize) -> usize, word_end as fn(&str, usize) -> usize)
+        };
+        let vertical = matches!(key, Key::ArrowUp | Key::ArrowDown);
+        if !vertical {
+            self.preferred_x = None;
+        }
+
+        match key {
+            Key::Character('v' | 'V') if modifiers.ctrl || modifiers.logo => self.paste(),
+            Key::Character(c) if !modifiers.ctrl && !modifiers.logo => {
+                let mut bytes = [0; 4];
+                self.insert(c.encode_utf8(&mut bytes));
+            }
+            Key::Enter if self.rows > 1 => self.insert("\n"),
+            Key::Backspace => {
+                let start = if modifiers.ctrl { word_start(text, self.cursor) } else { prev_boundary(text, self.cursor) };
+                self.delete_range(start, self.cursor);
+            }
+            Key::Delete => {
+                let end = if modifiers.ctrl { word_end(text, self.cursor) } else { next_boundary(text, self.cursor) };
+                let start = self.cursor;
+                self.delete_range(start, end);
+            }
+            Key::ArrowLeft => {
+                self.cursor = if modifiers.ctrl { word_start(text, self.cursor) } else { prev_boundary(text, self.cursor) };
+            }
+            Key::ArrowRight => {
+                self.cursor = if modifiers.ctrl { word_end(text, self.cursor) } else { next_boundary(text, self.cursor) };
+            }
+            Key::Home if modifiers.ctrl => self.cursor = 0,
+            Key::End if modifiers.ctrl => self.cursor = text.len(),
+            Key::Home => {
+                // Smart home: first non-whitespace character, then the absolute line start
+                let start = line_start(text, self.cursor);
+                let indent = start + text[start..line_end(text, self.cursor)].len()
+                    - text[start..line_end(text, self.cursor)].trim_start().len();
+                self.cursor = if self.cursor == indent { start } else { indent };
+            }
+            Key::End => self.cursor = line_end(text, self.cursor),
+            Key::ArrowUp | Key::ArrowDown => {
+                if let Some(target) = self.vertical_target(key == Key::ArrowUp) {
+                    self.cursor = target;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The start of the grapheme cluster before `index`, so the caret never splits an emoji or
+/// a letter from its accent.
+fn prev_boundary(text: &str, index: usize) -> usize {
+    text[..index].grapheme_indices(true).next_back().map_or(0, |(i, _)| i)
+}
+
+fn next_boundary(text: &str, index: usize) -> usize {
+    text[index..].graphemes(true).next().map_or(index, |grapheme| index + grapheme.len())
+}
+
+fn line_start(text: &str, index: usize) -> usize {
+    text[..index].rfind('\n').map_or(0, |i| i + 1)
+}
+
+fn line_end(text: &str, index: usize) -> usize {
+    text[index..].find('\n').map_or(text.len(), |i| index + i)
+}
+
+/// Word characters, punctuation and whitespace each form their own runs, so `foo.bar`
+/// stops at the `.` like most editors do.
+#[derive(PartialEq)]
+enum CharClass {
+    Word,
+    Punctuation,
+    Whitespace,
+}
+
+fn char_class(c: char) -> CharClass {
+    if c.is_whitespace() {
+        CharClass::Whitespace
+    } else if c.is_alphanumeric() || c == '_' {
+        CharClass::Word
+    } else {
+        CharClass::Punctuation
+    }
+}
+
+fn word_start(text: &str, index: usize) -> usize {
+    let mut chars = text[..index].char_indices().rev().skip_while(|(_, c)| c.is_whitespace()).peekable();
+    let Some(&(mut start, first)) = chars.peek() else { return 0 };
+    let class = char_class(first);
+    for (i, c) in chars {
+        if char_class(c) != class {
+            break;
+        }
+        start = i;
+    }
+    start
+}
+
+fn word_end(text: &str, index: usize) -> usize {
+    let rest = &text[index..];
+    let mut chars = rest.char_indices().skip_while(|(_, c)| c.is_whitespace()).peekable();
+    let Some(&(_, first)) = chars.peek() else { return text.len() };
+    let class = char_class(first);
+    chars.find(|(_, c)| char_class(*c) != class).map_or(text.len(), |(i, _)| index + i)
+}
+
+/// Returns the caret's `(x, top, height)` relative to the buffer origin.
+fn caret_position(buffer: &glyphon::Buffer, text: &str, cursor: usize) -> (f32, f32, f32) {
+    let line_i = text[..cursor].matches('\n').count();
+    let offset = cursor - line_start(text, cursor);
+    let mut position = (0.0, 0.0, buffer.metrics().line_height);
+    for run in buffer.layout_runs().filter(|run| run.line_i == line_i) {
+        let end_x = run.glyphs.last().map_or(0.0, |glyph| glyph.x + glyph.w);
+        position = (end_x, run.line_top, run.line_height);
+        if let Some(glyph) = run.glyphs.iter().find(|glyph| offset < glyph.end) {
+            position.0 = glyph.x;
+            break;
+        }
+    }
+    position
+}
+
+impl View for TextInput {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<NodeId, LayoutError> {
+        if let Some(factor) = self.relative_size
+            && ctx.base_font_size * factor != self.font_size
+        {
+            self.font_size = ctx.base_font_size * factor;
+            // The buffers were made with the old metrics
+            self.buffer = None;
+            self.placeholder_buffer = None;
+            self.last_text = None;
+        }
+        let node = ctx.taffy.new_leaf(Style {
+            size: Size {
+                width: length(240.0),
+                // Reserve the error line up front so showing an error doesn't need a relayout
+                height: length(self.box_height() + if self.error.is_some() { ERROR_FONT_SIZE * 1.5 + ERROR_GAP } else { 0.0 }),
+            },
+            ..Default::default()
+        })?;
+        self.node_id = Some(node);
+        if let Some(name) = &self.debug_name {
+            ctx.set_debug_name(node, name.clone());
+        }
+        Ok(node)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        let my_geo = Geometry { height: self.box_height(), ..my_geo };
+
+        if self.buffer.is_none() {
+            let mut buffer = glyphon::Buffer::new(&mut ctx.font_system, glyphon::Metrics::new(self.font_size, self.line_height()));
+            if self.rows == 1 {
+                buffer.set_wrap(&mut ctx.font_system, glyphon::Wrap::None);
+            }
+            self.buffer = Some(buffer);
+        }
+
+        if let Some(placeholder) = &self.placeholder
+            && self.placeholder_buffer.is_none()
+        {
+            let mut buffer = glyphon::Buffer::new(&mut ctx.font_system, glyphon::Metrics::new(self.font_size, self.line_height()));
+            buffer.set_wrap(&mut ctx.font_system, glyphon::Wrap::None);
+            buffer.set_text(&mut ctx.font_system, placeholder, &glyphon::Attrs::new().family(glyphon::Family::SansSerif), layout_ctx.text_shaping);
+            buffer.set_size(&mut ctx.font_system, Some(my_geo.width - INPUT_PADDING * 2.0), Some(my_geo.height - INPUT_PADDING * 2.0));
+            buffer.shape_until_scroll(&mut ctx.font_system, false);
+            self.placeholder_buffer = Some(buffer);
+        }
+
+        if let Some(error) = &self.error {
+            let message = error.get().unwrap_or_default();
+            if self.last_error.as_ref() != Some(&message) {
+                let buffer = self.error_buffer.get_or_insert_with(|| {
+                    glyphon::Buffer::new(&mut ctx.font_system, glyphon::Metrics::new(ERROR_FONT_SIZE, ERROR_FONT_SIZE * 1.5))
+                });
+                buffer.set_text(&mut ctx.font_system, &message, &glyphon::Attrs::new().family(glyphon::Family::SansSerif), layout_ctx.text_shaping);
+                buffer.set_size(&mut ctx.font_system, Some(my_geo.width), Some(ERROR_FONT_SIZE * 1.5));
+                buffer.shape_until_scroll(&mut ctx.font_system, false);
+                self.last_error = Some(message);
+            }
+        }
+
+        let composed = self.composed_text();
+        let display_cursor = self.display_index(&composed, self.composed_cursor());
+        let preedit_range = self.preedit.as_ref().map(|(preedit, _)| {
+            (self.display_index(&composed, self.cursor), self.display_index(&composed, self.cursor + preedit.len()))
+        });
+        let display = self.display_text(&composed);
+        let buffer = self.buffer.as_mut().unwrap();
+
+        if self.last_text.as_ref() != Some(&display) {
+            ctx.stats.shaped_texts += 1;
+            buffer.set_text(&mut ctx.font_system, &display, &glyphon::Attrs::new().family(glyphon::Family::SansSerif), layout_ctx.text_shaping);
+            buffer.set_size(&mut ctx.font_system, Some(my_geo.width - INPUT_PADDING * 2.0), Some(my_geo.height - INPUT_PADDING * 2.0));
+            buffer.shape_until_scroll(&mut ctx.font_system, false);
+            self.last_text = Some(display.clone());
+        }
+
+        let border = if self.interaction.focused { [0.247, 0.317, 0.709, 1.0] } else { [0.3, 0.3, 0.35, 1.0] };
+        ctx.render_queue.push_rounded_rect(my_geo, border, 6.0);
+        ctx.render_queue.push_rounded_rect(
+            Geometry { x: my_geo.x + 1.5, y: my_geo.y + 1.5, width: my_geo.width - 3.0, height: my_geo.height - 3.0 },
+            [0.1, 0.1, 0.12, 1.0],
+            4.5,
+        );
+
+        if self.interaction.focused {
+            let (x, top, height) = caret_position(buffer, &display, display_cursor);
+            let caret = Geometry { x: my_geo.x + INPUT_PADDING + x, y: my_geo.y + INPUT_PADDING + top, width: 1.5, height };
+            if std::mem::take(&mut self.caret_moved) {
+                ctx.restart_blink();
+            }
+            if ctx.blink_phase() {
+                ctx.render_queue.push_rect(caret, [1.0, 1.0, 1.0, 1.0]);
+            }
+            ctx.set_ime_cursor_area(caret);
+
+            // Underline the composition so it reads as provisional
+            if let Some((start, end)) = preedit_range {
+                let (start_x, start_top, start_height) = caret_position(buffer, &display, start);
+                let (end_x, _, _) = caret_position(buffer, &display, end);
+                ctx.render_queue.push_rect(
+                    Geometry {
+                        x: my_geo.x + INPUT_PADDING + start_x,
+                        y: my_geo.y + INPUT_PADDING + start_top + start_height - 2.0,
+                        width: (end_x - start_x).max(0.0),
+                        height: 1.0,
+                    },
+                    [1.0, 1.0, 1.0, 0.8],
+                );
+            }
+        }
+
+        if ctx.debug {
+            render_outline_helper(ctx, my_geo, [0.0, 1.0, 1.0, 1.0]);
+        }
+    }
+
+    fn collect_text_areas<'a>(&'a self, layout_ctx: &LayoutContext, geometry: Geometry, areas: &mut Vec<glyphon::TextArea<'a>>) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        let my_geo = Geometry { height: self.box_height(), ..my_geo };
+
+        let (buffer, color) = if self.shows_placeholder() {
+            (&self.placeholder_buffer, glyphon::Color::rgba(255, 255, 255, 110))
+        } else {
+            (&self.buffer, glyphon::Color::rgb(255, 255, 255))
+        };
+
+        if let Some(buffer) = buffer {
+            areas.push(glyphon::TextArea {
+                buffer,
+                left: my_geo.x + INPUT_PADDING,
+                top: my_geo.y + INPUT_PADDING,
+                scale: 1.0,
+                bounds: glyphon::TextBounds {
+                    left: (my_geo.x + INPUT_PADDING) as i32,
+                    top: (my_geo.y + INPUT_PADDING) as i32,
+                    right: (my_geo.x + my_geo.width - INPUT_PADDING) as i32,
+                    bottom: (my_geo.y + my_geo.height - INPUT_PADDING) as i32,
+                },
+                default_color: color,
+                custom_glyphs: &[],
+            });
+        }
+
+        if let Some(buffer) = &self.error_buffer
+            && self.last_error.as_ref().is_some_and(|message| !message.is_empty())
+        {
+            let top = my_geo.y + my_geo.height + ERROR_GAP;
+            areas.push(glyphon::TextArea {
+                buffer,
+                left: my_geo.x,
+                top,
+                scale: 1.0,
+                bounds: glyphon::TextBounds {
+                    left: my_geo.x as i32,
+                    top: top as i32,
+                    right: (my_geo.x + my_geo.width) as i32,
+                    bottom: (top + ERROR_FONT_SIZE * 1.5) as i32,
+                },
+                default_color: glyphon::Color::rgb(239, 83, 80),
+                custom_glyphs: &[],
+            });
+        }
+    }
+
+    fn render<'rp>(&'rp self, _: &'rp RenderContext, _: &mut wgpu::RenderPass<'rp>, _: Geometry) {}
+
+    fn handle_event(&mut self, event: &Event, layout_ctx: &LayoutContext, geometry: Geometry) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        let my_geo = Geometry { height: self.box_height(), ..my_geo };
+
+        let change = self.interaction.handle_event(event, my_geo);
+        if !self.interaction.focused {
+            self.preedit = None;
+            return;
+        }
+        // The signal may have changed since the last frame; edit what it holds now
+        self.sync_binding();
+
+        self.caret_moved |= change.focus_changed
+            || matches!(event, Event::MouseDown { .. } | Event::KeyDown { .. } | Event::ImePreedit { .. } | Event::ImeCommit(_));
+        match event {
+            Event::KeyDown { key, modifiers } if self.preedit.is_none() => self.handle_key(*key, *modifiers),
+            Event::ImePreedit { text, cursor } => {
+                self.preedit = (!text.is_empty()).then(|| (text.clone(), *cursor));
+            }
+            Event::ImeCommit(text) => {
+                self.preedit = None;
+                self.insert(text);
+            }
+            _ => {}
+        }
+    }
+
+    fn on_update(&mut self) {
+        self.sync_binding();
+    }
+}
+
+/// Adds the hover/press/focus tracking `Button` uses to any view, reporting transitions and
+/// clicks through callbacks. Layout-transparent: the child's node is used as-is. `view!`
+/// wraps a widget in one when it is given an `on_click`, `on_double_click`, `on_long_press`,
+/// `on_hover_change`, `on_press_change` or `on_focus_change` property.
+pub struct Interactive {
+    pub child: Box<dyn View>,
+    pub interaction: Interaction,
+    on_hover_change: Option<Box<dyn FnMut(bool)>>,
+    on_press_change: Option<Box<dyn FnMut(bool)>>,
+    on_focus_change: Option<Box<dyn FnMut(bool)>>,
+    on_click: Option<Box<dyn FnMut(f32, f32)>>,
+    on_double_click: Option<Box<dyn FnMut(f32, f32)>>,
+    on_long_press: Option<Box<dyn FnMut(f32, f32)>>,
+    node_id: Option<NodeId>,
+}
+
+impl Interactive {
+    pub fn new(child: impl View + 'static) -> Self {
+        Self {
+            child: Box::new(child),
+            interaction: Interaction::default(),
+            on_hover_change: None,
+            on_press_change: None,
+            on_focus_change: None,
+            on_click: None,
+            on_double_click: None,
+            on_long_press: None,
+            node_id: None,
+        }
+    }
+
+    pub fn on_hover_change(mut self, f: impl FnMut(bool) + 'static) -> Self {
+        self.on_hover_change = Some(Box::new(f));
+        self
+    }
+
+    pub fn on_press_change(mut self, f: impl FnMut(bool) + 'static) -> Self {
+        self.on_press_change = Some(Box::new(f));
+        self
+    }
+
+    pub fn on_focus_change(mut self, f: impl FnMut(bool) + 'static) -> Self {
+        self.on_focus_change = Some(Box::new(f));
+        self
+    }
+
+    /// Called with the window coordinates of each click that lands on the child.
+    pub fn on_click(mut self, f: impl FnMut(f32, f32) + 'static) -> Self {
+        self.on_click = Some(Box::new(f));
+        self
+    }
+
+    /// Called with the window coordinates of each double click on the child. Its first
+    /// click and the second one's `MouseClick` still reach `on_click` first.
+    pub fn on_double_click(mut self, f: impl FnMut(f32, f32) + 'static) -> Self {
+        self.on_double_click = Some(Box::new(f));
+        self
+    }
+
+    /// Called with the window coordinates when the child is pressed and held (see
+    /// `Event::LongPress`).
+    pub fn on_long_press(mut self, f: impl FnMut(f32, f32) + 'static) -> Self {
+        self.on_long_press = Some(Box::new(f));
+        self
+    }
+}
+
+impl View for Interactive {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<NodeId, LayoutError> {
+        let node = self.child.layout(ctx)?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        self.child.prepare(ctx, layout_ctx, geometry);
+    }
+
+    fn collect_text_areas<'a>(&'a self, layout_ctx: &LayoutContext, geometry: Geometry, areas: &mut Vec<glyphon::TextArea<'a>>) {
+        self.child.collect_text_areas(layout_ctx, geometry, areas);
+    }
+
+    fn render<'rp>(&'rp self, ctx: &'rp RenderContext, render_pass: &mut wgpu::RenderPass<'rp>, geometry: Geometry) {
+        self.child.render(ctx, render_pass, geometry);
+    }
+
+    fn handle_event(&mut self, event: &Event, layout_ctx: &LayoutContext, geometry: Geometry) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+
+        let change = self.interaction.handle_event(event, my_geo);
+        if change.hover_changed && let Some(f) = &mut self.on_hover_change {
+            f(self.interaction.hovered);
+        }
+        if change.press_changed && let Some(f) = &mut self.on_press_change {
+            f(self.interaction.pressed);
+        }
+        if change.focus_changed && let Some(f) = &mut self.on_focus_change {
+            f(self.interaction.focused);
+        }
+        if change.clicked
+            && let Event::MouseClick { x, y } = event
+            && let Some(f) = &mut self.on_click
+        {
+            f(*x, *y);
+        }
+        if change.double_clicked
+            && let Event::DoubleClick { x, y } = event
+            && let Some(f) = &mut self.on_double_click
+        {
+            f(*x, *y);
+        }
+        if change.long_pressed
+            && let Event::LongPress { x, y } = event
+            && let Some(f) = &mut self.on_long_press
+        {
+            f(*x, *y);
+        }
+
+        self.child.handle_event(event, layout_ctx, geometry);
+    }
+
+    fn on_init(&mut self) { self.child.on_init(); }
+    fn on_mount(&mut self) { self.child.on_mount(); }
+    fn on_update(&mut self) { self.child.on_update(); }
+    fn on_unmount(&mut self) { self.child.on_unmount(); }
+    fn on_resize(&mut self, width: f32, height: f32) { self.child.on_resize(width, height); }
+    fn drawn_z_index(&self) -> Option<i32> { self.child.drawn_z_index() }
+}
+
+/// Whether a `Hideable` widget is shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Visibility {
+    #[default]
+    Visible,
+    /// Not drawn and deaf to events, but still taking up its space (CSS `visibility: hidden`).
+    Hidden,
+    /// Not drawn, deaf to events and taking up no space (CSS `display: none`).
+    Collapsed,
+}
+
+/// Shows or hides its child as `visibility` changes, without rebuilding the tree: the child
+/// keeps its state while hidden. `view!` wraps a widget in one when it is given a
+/// `visibility` property.
+pub struct Hideable {
+    pub child: Box<dyn View>,
+    visibility: Signal<Visibility>,
+    /// Whether the last layout collapsed the child.
+    collapsed: bool,
+}
+
+impl Hideable {
+    pub fn new(child: impl View + 'static) -> Self {
+        Self { child: Box::new(child), visibility: create_signal(Visibility::Visible), collapsed: false }
+    }
+
+    /// Follows `visibility`; setting the signal shows or hides the child on the next frame.
+    pub fn visibility(mut self, visibility: Signal<Visibility>) -> Self {
+        self.visibility = visibility;
+        self
+    }
+
+    fn shown(&self) -> bool {
+        self.visibility.get() == Visibility::Visible
+    }
+}
+
+impl View for Hideable {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<NodeId, LayoutError> {
+        let node = self.child.layout(ctx)?;
+        self.collapsed = self.visibility.get() == Visibility::Collapsed;
+        if self.collapsed {
+            let style = Style { display: Display::None, ..ctx.taffy.style(node)?.clone() };
+            ctx.taffy.set_style(node, style)?;
+        }
+        Ok(node)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        if self.shown() {
+            self.child.prepare(ctx, layout_ctx, geometry);
+        }
+    }
+
+    fn collect_text_areas<'a>(&'a self, layout_ctx: &LayoutContext, geometry: Geometry, areas: &mut Vec<glyphon::TextArea<'a>>) {
+        if self.shown() {
+            self.child.collect_text_areas(layout_ctx, geometry, areas);
+        }
+    }
+
+    fn render<'rp>(&'rp self, ctx: &'rp RenderContext, render_pass: &mut wgpu::RenderPass<'rp>, geometry: Geometry) {
+        if self.shown() {
+            self.child.render(ctx, render_pass, geometry);
+        }
+    }
+
+    fn handle_event(&mut self, event: &Event, layout_ctx: &LayoutContext, geometry: Geometry) {
+        if self.shown() {
+            self.child.handle_event(event, layout_ctx, geometry);
+        }
+    }
+
+    fn on_init(&mut self) { self.child.on_init(); }
+    fn on_mount(&mut self) { self.child.on_mount(); }
+    fn on_update(&mut self) {
+        // Collapsing frees or reclaims space, which needs a new layout
+        if (self.visibility.get() == Visibility::Collapsed) != self.collapsed {
+            request_layout();
+        }
+        self.child.on_update();
+    }
+    fn on_unmount(&mut self) { self.child.on_unmount(); }
+    fn on_resize(&mut self, width: f32, height: f32) { self.child.on_resize(width, height); }
+    fn drawn_z_index(&self) -> Option<i32> { self.child.drawn_z_index() }
+}
+
+/// How long `Animated` takes to move its child to a new place, unless set with `duration`.
+const ANIMATED_DURATION: Duration = Duration::from_millis(250);
+
+thread_local! {
+    /// State of keyed `Animated` views, so transitions carry over when the tree is rebuilt.
+    static ANIMATED_STATES: RefCell<HashMap<String, Rc<RefCell<AnimatedState>>>> = RefCell::new(HashMap::new());
+}
+
+/// Where an `Animated` child is laid out and drawn, relative to its parent.
+#[derive(Default)]
+struct AnimatedState {
+    /// Where the last layout put the child.
+    target: Option<Geometry>,
+    /// Where the child was drawn in the last frame, on its way to `target`.
+    shown: Option<Geometry>,
+    /// Where the running transition started, and when.
+    transition: Option<(Geometry, Instant)>,
+}
+
+/// Moves and resizes its child smoothly to wherever layout puts it instead of letting it
+/// jump, e.g. so list items slide down when one is inserted above them. While the child's
+/// size changes it is clipped to the size shown so far.
+///
+/// The child appears in place the first time it is laid out. A new layout during a
+/// transition starts another one from where the child is drawn, so it turns smoothly
+/// rather than jumping. Trees rebuilt by `App::with_render_fn` are new views with no
+/// memory of the old positions; give each `Animated` a `key` to carry them over.
+///
+/// ```
+/// use noxkit::prelude::*;
+///
+/// let items = create_signal(vec!["b".to_string(), "c".to_string()]);
+/// let app = App::with_render_fn(move || {
+///     let rows: Vec<Box<dyn View>> = items.get().into_iter()
+///         .map(|item| Box::new(Animated::new(Text::new(item.clone())).key(item)) as Box<dyn View>)
+///         .collect();
+///     Box::new(Column::new(rows))
+/// });
+/// ```
+pub struct Animated {
+    pub child: Box<dyn View>,
+    duration: Duration,
+    easing: Easing,
+    state: Rc<RefCell<AnimatedState>>,
+    node_id: Option<NodeId>,
+}
+
+impl Animated {
+    pub fn new(child: impl View + 'static) -> Self {
+        Self {
+            child: Box::new(child),
+            duration: ANIMATED_DURATION,
+            easing: Easing::CubicOut,
+            state: Rc::new(RefCell::new(AnimatedState::default())),
+            node_id: None,
+        }
+    }
+
+    /// How long moving to a new place takes. Defaults to 250ms.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Defaults to `Easing::CubicOut`.
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Shares position and transition with the `Animated` given the same key in the tree this
+    /// one replaces. Keys must be unique within a tree, e.g. the id of a list item.
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.state = ANIMATED_STATES.with(|states| {
+            let mut states = states.borrow_mut();
+            // Keys no view holds any more belong to views removed two rebuilds ago or earlier
+            states.retain(|_, state| Rc::strong_count(state) > 1);
+            states.entry(key.into()).or_default().clone()
+        });
+        self
+    }
+
+    /// The geometry to hand the child so it is drawn where it is shown rather than where it
+    /// was laid out, and the box to clip it to while its size changes.
+    fn placement(&self, geometry: Geometry) -> (Geometry, Option<Geometry>) {
+        let state = self.state.borrow();
+        let (Some(target), Some(shown)) = (state.target, state.shown) else { return (geometry, None) };
+        let parent = Geometry { x: geometry.x + shown.x - target.x, y: geometry.y + shown.y - target.y, ..geometry };
+        let resizing = shown.width != target.width || shown.height != target.height;
+        let clip = resizing.then_some(Geometry { x: geometry.x + shown.x, y: geometry.y + shown.y, ..shown });
+        (parent, clip)
+    }
+}
+
+/// The geometry `t` of the way from `from` to `to`.
+fn lerp_geometry(from: Geometry, to: Geometry, t: f32) -> Geometry {
+    let lerp = |a: f32, b: f32| a + (b - a) * t;
+    Geometry {
+        x: lerp(from.x, to.x),
+        y: lerp(from.y, to.y),
+        width: lerp(from.width, to.width),
+        height: lerp(from.height, to.height),
+    }
+}
+
+impl View for Animated {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<NodeId, LayoutError> {
+        let node = self.child.layout(ctx)?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+
+        {
+            // Relative to the parent, so scrolling it doesn't count as moving
+            let target = Geometry { x: my_geo.x - geometry.x, y: my_geo.y - geometry.y, ..my_geo };
+            let mut state = self.state.borrow_mut();
+            if state.target.is_some_and(|last| last != target) {
+                state.transition = Some((state.shown.unwrap_or(target), Instant::now()));
+            }
+            state.target = Some(target);
+            let shown = match state.transition {
+                Some((from, started)) => {
+                    let t = started.elapsed().as_secs_f32() / self.duration.as_secs_f32().max(f32::EPSILON);
+                    if t >= 1.0 {
+                        state.transition = None;
+                        target
+                    } else {
+                        ctx.request_redraw();
+                        lerp_geometry(from, target, self.easing.apply(t))
+                    }
+                }
+                None => target,
+            };
+            state.shown = Some(shown);
+        }
+
+        let (parent, clip) = self.placement(geometry);
+        let previous_clip = clip.map(|geometry| ctx.render_queue.set_clip(Some(Clip { geometry, radius: 0.0 })));
+        self.child.prepare(ctx, layout_ctx, parent);
+        if let Some(previous_clip) = previous_clip {
+            ctx.render_queue.set_clip(previous_clip);
+        }
+    }
+
+    fn collect_text_areas<'a>(&'a self, layout_ctx: &LayoutContext, geometry: Geometry, areas: &mut Vec<glyphon::TextArea<'a>>) {
+        let (parent, clip) = self.placement(geometry);
+        let start = areas.len();
+        self.child.collect_text_areas(layout_ctx, parent, areas);
+        // Text isn't drawn through the render queue, so clip it here
+        if let Some(clip) = clip {
+            for area in &mut areas[start..] {
+                let bounds = &mut area.bounds;
+                bounds.left = bounds.left.max(clip.x as i32);
+                bounds.top = bounds.top.max(clip.y as i32);
+                bounds.right = bounds.right.min((clip.x + clip.width) as i32);
+                bounds.bottom = bounds.bottom.min((clip.y + clip.height) as i32);
+            }
+        }
+    }
+
+    fn render<'rp>(&'rp self, ctx: &'rp RenderContext, render_pass: &mut wgpu::RenderPass<'rp>, geometry: Geometry) {
+        self.child.render(ctx, render_pass, self.placement(geometry).0);
+    }
+
+    fn handle_event(&mut self, event: &Event, layout_ctx: &LayoutContext, geometry: Geometry) {
+        // Where the child is drawn, so clicks land on what the user sees
+        let (parent, _) = self.placement(geometry);
+        self.child.handle_event(event, layout_ctx, parent);
+    }
+
+    fn on_init(&mut self) { self.child.on_init(); }
+    fn on_mount(&mut self) { self.child.on_mount(); }
+    fn on_update(&mut self) { self.child.on_update(); }
+    fn on_unmount(&mut self) { self.child.on_unmount(); }
+    fn on_resize(&mut self, width: f32, height: f32) { self.child.on_resize(width, height); }
+}
+
+const BADGE_SIZE: f32 = 18.0;
+const BADGE_DOT_SIZE: f32 = 10.0;
+const BADGE_COLOR: [f32; 4] = [0.898, 0.224, 0.208, 1.0];
+
+/// A small count or dot over the top-right corner of its child, e.g. unread notifications
+/// on an icon. Counts over 99 show as "99+"; a count of 0 shows a plain dot.
+pub struct Badge {
+    pub child: Box<dyn View>,
+    pub count: usize,
+    pub visible: bool,
+    pub color: [f32; 4],
+    text_view: Option<Text>,
+    node_id: Option<NodeId>,
+    badge_node: Option<NodeId>,
+}
+
+impl Badge {
+    pub fn new(child: impl View + 'static) -> Self {
+        Self {
+            child: Box::new(child),
+            count: 0,
+            visible: true,
+            color: BADGE_COLOR,
+            text_view: None,
+            node_id: None,
+            badge_node: None,
+        }
+    }
+
+    pub fn count(mut self, count: usize) -> Self {
+        self.count = count;
+        self
+    }
+
+    /// Hides the badge while keeping the child, e.g. once everything is read.
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = visible;
+        self
+    }
+
+    pub fn color(mut self, color: [f32; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    fn label(&self) -> Option<String> {
+        match self.count {
+            0 => None,
+            1..=99 => Some(self.count.to_string()),
+            _ => Some("99+".to_string()),
+        }
+    }
+}
+
+impl View for Badge {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<NodeId, LayoutError> {
+        let child = self.child.layout(ctx)?;
+        let mut children = vec![child];
+        self.text_view = self.label().filter(|_| self.visible).map(|label| Text::new(label).size(11.0).line_height(BADGE_SIZE));
+        self.badge_node = if self.visible {
+            let (size, offset) = if self.text_view.is_some() { (BADGE_SIZE, -6.0) } else { (BADGE_DOT_SIZE, -2.0) };
+            let text_nodes = match &mut self.text_view {
+                Some(text_view) => vec![text_view.layout(ctx)?],
+                None => Vec::new(),
+            };
+            let node = ctx.taffy.new_with_children(
+                Style {
+                    position: Position::Absolute,
+                    inset: taffy::Rect { left: auto(), right: length(offset), top: length(offset), bottom: auto() },
+                    min_size: Size { width: length(size), height: length(size) },
+                    size: Size { width: auto(), height: length(size) },
+                    padding: taffy::Rect { left: length(5.0), right: length(5.0), top: zero(), bottom: zero() },
+                    justify_content: Some(JustifyContent::Center),
+                    align_items: Some(AlignItems::Center),
+                    ..Default::default()
+                },
+                &text_nodes,
+            )?;
+            children.push(node);
+            Some(node)
+        } else {
+            None
+        };
+        let node = ctx.taffy.new_with_children(Style::default(), &children)?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        self.child.prepare(ctx, layout_ctx, my_geo);
+
+        let Some(badge_geo) = layout_ctx.geometry(self.badge_node, my_geo) else { return };
+        match &mut self.text_view {
+            Some(text_view) => {
+                ctx.render_queue.push_rounded_rect(badge_geo, self.color, badge_geo.height * 0.5);
+                text_view.prepare(ctx, layout_ctx, badge_geo);
+            }
+            None => ctx.render_queue.push_circle(badge_geo, self.color),
+        }
+    }
+
+    fn collect_text_areas<'a>(&'a self, layout_ctx: &LayoutContext, geometry: Geometry, areas: &mut Vec<glyphon::TextArea<'a>>) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        self.child.collect_text_areas(layout_ctx, my_geo, areas);
+        if let (Some(text_view), Some(badge_geo)) = (&self.text_view, layout_ctx.geometry(self.badge_node, my_geo)) {
+            text_view.collect_text_areas(layout_ctx, badge_geo, areas);
+        }
+    }
+
+    fn render<'rp>(&'rp self, ctx: &'rp RenderContext, render_pass: &mut wgpu::RenderPass<'rp>, geometry: Geometry) {
+        self.child.render(ctx, render_pass, geometry);
+    }
+
+    fn handle_event(&mut self, event: &Event, layout_ctx: &LayoutContext, geometry: Geometry) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        self.child.handle_event(event, layout_ctx, my_geo);
+    }
+
+    fn on_init(&mut self) { self.child.on_init(); }
+    fn on_mount(&mut self) { self.child.on_mount(); }
+    fn on_update(&mut self) { self.child.on_update(); }
+    fn on_unmount(&mut self) { self.child.on_unmount(); }
+    fn on_resize(&mut self, width: f32, height: f32) { self.child.on_resize(width, height); }
+}
+
+const TREE_INDENT: f32 = 16.0;
+const TREE_CHEVRON_WIDTH: f32 = 16.0;
+
+/// One node of a `TreeView`'s model.
+pub struct TreeNode<T> {
+    pub value: T,
+    pub children: Vec<TreeNode<T>>,
+    pub expanded: bool,
+    /// Children still to be fetched with the tree's loader on first expand.
+    lazy: bool,
+}
+
+impl<T> TreeNode<T> {
+    /// A node without children.
+    pub fn new(value: T) -> Self {
+        Self { value, children: Vec::new(), expanded: false, lazy: false }
+    }
+
+    /// A node whose children are fetched by `TreeView::load_children` the first time it is
+    /// expanded, e.g. a directory that hasn't been read yet.
+    pub fn lazy(value: T) -> Self {
+        Self { lazy: true, ..Self::new(value) }
+    }
+
+    pub fn children(mut self, children: Vec<TreeNode<T>>) -> Self {
+        self.children = children;
+        self
+    }
+
+    pub fn expanded(mut self, expanded: bool) -> Self {
+        self.expanded = expanded;
+        self
+    }
+
+    fn has_children(&self) -> bool {
+        self.lazy || !self.children.is_empty()
+    }
+}
+
+type ChildLoader<T> = Box<dyn Fn(&T) -> Vec<TreeNode<T>>>;
+type SelectHandler<T> = Box<dyn FnMut(&T)>;
+
+/// A visible row of a `TreeView`, rebuilt on every layout.
+struct TreeRow {
+    /// Child indices leading from the roots to the node.
+    path: Vec<usize>,
+    chevron: Text,
+    label: Text,
+    node: NodeId,
+    chevron_node: NodeId,
+}
+
+/// Hierarchical data as indented, expandable rows, e.g. a file explorer.
+///
+/// Clicking a row's chevron expands or collapses it, clicking the row selects it.
+/// Collapsed nodes' children aren't laid out at all.
+pub struct TreeView<T> {
+    pub roots: Vec<TreeNode<T>>,
+    render_label: Box<dyn Fn(&T) -> String>,
+    load_children: Option<ChildLoader<T>>,
+    on_select: Option<SelectHandler<T>>,
+    selected: Option<Vec<usize>>,
+    hovered: Option<usize>,
+    rows: Vec<TreeRow>,
+    node_id: Option<NodeId>,
+}
+
+impl<T: 'static> TreeView<T> {
+    pub fn new(roots: Vec<TreeNode<T>>, render_label: impl Fn(&T) -> String + 'static) -> Self {
+        Self {
+            roots,
+            render_label: Box::new(render_label),
+            load_children: None,
+            on_select: None,
+            selected: None,
+            hovered: None,
+            rows: Vec::new(),
+            node_id: None,
+        }
+    }
+
+    /// Fetches the children of `TreeNode::lazy` nodes when they are first expanded.
+    pub fn load_children(mut self, f: impl Fn(&T) -> Vec<TreeNode<T>> + 'static) -> Self {
+        self.load_children = Some(Box::new(f));
+        self
+    }
+
+    pub fn on_select(mut self, f: impl FnMut(&T) + 'static) -> Self {
+        self.on_select = Some(Box::new(f));
+        self
+    }
+
+    fn toggle(&mut self, path: &[usize]) {
+        let Some(node) = tree_node_mut(&mut self.roots, path) else { return };
+        node.expanded = !node.expanded;
+        if node.expanded && node.lazy {
+            node.lazy = false;
+            if let Some(load_children) = &self.load_children {
+                node.children = load_children(&node.value);
+            }
+        }
+        request_layout();
+    }
+
+    fn row_at(&self, layout_ctx: &LayoutContext, my_geo: Geometry, x: f32, y: f32) -> Option<usize> {
+        self.rows.iter().position(|row| {
+            layout_ctx.geometry(Some(row.node), my_geo).is_some_and(|geo| geo.contains(x, y))
+        })
+    }
+}
+
+/// Follows `path` (child indices from the roots) to a node.
+fn tree_node<'a, T>(roots: &'a [TreeNode<T>], path: &[usize]) -> Option<&'a TreeNode<T>> {
+    let (first, rest) = path.split_first()?;
+    rest.iter().try_fold(roots.get(*first)?, |node, &index| node.children.get(index))
+}
+
+fn tree_node_mut<'a, T>(roots: &'a mut [TreeNode<T>], path: &[usize]) -> Option<&'a mut TreeNode<T>> {
+    let (first, rest) = path.split_first()?;
+    rest.iter().try_fold(roots.get_mut(*first)?, |node, &index| node.children.get_mut(index))
+}
+
+/// Collects the visible rows below `nodes` in display order, as (path, depth, node).
+fn visible_tree_rows<'a, T>(nodes: &'a [TreeNode<T>], path: &mut Vec<usize>, out: &mut Vec<(Vec<usize>, usize, &'a TreeNode<T>)>) {
+    for (index, node) in nodes.iter().enumerate() {
+        path.push(index);
+        out.push((path.clone(), path.len() - 1, node));
+        if node.expanded {
+            visible_tree_rows(&node.children, path, out);
+        }
+        path.pop();
+    }
+}
+
+impl<T: 'static> View for TreeView<T> {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<NodeId, LayoutError> {
+        let mut visible = Vec::new();
+        visible_tree_rows(&self.roots, &mut Vec::new(), &mut visible);
+
+        self.rows.clear();
+        for (path, depth, node) in visible {
+            let chevron = match (node.has_children(), node.expanded) {
+                (false, _) => "",
+                (true, false) => "▸",
+                (true, true) => "▾",
+            };
+            let mut chevron = Text::new(chevron).relative_size(0.875);
+            let mut label = Text::new((self.render_label)(&node.value)).relative_size(0.875).ellipsis(true);
+            let chevron_node = {
+                let text_node = chevron.layout(ctx)?;
+                ctx.taffy.new_with_children(
+                    Style {
+                        size: Size { width: length(TREE_CHEVRON_WIDTH), height: auto() },
+                        flex_shrink: 0.0,
+                        justify_content: Some(JustifyContent::Center),
+                        ..Default::default()
+                    },
+                    &[text_node],
+                )?
+            };
+            let label_node = label.layout(ctx)?;
+            let node = ctx.taffy.new_with_children(
+                Style {
+                    flex_direction: FlexDirection::Row,
+                    align_items: Some(AlignItems::Center),
+                    gap: Size { width: length(4.0), height: zero() },
+                    padding: taffy::Rect {
+                        left: length(4.0 + depth as f32 * TREE_INDENT),
+                        right: length(8.0),
+                        top: length(2.0),
+                        bottom: length(2.0),
+                    },
+                    ..Default::default()
+                },
+                &[chevron_node, label_node],
+            )?;
+            self.rows.push(TreeRow { path, chevron, label, node, chevron_node });
+        }
+
+        let row_nodes: Vec<NodeId> = self.rows.iter().map(|row| row.node).collect();
+        let node = ctx.taffy.new_with_children(
+            Style {
+                flex_direction: FlexDirection::Column,
+                ..Default::default()
+            },
+            &row_nodes,
+        )?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        for (index, row) in self.rows.iter_mut().enumerate() {
+            let Some(row_geo) = layout_ctx.geometry(Some(row.node), my_geo) else { continue };
+            if self.selected.as_ref() == Some(&row.path) {
+                ctx.render_queue.push_rounded_rect(row_geo, [0.247, 0.317, 0.709, 0.6], 4.0);
+            } else if self.hovered == Some(index) {
+                ctx.render_queue.push_rounded_rect(row_geo, [1.0, 1.0, 1.0, 0.06], 4.0);
+            }
+            if let Some(chevron_geo) = layout_ctx.geometry(Some(row.chevron_node), row_geo) {
+                row.chevron.prepare(ctx, layout_ctx, chevron_geo);
+            }
+            row.label.prepare(ctx, layout_ctx, row_geo);
+        }
+    }
+
+    fn collect_text_areas<'a>(&'a self, layout_ctx: &LayoutContext, geometry: Geometry, areas: &mut Vec<glyphon::TextArea<'a>>) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        for row in &self.rows {
+            let Some(row_geo) = layout_ctx.geometry(Some(row.node), my_geo) else { continue };
+            if let Some(chevron_geo) = layout_ctx.geometry(Some(row.chevron_node), row_geo) {
+                row.chevron.collect_text_areas(layout_ctx, chevron_geo, areas);
+            }
+            row.label.collect_text_areas(layout_ctx, row_geo, areas);
+        }
+    }
+
+    fn render<'rp>(&'rp self, _: &'rp RenderContext, _: &mut wgpu::RenderPass<'rp>, _: Geometry) {}
+
+    fn handle_event(&mut self, event: &Event, layout_ctx: &LayoutContext, geometry: Geometry) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        match *event {
+            Event::MouseMove { x, y } => self.hovered = self.row_at(layout_ctx, my_geo, x, y),
+            Event::MouseClick { x, y } => {
+                let Some(index) = self.row_at(layout_ctx, my_geo, x, y) else { return };
+                let row = &self.rows[index];
+                let path = row.path.clone();
+                let on_chevron = layout_ctx.geometry(Some(row.node), my_geo)
+                    .and_then(|row_geo| layout_ctx.geometry(Some(row.chevron_node), row_geo))
+                    .is_some_and(|chevron_geo| chevron_geo.contains(x, y));
+                if on_chevron {
+                    self.toggle(&path);
+                } else {
+                    if let (Some(node), Some(on_select)) = (tree_node(&self.roots, &path), &mut self.on_select) {
+                        on_select(&node.value);
+                    }
+                    self.selected = Some(path);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Whether a `SelectionState` holds one item or any number of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SelectionMode {
+    #[default]
+    Single,
+    Multi,
+}
+
+/// Which items of a list are selected, plus the keyboard's active item, for list-like
+/// widgets such as `ListView`.
+///
+/// Clicks select one item; in `Multi` mode ctrl-click (cmd on macOS) toggles one and
+/// shift-click selects the range from the last clicked item. Up/down/home/end move the
+/// active item (shift extends the range in `Multi` mode) and space/enter select it.
+#[derive(Debug, Clone, Default)]
+pub struct SelectionState {
+    mode: SelectionMode,
+    len: usize,
+    selected: BTreeSet<usize>,
+    active: Option<usize>,
+    /// Where shift ranges start: the last item clicked or toggled without shift.
+    anchor: Option<usize>,
+}
+
+impl SelectionState {
+    pub fn new(mode: SelectionMode, len: usize) -> Self {
+        Self { mode, len, ..Default::default() }
+    }
+
+    pub fn mode(&self) -> SelectionMode {
+        self.mode
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Changes the number of items, dropping selected indices past the end.
+    pub fn set_len(&mut self, len: usize) {
+        self.len = len;
+        self.selected.retain(|&index| index < len);
+        self.active = self.active.filter(|&index| index < len);
+        self.anchor = self.anchor.filter(|&index| index < len);
+    }
+
+    /// Selected indices in ascending order.
+    pub fn selected(&self) -> Vec<usize> {
+        self.selected.iter().copied().collect()
+    }
+
+    pub fn is_selected(&self, index: usize) -> bool {
+        self.selected.contains(&index)
+    }
+
+    /// The item keyboard navigation moves from; shown with an outline while focused.
+    pub fn active(&self) -> Option<usize> {
+        self.active
+    }
+
+    /// Selects `index` alone and makes it the active item.
+    pub fn select(&mut self, index: usize) {
+        if index >= self.len {
+            return;
+        }
+        self.selected.clear();
+        self.selected.insert(index);
+        self.active = Some(index);
+        self.anchor = Some(index);
+    }
+
+    /// Adds `index` to the selection or removes it; in `Single` mode it replaces the selection.
+    pub fn toggle(&mut self, index: usize) {
+        if index >= self.len {
+            return;
+        }
+        if self.mode == SelectionMode::Single {
+            self.select(index);
+            return;
+        }
+        if !self.selected.remove(&index) {
+            self.selected.insert(index);
+        }
+        self.active = Some(index);
+        self.anchor = Some(index);
+    }
+
+    pub fn select_all(&mut self) {
+        if self.mode == SelectionMode::Multi {
+            self.selected = (0..self.len).collect();
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.selected.clear();
+    }
+
+    /// Selects from the anchor to `index`, adding to the selection with `extend`.
+    fn select_range(&mut self, index: usize, extend: bool) {
+        let anchor = self.anchor.unwrap_or(index);
+        if !extend {
+            self.selected.clear();
+        }
+        self.selected.extend(anchor.min(index)..=anchor.max(index));
+        self.active = Some(index);
+        self.anchor = Some(anchor);
+    }
+
+    /// Applies a click on `index` with the modifiers held at the time.
+    pub fn click(&mut self, index: usize, modifiers: Modifiers) {
+        if index >= self.len {
+            return;
+        }
+        let toggle = modifiers.ctrl || modifiers.logo;
+        match self.mode {
+            SelectionMode::Multi if modifiers.shift => self.select_range(index, toggle),
+            SelectionMode::Multi if toggle => self.toggle(index),
+            _ => self.select(index),
+        }
+    }
+
+    /// Applies a navigation or selection key, returning whether it was one.
+    pub fn handle_key(&mut self, key: &Key, modifiers: Modifiers) -> bool {
+        if self.len == 0 {
+            return false;
+        }
+        let last = self.len - 1;
+        let target = match key {
+            Key::ArrowUp => self.active.map_or(last, |index| index.saturating_sub(1)),
+            Key::ArrowDown => self.active.map_or(0, |index| (index + 1).min(last)),
+            Key::Home => 0,
+            Key::End => last,
+            Key::Enter | Key::Character(' ') => {
+                let Some(active) = self.active else { return false };
+                self.toggle(active);
+                return true;
+            }
+            Key::Character('a') if modifiers.ctrl || modifiers.logo => {
+                self.select_all();
+                return self.mode == SelectionMode::Multi;
+            }
+            _ => return false,
+        };
+        if modifiers.shift && self.mode == SelectionMode::Multi {
+            if self.anchor.is_none() {
+                self.anchor = self.active.or(Some(target));
+            }
+            self.select_range(target, false);
+        } else {
+            self.active = Some(target);
+        }
+        true
+    }
+}
+
+type SelectionHandler = Box<dyn FnMut(&[usize])>;
+
+/// A visible row of a `ListView`.
+struct ListRow {
+    label: Text,
+    node: NodeId,
+}
+
+/// A flat list of items with single or multiple selection (see `SelectionState`).
+///
+/// Clicking focuses the list; while focused it takes the keyboard, and the active row is
+/// outlined.
+pub struct ListView<T> {
+    pub items: Vec<T>,
+    selection: SelectionState,
+    render_label: Box<dyn Fn(&T) -> String>,
+    on_selection_change: Option<SelectionHandler>,
+    interaction: Interaction,
+    /// Modifiers held at the last `ModifiersChanged`, for shift- and ctrl-clicks.
+    modifiers: Modifiers,
+    hovered: Option<usize>,
+    rows: Vec<ListRow>,
+    node_id: Option<NodeId>,
+}
+
+impl<T: 'static> ListView<T> {
+    pub fn new(items: Vec<T>, render_label: impl Fn(&T) -> String + 'static) -> Self {
+        Self {
+            selection: SelectionState::new(SelectionMode::Single, items.len()),
+            items,
+            render_label: Box::new(render_label),
+            on_selection_change: None,
+            interaction: Interaction::default(),
+            modifiers: Modifiers::default(),
+            hovered: None,
+            rows: Vec::new(),
+            node_id: None,
+        }
+    }
+
+    /// Allows selecting any number of items with ctrl-click, shift-click and shift+arrows.
+    pub fn multi_select(mut self, multi: bool) -> Self {
+        let mode = if multi { SelectionMode::Multi } else { SelectionMode::Single };
+        self.selection = SelectionState::new(mode, self.items.len());
+        self
+    }
+
+    /// Called with the selected indices, in ascending order, whenever they change.
+    pub fn on_selection_change(mut self, f: impl FnMut(&[usize]) + 'static) -> Self {
+        self.on_selection_change = Some(Box::new(f));
+        self
+    }
+
+    pub fn selection(&self) -> &SelectionState {
+        &self.selection
+    }
+
+    pub fn selected_items(&self) -> Vec<&T> {
+        self.selection.selected().into_iter().filter_map(|index| self.items.get(index)).collect()
+    }
+
+    fn row_at(&self, layout_ctx: &LayoutContext, my_geo: Geometry, x: f32, y: f32) -> Option<usize> {
+        self.rows.iter().position(|row| {
+            layout_ctx.geometry(Some(row.node), my_geo).is_some_and(|geo| geo.contains(x, y))
+        })
+    }
+
+    /// Runs `f` on the selection and reports the new selection if it changed.
+    fn update_selection(&mut self, f: impl FnOnce(&mut SelectionState)) {
+        let before = self.selection.selected();
+        f(&mut self.selection);
+        let after = self.selection.selected();
+        if after != before && let Some(on_selection_change) = &mut self.on_selection_change {
+            on_selection_change(&after);
+        }
+    }
+}
+
+impl<T: 'static> View for ListView<T> {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<NodeId, LayoutError> {
+        self.selection.set_len(self.items.len());
+        self.rows.clear();
+        for item in &self.items {
+            let mut label = Text::new((self.render_label)(item)).relative_size(0.875).ellipsis(true);
+            let label_node = label.layout(ctx)?;
+            let node = ctx.taffy.new_with_children(
+                Style {
+                    padding: taffy::Rect { left: length(8.0), right: length(8.0), top: length(2.0), bottom: length(2.0) },
+                    ..Default::default()
+                },
+                &[label_node],
+            )?;
+            self.rows.push(ListRow { label, node });
+        }
+
+        let row_nodes: Vec<NodeId> = self.rows.iter().map(|row| row.node).collect();
+        let node = ctx.taffy.new_with_children(
+            Style {
+                flex_direction: FlexDirection::Column,
+                ..Default::default()
+            },
+            &row_nodes,
+        )?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        for (index, row) in self.rows.iter_mut().enumerate() {
+            let Some(row_geo) = layout_ctx.geometry(Some(row.node), my_geo) else { continue };
+            if self.selection.is_selected(index) {
+                ctx.render_queue.push_rounded_rect(row_geo, [0.247, 0.317, 0.709, 0.6], 4.0);
+            } else if self.hovered == Some(index) {
+                ctx.render_queue.push_rounded_rect(row_geo, [1.0, 1.0, 1.0, 0.06], 4.0);
+            }
+            if self.interaction.focused && self.selection.active() == Some(index) {
+                render_outline_helper(ctx, row_geo, [1.0, 1.0, 1.0, 0.5]);
+            }
+            row.label.prepare(ctx, layout_ctx, row_geo);
+        }
+    }
+
+    fn collect_text_areas<'a>(&'a self, layout_ctx: &LayoutContext, geometry: Geometry, areas: &mut Vec<glyphon::TextArea<'a>>) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        for row in &self.rows {
+            let Some(row_geo) = layout_ctx.geometry(Some(row.node), my_geo) else { continue };
+            row.label.collect_text_areas(layout_ctx, row_geo, areas);
+        }
+    }
+
+    fn render<'rp>(&'rp self, _: &'rp RenderContext, _: &mut wgpu::RenderPass<'rp>, _: Geometry) {}
+
+    fn handle_event(&mut self, event: &Event, layout_ctx: &LayoutContext, geometry: Geometry) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        self.interaction.handle_event(event, my_geo);
+        match event {
+            Event::ModifiersChanged(modifiers) => self.modifiers = *modifiers,
+            Event::MouseMove { x, y } => self.hovered = self.row_at(layout_ctx, my_geo, *x, *y),
+            Event::MouseClick { x, y } => {
+                let Some(index) = self.row_at(layout_ctx, my_geo, *x, *y) else { return };
+                let modifiers = self.modifiers;
+                self.update_selection(|selection| selection.click(index, modifiers));
+            }
+            Event::KeyDown { key, modifiers } if self.interaction.focused => {
+                self.update_selection(|selection| { selection.handle_key(key, *modifiers); });
+            }
+            _ => {}
+        }
+    }
+}
+
+const SPLIT_DIVIDER: f32 = 6.0;
+
+/// Two children side by side (or stacked, with `vertical`) separated by a divider that can
+/// be dragged to share the space differently. Each pane keeps at least `min_size` pixels.
+pub struct SplitPane {
+    pub first: Box<dyn View>,
+    pub second: Box<dyn View>,
+    /// Share of the space (excluding the divider) given to the first child, 0 to 1.
+    pub ratio: f32,
+    pub min_size: f32,
+    vertical: bool,
+    dragging: bool,
+    divider_hovered: bool,
+    node_id: Option<NodeId>,
+    first_node: Option<NodeId>,
+    divider_node: Option<NodeId>,
+    second_node: Option<NodeId>,
+}
+
+impl SplitPane {
+    pub fn new(first: impl View + 'static, second: impl View + 'static) -> Self {
+        Self {
+            first: Box::new(first),
+            second: Box::new(second),
+            ratio: 0.5,
+            min_size: 48.0,
+            vertical: false,
+            dragging: false,
+            divider_hovered: false,
+            node_id: None,
+            first_node: None,
+            divider_node: None,
+            second_node: None,
+        }
+    }
+
+    /// Stacks the children top to bottom with a horizontal divider.
+    pub fn vertical(mut self) -> Self {
+        self.vertical = true;
+        self
+    }
+
+    pub fn ratio(mut self, ratio: f32) -> Self {
+        self.ratio = ratio.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn min_size(mut self, min_size: f32) -> Self {
+        self.min_size = min_size.max(0.0);
+        self
+    }
+
+    fn pane_style(&self, grow: f32) -> Style {
+        let min = if self.vertical {
+            Size { width: auto(), height: length(self.min_size) }
+        } else {
+            Size { width: length(self.min_size), height: auto() }
+        };
+        Style {
+            // Children stretch across the pane
+            flex_direction: FlexDirection::Column,
+            flex_grow: grow,
+            flex_shrink: 1.0,
+            flex_basis: length(0.0),
+            min_size: min,
+            overflow: taffy::Point { x: taffy::Overflow::Hidden, y: taffy::Overflow::Hidden },
+            ..Default::default()
+        }
+    }
+
+    /// Moves the divider under the pointer, keeping both panes at least `min_size`.
+    fn drag_to(&mut self, my_geo: Geometry, x: f32, y: f32) {
+        let (local_x, local_y) = my_geo.to_local(x, y);
+        let (extent, pointer) = if self.vertical { (my_geo.height, local_y) } else { (my_geo.width, local_x) };
+        let available = extent - SPLIT_DIVIDER;
+        if available <= 0.0 {
+            return;
+        }
+        let max = (available - self.min_size).max(self.min_size);
+        let first = (pointer - SPLIT_DIVIDER / 2.0).clamp(self.min_size.min(max), max);
+        let ratio = first / available;
+        if ratio != self.ratio {
+            self.ratio = ratio;
+            request_layout();
+        }
+    }
+}
+
+impl View for SplitPane {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<NodeId, LayoutError> {
+        let first_child = self.first.layout(ctx)?;
+        let second_child = self.second.layout(ctx)?;
+        let first = ctx.taffy.new_with_children(self.pane_style(self.ratio), &[first_child])?;
+        let second = ctx.taffy.new_with_children(self.pane_style(1.0 - self.ratio), &[second_child])?;
+        let divider_size = if self.vertical {
+            Size { width: auto(), height: length(SPLIT_DIVIDER) }
+        } else {
+            Size { width: length(SPLIT_DIVIDER), height: auto() }
+        };
+        let divider = ctx.taffy.new_leaf(Style { size: divider_size, flex_shrink: 0.0, ..Default::default() })?;
+        let node = ctx.taffy.new_with_children(
+            Style {
+                flex_direction: if self.vertical { FlexDirection::Column } else { FlexDirection::Row },
+                flex_grow: 1.0,
+                size: Size { width: percent(1.0), height: percent(1.0) },
+                ..Default::default()
+            },
+            &[first, divider, second],
+        )?;
+        self.node_id = Some(node);
+        self.first_node = Some(first);
+        self.divider_node = Some(divider);
+        self.second_node = Some(second);
+        Ok(node)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        if let Some(first_geo) = layout_ctx.geometry(self.first_node, my_geo) {
+            self.first.prepare(ctx, layout_ctx, first_geo);
+        }
+        if let Some(second_geo) = layout_ctx.geometry(self.second_node, my_geo) {
+            self.second.prepare(ctx, layout_ctx, second_geo);
+        }
+        if let Some(divider_geo) = layout_ctx.geometry(self.divider_node, my_geo) {
+            let color = if self.dragging {
+                [0.247, 0.317, 0.709, 1.0]
+            } else if self.divider_hovered {
+                [0.35, 0.36, 0.42, 1.0]
+            } else {
+                [0.2, 0.2, 0.24, 1.0]
+            };
+            ctx.render_queue.push_rect(divider_geo, color);
+        }
+    }
+
+    fn collect_text_areas<'a>(&'a self, layout_ctx: &LayoutContext, geometry: Geometry, areas: &mut Vec<glyphon::TextArea<'a>>) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        if let Some(first_geo) = layout_ctx.geometry(self.first_node, my_geo) {
+            self.first.collect_text_areas(layout_ctx, first_geo, areas);
+        }
+        if let Some(second_geo) = layout_ctx.geometry(self.second_node, my_geo) {
+            self.second.collect_text_areas(layout_ctx, second_geo, areas);
+        }
+    }
+
+    fn render<'rp>(&'rp self, ctx: &'rp RenderContext, render_pass: &mut wgpu::RenderPass<'rp>, geometry: Geometry) {
+        self.first.render(ctx, render_pass, geometry);
+        self.second.render(ctx, render_pass, geometry);
+    }
+
+    fn handle_event(&mut self, event: &Event, layout_ctx: &LayoutContext, geometry: Geometry) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        let divider_geo = layout_ctx.geometry(self.divider_node, my_geo);
+        match *event {
+            Event::MouseDown { x, y } if divider_geo.is_some_and(|geo| geo.contains(x, y)) => {
+                self.dragging = true;
+                return;
+            }
+            Event::MouseMove { x, y } => {
+                self.divider_hovered = divider_geo.is_some_and(|geo| geo.contains(x, y));
+                if self.dragging {
+                    self.drag_to(my_geo, x, y);
+                    return;
+                }
+            }
+            Event::MouseUp { .. } if self.dragging => {
+                self.dragging = false;
+                return;
+            }
+            _ => {}
+        }
+        if let Some(first_geo) = layout_ctx.geometry(self.first_node, my_geo) {
+            self.first.handle_event(event, layout_ctx, first_geo);
+        }
+        if let Some(second_geo) = layout_ctx.geometry(self.second_node, my_geo) {
+            self.second.handle_event(event, layout_ctx, second_geo);
+        }
+    }
+
+    fn on_init(&mut self) {
+        self.first.on_init();
+        self.second.on_init();
+    }
+
+    fn on_mount(&mut self) {
+        self.first.on_mount();
+        self.second.on_mount();
+    }
+
+    fn on_update(&mut self) {
+        self.first.on_update();
+        self.second.on_update();
+    }
+
+    fn on_unmount(&mut self) {
+        self.first.on_unmount();
+        self.second.on_unmount();
+    }
+
+    fn on_resize(&mut self, width: f32, height: f32) {
+        self.first.on_resize(width, height);
+        self.second.on_resize(width, height);
+    }
+}
+
+/// Accepts files dragged in from the OS. Highlights its child while a file hovers over it
+/// and calls `on_drop` with every file dropped onto it in one gesture.
+pub struct DropZone {
+    pub child: Box<dyn View>,
+    pub highlight: [f32; 4],
+    pub radius: f32,
+    hovered: bool,
+    on_drop: Box<dyn FnMut(Vec<PathBuf>)>,
+    node_id: Option<NodeId>,
+}
+
+impl DropZone {
+    pub fn new(child: impl View + 'static, on_drop: impl FnMut(Vec<PathBuf>) + 'static) -> Self {
+        Self {
+            child: Box::new(child),
+            highlight: [0.247, 0.317, 0.709, 0.35],
+            radius: 8.0,
+            hovered: false,
+            on_drop: Box::new(on_drop),
+            node_id: None,
+        }
+    }
+
+    /// Overlay color drawn over the child while a file hovers.
+    pub fn highlight(mut self, color: [f32; 4]) -> Self {
+        self.highlight = color;
+        self
+    }
+
+    pub fn radius(mut self, radius: f32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    pub fn is_hovered(&self) -> bool {
+        self.hovered
+    }
+}
+
+impl View for DropZone {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<NodeId, LayoutError> {
+        let node = self.child.layout(ctx)?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        self.child.prepare(ctx, layout_ctx, geometry);
+        if self.hovered && let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) {
+            ctx.render_queue.push_rounded_rect(my_geo, self.highlight, self.radius);
+        }
+    }
+
+    fn collect_text_areas<'a>(&'a self, layout_ctx: &LayoutContext, geometry: Geometry, areas: &mut Vec<glyphon::TextArea<'a>>) {
+        self.child.collect_text_areas(layout_ctx, geometry, areas);
+    }
+
+    fn render<'rp>(&'rp self, ctx: &'rp RenderContext, render_pass: &mut wgpu::RenderPass<'rp>, geometry: Geometry) {
+        self.child.render(ctx, render_pass, geometry);
+    }
+
+    fn handle_event(&mut self, event: &Event, layout_ctx: &LayoutContext, geometry: Geometry) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        match event {
+            Event::FileHovered { x, y } => self.hovered = my_geo.contains(*x, *y),
+            Event::FileHoverCancelled => self.hovered = false,
+            Event::FileDropped { paths, x, y } => {
+                self.hovered = false;
+                if my_geo.contains(*x, *y) {
+                    (self.on_drop)(paths.clone());
+                }
+            }
+            _ => {}
+        }
+
+        self.child.handle_event(event, layout_ctx, geometry);
+    }
+
+    fn on_init(&mut self) { self.child.on_init(); }
+    fn on_mount(&mut self) { self.child.on_mount(); }
+    fn on_update(&mut self) { self.child.on_update(); }
+    fn on_unmount(&mut self) { self.child.on_unmount(); }
+    fn on_resize(&mut self, width: f32, height: f32) { self.child.on_resize(width, height); }
+}
+
+/// Window width classes used by `Responsive`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Breakpoint {
+    Mobile,
+    Tablet,
+    Desktop,
+}
+
+/// Shows a different subtree depending on the window width: `mobile` below `tablet_min`
+/// (600 by default), `tablet` below `desktop_min` (1024), `desktop` above. Missing
+/// variants fall back to the next smaller one.
+///
+/// The subtree is picked on every layout, i.e. at startup and on resize. The inactive
+/// subtrees are kept (with their state) and get `on_unmount`/`on_mount` when swapped.
+pub struct Responsive {
+    /// Private so the active subtree can't be removed from under it; `breakpoint_for` only
+    /// picks variants that are set.
+    mobile: Box<dyn View>,
+    tablet: Option<Box<dyn View>>,
+    desktop: Option<Box<dyn View>>,
+    pub tablet_min: f32,
+    pub desktop_min: f32,
+    active: Breakpoint,
+    mounted: bool,
+    node_id: Option<NodeId>,
+}
+
+impl Responsive {
+    pub fn new(mobile: impl View + 'static) -> Self {
+        Self {
+            mobile: Box::new(mobile),
+            tablet: None,
+            desktop: None,
+            tablet_min: 600.0,
+            desktop_min: 1024.0,
+            active: Breakpoint::Mobile,
+            mounted: false,
+            node_id: None,
+        }
+    }
+
+    pub fn tablet(mut self, tablet: impl View + 'static) -> Self {
+        self.tablet = Some(Box::new(tablet));
+        self
+    }
+
+    pub fn desktop(mut self, desktop: impl View + 'static) -> Self {
+        self.desktop = Some(Box::new(desktop));
+        self
+    }
+
+    /// Minimum window widths for the tablet and desktop layouts.
+    pub fn breakpoints(mut self, tablet_min: f32, desktop_min: f32) -> Self {
+        self.tablet_min = tablet_min;
+        self.desktop_min = desktop_min;
+        self
+    }
+
+    /// The breakpoint picked at the last layout.
+    pub fn breakpoint(&self) -> Breakpoint {
+        self.active
+    }
+
+    fn breakpoint_for(&self, width: f32) -> Breakpoint {
+        if width >= self.desktop_min && self.desktop.is_some() {
+            Breakpoint::Desktop
+        } else if width >= self.tablet_min && self.tablet.is_some() {
+            Breakpoint::Tablet
+        } else {
+            Breakpoint::Mobile
+        }
+    }
+
+    fn child(&self) -> &dyn View {
+        match self.active {
+            Breakpoint::Desktop => self.desktop.as_deref().unwrap(),
+            Breakpoint::Tablet => self.tablet.as_deref().unwrap(),
+            Breakpoint::Mobile => self.mobile.as_ref(),
+        }
+    }
+
+    fn child_mut(&mut self) -> &mut dyn View {
+        match self.active {
+            Breakpoint::Desktop => self.desktop.as_deref_mut().unwrap(),
+            Breakpoint::Tablet => self.tablet.as_deref_mut().unwrap(),
+            Breakpoint::Mobile => self.mobile.as_mut(),
+        }
+    }
+}
+
+impl View for Responsive {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<NodeId, LayoutError> {
+        let breakpoint = self.breakpoint_for(ctx.window_size.width);
+        if breakpoint != self.active {
+            if self.mounted {
+                self.child_mut().on_unmount();
+            }
+            self.active = breakpoint;
+            if self.mounted {
+                self.child_mut().on_mount();
+            }
+        }
+        let node = self.child_mut().layout(ctx)?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        self.child_mut().prepare(ctx, layout_ctx, geometry);
+    }
+
+    fn collect_text_areas<'a>(&'a self, layout_ctx: &LayoutContext, geometry: Geometry, areas: &mut Vec<glyphon::TextArea<'a>>) {
+        self.child().collect_text_areas(layout_ctx, geometry, areas);
+    }
+
+    fn render<'rp>(&'rp self, ctx: &'rp RenderContext, render_pass: &mut wgpu::RenderPass<'rp>, geometry: Geometry) {
+        self.child().render(ctx, render_pass, geometry);
+    }
+
+    fn handle_event(&mut self, event: &Event, layout_ctx: &LayoutContext, geometry: Geometry) {
+        self.child_mut().handle_event(event, layout_ctx, geometry);
+    }
+
+    fn on_init(&mut self) {
+        self.mobile.on_init();
+        self.tablet.iter_mut().chain(self.desktop.iter_mut()).for_each(|child| child.on_init());
+    }
+    fn on_mount(&mut self) {
+        self.mounted = true;
+        self.child_mut().on_mount();
+    }
+    fn on_update(&mut self) { self.child_mut().on_update(); }
+    fn on_unmount(&mut self) {
+        self.mounted = false;
+        self.child_mut().on_unmount();
+    }
+    fn on_resize(&mut self, width: f32, height: f32) { self.child_mut().on_resize(width, height); }
+}
+
+#[derive(Debug)]
+struct NavigatorState {
+    /// Routes from the first screen to the current one, each with a unique id so that
+    /// visiting a route again builds a new screen.
+    history: Vec<(u64, String)>,
+    next_id: u64,
+}
+
+/// The history of a `Router`, for navigating from handlers. Clones share the same history.
+#[derive(Debug, Clone)]
+pub struct Navigator {
+    state: Rc<RefCell<NavigatorState>>,
+}
+
+impl Navigator {
+    /// A history holding only `initial`, the first screen shown.
+    pub fn new(initial: impl Into<String>) -> Self {
+        Self { state: Rc::new(RefCell::new(NavigatorState { history: vec![(0, initial.into())], next_id: 1 })) }
+    }
+
+    /// Shows `route` on top of the current screen, which `back` returns to.
+    pub fn navigate(&self, route: impl Into<String>) {
+        let mut state = self.state.borrow_mut();
+        let id = state.next_id;
+        state.next_id += 1;
+        state.history.push((id, route.into()));
+        Self::changed();
+    }
+
+    /// Returns to the previous screen. Returns false, doing nothing, on the first screen.
+    pub fn back(&self) -> bool {
+        let mut state = self.state.borrow_mut();
+        if state.history.len() < 2 {
+            return false;
+        }
+        state.history.pop();
+        Self::changed();
+        true
+    }
+
+    /// The route of the current screen.
+    pub fn current(&self) -> String {
+        self.state.borrow().history.last().map(|(_, route)| route.clone()).unwrap_or_default()
+    }
+
+    pub fn can_go_back(&self) -> bool {
+        self.state.borrow().history.len() > 1
+    }
+
+    /// Number of screens in the history, including the current one.
+    pub fn depth(&self) -> usize {
+        self.state.borrow().history.len()
+    }
+
+    fn changed() {
+        request_layout();
+        Commands::new().request_redraw();
+    }
+}
+
+type RouteBuilder = Box<dyn Fn() -> Box<dyn View>>;
+
+/// Shows one of several named screens, switched with its `Navigator`'s `navigate` and
+/// `back`. Each route maps to a closure that builds its screen.
+///
+/// Screens under the current one are kept with their state, unmounted, so going back
+/// shows the previous screen as it was left. Screens popped off the history are dropped.
+///
+/// ```
+/// use noxkit::prelude::*;
+/// use noxkit::testing::TestHarness;
+///
+/// let navigator = Navigator::new("home");
+/// let router = Router::new(navigator.clone())
+///     .route("home", || Box::new(Text::new("Home").debug_name("home")))
+///     .route("settings", || Box::new(Text::new("Settings").debug_name("settings")));
+/// let mut harness = TestHarness::new(Box::new(router), 400.0, 300.0).unwrap();
+///
+/// navigator.navigate("settings");
+/// harness.move_to(0.0, 0.0);
+/// assert!(harness.find_named("settings").is_some());
+///
+/// assert!(navigator.back());
+/// harness.move_to(0.0, 0.0);
+/// assert!(harness.find_named("home").is_some());
+/// assert!(harness.find_named("settings").is_none());
+/// assert!(!navigator.back());
+/// ```
+pub struct Router {
+    routes: std::collections::HashMap<String, RouteBuilder>,
+    navigator: Navigator,
+    /// Built screens, matching the navigator's history up to the last layout.
+    screens: Vec<(u64, Box<dyn View>)>,
+    initialized: bool,
+    mounted: bool,
+    node_id: Option<NodeId>,
+}
+
+impl Router {
+    pub fn new(navigator: Navigator) -> Self {
+        Self {
+            routes: std::collections::HashMap::new(),
+            navigator,
+            screens: Vec::new(),
+            initialized: false,
+            mounted: false,
+            node_id: None,
+        }
+    }
+
+    /// Builds the screen for `name` with `build` whenever it is navigated to.
+    pub fn route(mut self, name: impl Into<String>, build: impl Fn() -> Box<dyn View> + 'static) -> Self {
+        self.routes.insert(name.into(), Box::new(build));
+        self
+    }
+
+    /// The navigator driving this router.
+    pub fn navigator(&self) -> Navigator {
+        self.navigator.clone()
+    }
+
+    fn build(&self, route: &str) -> Box<dyn View> {
+        match self.routes.get(route) {
+            Some(build) => build(),
+            None => {
+                tracing::warn!("No route named {:?}", route);
+                Box::new(Column::new(Vec::new()))
+            }
+        }
+    }
+
+    /// Brings the built screens in line with the navigator's history, unmounting the
+    /// screen that stops being current and mounting the one that becomes current.
+    fn sync(&mut self) {
+        let history = self.navigator.state.borrow().history.clone();
+        let kept = self.screens.iter().zip(&history)
+            .take_while(|((built, _), (id, _))| built == id)
+            .count();
+        if kept == self.screens.len() && kept == history.len() {
+            return;
+        }
+
+        if self.mounted && let Some((_, screen)) = self.screens.last_mut() {
+            screen.on_unmount();
+        }
+        self.screens.truncate(kept);
+        for (id, route) in &history[kept..] {
+            let mut screen = self.build(route);
+            if self.initialized {
+                screen.on_init();
+            }
+            self.screens.push((*id, screen));
+        }
+        if self.mounted && let Some((_, screen)) = self.screens.last_mut() {
+            screen.on_mount();
+        }
+    }
+
+    fn screen(&self) -> Option<&dyn View> {
+        self.screens.last().map(|(_, screen)| screen.as_ref())
+    }
+
+    fn screen_mut(&mut self) -> Option<&mut Box<dyn View>> {
+        self.screens.last_mut().map(|(_, screen)| screen)
+    }
+}
+
+impl View for Router {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<NodeId, LayoutError> {
+        self.sync();
+        let node = match self.screen_mut() {
+            Some(screen) => screen.layout(ctx)?,
+            None => ctx.taffy.new_leaf(Style::default())?,
+        };
+        self.node_id = Some(node);
+        Ok(node)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        if let Some(screen) = self.screen_mut() {
+            screen.prepare(ctx, layout_ctx, geometry);
+        }
+    }
+
+    fn collect_text_areas<'a>(&'a self, layout_ctx: &LayoutContext, geometry: Geometry, areas: &mut Vec<glyphon::TextArea<'a>>) {
+        if let Some(screen) = self.screen() {
+            screen.collect_text_areas(layout_ctx, geometry, areas);
+        }
+    }
+
+    fn render<'rp>(&'rp self, ctx: &'rp RenderContext, render_pass: &mut wgpu::RenderPass<'rp>, geometry: Geometry) {
+        if let Some(screen) = self.screen() {
+            screen.render(ctx, render_pass, geometry);
+        }
+    }
+
+    fn handle_event(&mut self, event: &Event, layout_ctx: &LayoutContext, geometry: Geometry) {
+        if let Some(screen) = self.screen_mut() {
+            screen.handle_event(event, layout_ctx, geometry);
+        }
+    }
+
+    fn on_init(&mut self) {
+        self.sync();
+        self.initialized = true;
+        self.screens.iter_mut().for_each(|(_, screen)| screen.on_init());
+    }
+    fn on_mount(&mut self) {
+        self.mounted = true;
+        if let Some(screen) = self.screen_mut() {
+            screen.on_mount();
+        }
+    }
+    fn on_update(&mut self) {
+        if let Some(screen) = self.screen_mut() {
+            screen.on_update();
+        }
+    }
+    fn on_unmount(&mut self) {
+        self.mounted = false;
+        if let Some(screen) = self.screen_mut() {
+            screen.on_unmount();
+        }
+    }
+    fn on_resize(&mut self, width: f32, height: f32) {
+        if let Some(screen) = self.screen_mut() {
+            screen.on_resize(width, height);
+        }
+    }
+}
+
+/// Keeps its children in a box of a fixed width:height ratio (e.g. 16:9 for video), as
+/// large as fits in the space it is given and centered in it. The leftover space is
+/// letterboxed above and below or pillarboxed left and right, filled with `color` if set.
+///
+/// Children fill the box; more than one share it top to bottom.
+pub struct AspectRatio {
+    pub ratio: f32,
+    pub children: Vec<Box<dyn View>>,
+    /// Fill for the bars around the box.
+    pub color: Option<[f32; 4]>,
+    pub z_index: Option<i32>,
+    node_id: Option<NodeId>,
+    frame_node: Option<NodeId>,
+}
+
+impl AspectRatio {
+    /// `ratio` is width over height, e.g. `16.0 / 9.0`.
+    pub fn new(ratio: f32) -> Self {
+        Self {
+            ratio,
+            children: Vec::new(),
+            color: None,
+            z_index: None,
+            node_id: None,
+            frame_node: None,
+        }
+    }
+
+    /// Adds `child` after the existing children.
+    pub fn child(mut self, child: impl IntoViews) -> Self {
+        self.children.extend(child.into_views());
+        self
+    }
+
+    /// Adds every view in `children` after the existing ones.
+    pub fn children<V: IntoViews>(mut self, children: impl IntoIterator<Item = V>) -> Self {
+        self.children.extend(children.into_iter().flat_map(IntoViews::into_views));
+        self
+    }
+
+    pub fn color(mut self, color: [f32; 4]) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn z_index(mut self, z_index: i32) -> Self {
+        self.z_index = Some(z_index);
+        self
+    }
+}
+
+impl View for AspectRatio {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<NodeId, LayoutError> {
+        let child_nodes: Vec<NodeId> = self.children.iter_mut()
+            .map(|child| child.layout(ctx))
+            .collect::<Result<_, _>>()?;
+        // Whatever size the children asked for, they take the frame's
+        for &node in &child_nodes {
+            let style = Style {
+                size: Size::auto(),
+                min_size: Size::zero(),
+                flex_grow: 1.0,
+                flex_basis: length(0.0),
+                ..ctx.taffy.style(node)?.clone()
+            };
+            ctx.taffy.set_style(node, style)?;
+        }
+
+        let frame = ctx.taffy.new_with_children(
+            Style {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                flex_shrink: 0.0,
+                aspect_ratio: Some(self.ratio),
+                size: Size { width: Dimension::Percent(1.0), height: Dimension::Auto },
+                ..Default::default()
+            },
+            &child_nodes,
+        )?;
+        let node = ctx.taffy.new_with_children(
+            Style {
+                display: Display::Flex,
+                justify_content: Some(JustifyContent::Center),
+                align_items: Some(AlignItems::Center),
+                flex_grow: 1.0,
+                size: Size { width: Dimension::Percent(1.0), height: Dimension::Auto },
+                min_size: Size::zero(),
+                ..Default::default()
+            },
+            &[frame],
+        )?;
+        ctx.fit_aspect_ratio(node, frame, self.ratio);
+        self.frame_node = Some(frame);
+        self.node_id = Some(node);
+        Ok(node)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        let Some(frame_geo) = layout_ctx.geometry(self.frame_node, my_geo) else { return };
+
+        with_z_index(ctx, self.z_index, |ctx| {
+            if let Some(color) = self.color {
+                ctx.render_queue.push_rect(my_geo, color);
+            }
+            for child in self.children.iter_mut() {
+                child.prepare(ctx, layout_ctx, frame_geo);
+            }
+
+            if ctx.debug {
+                render_outline_helper(ctx, frame_geo, [0.0, 1.0, 1.0, 1.0]);
+            }
+        });
+    }
+
+    fn collect_text_areas<'a>(&'a self, layout_ctx: &LayoutContext, geometry: Geometry, areas: &mut Vec<glyphon::TextArea<'a>>) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        let Some(frame_geo) = layout_ctx.geometry(self.frame_node, my_geo) else { return };
+        for child in &self.children {
+            child.collect_text_areas(layout_ctx, frame_geo, areas);
+        }
+    }
+
+    fn render<'rp>(&'rp self, ctx: &'rp RenderContext, render_pass: &mut wgpu::RenderPass<'rp>, geometry: Geometry) {
+        for child in &self.children {
+            child.render(ctx, render_pass, geometry);
+        }
+    }
+
+    fn handle_event(&mut self, event: &Event, layout_ctx: &LayoutContext, geometry: Geometry) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        let Some(frame_geo) = layout_ctx.geometry(self.frame_node, my_geo) else { return };
+        for child in self.children.iter_mut() {
+            child.handle_event(event, layout_ctx, frame_geo);
+        }
+    }
+
+    fn on_init(&mut self) { self.children.iter_mut().for_each(|child| child.on_init()); }
+    fn on_mount(&mut self) { self.children.iter_mut().for_each(|child| child.on_mount()); }
+    fn on_update(&mut self) { self.children.iter_mut().for_each(|child| child.on_update()); }
+    fn on_unmount(&mut self) { self.children.iter_mut().for_each(|child| child.on_unmount()); }
+    fn on_resize(&mut self, width: f32, height: f32) { self.children.iter_mut().for_each(|child| child.on_resize(width, height)); }
+
+    fn drawn_z_index(&self) -> Option<i32> {
+        self.z_index
+    }
+}
+
+const SCROLL_ANIMATION: Duration = Duration::from_millis(300);
+/// How long scrolling must pause before a snapping `ScrollView` settles on an item.
+const SNAP_DELAY: Duration = Duration::from_millis(150);
+const SCROLLBAR_THICKNESS: f32 = 6.0;
+/// Gap between the scroll bar and the edges of the view.
+const SCROLLBAR_MARGIN: f32 = 2.0;
+/// The thumb never shrinks below this, so it stays grabbable over very long content.
+const SCROLLBAR_MIN_THUMB: f32 = 24.0;
+/// How long an auto-hiding scroll bar stays after scrolling or hovering, before fading out.
+const SCROLLBAR_IDLE: Duration = Duration::from_millis(1000);
+const SCROLLBAR_FADE: Duration = Duration::from_millis(300);
+
+/// When a `ScrollView` shows its scroll bar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScrollbarMode {
+    /// Shown while scrolling or hovering the bar, fading out once idle.
+    #[default]
+    AutoHide,
+    /// Shown whenever the content doesn't fit.
+    Always,
+    Hidden,
+}
+
+#[derive(Debug, Default)]
+struct ScrollState {
+    offset: f32,
+    max_offset: f32,
+    /// Length of the view along the scroll axis, from the last frame's layout.
+    viewport: f32,
+    /// Where each child starts along the scroll axis, from the last frame's layout.
+    item_offsets: Vec<f32>,
+    animation: Option<ScrollAnimation>,
+    last_input: Option<Instant>,
+    /// When the scroll bar was last woken up, and the offset it was drawn at.
+    scrollbar_shown: Option<Instant>,
+    scrollbar_offset: f32,
+    /// Pointer position along the scroll axis and offset when the thumb was grabbed.
+    thumb_drag: Option<(f32, f32)>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ScrollAnimation {
+    from: f32,
+    to: f32,
+    started: Instant,
+}
+
+impl ScrollState {
+    fn clamp(&self, offset: f32) -> f32 {
+        offset.clamp(0.0, self.max_offset)
+    }
+
+    fn set(&mut self, offset: f32, animated: bool) {
+        let to = self.clamp(offset);
+        self.animation = animated.then_some(ScrollAnimation { from: self.offset, to, started: Instant::now() });
+        if !animated {
+            self.offset = to;
+        }
+    }
+
+    /// Advances a running animation. Returns whether it needs another frame.
+    fn tick(&mut self) -> bool {
+        let Some(animation) = self.animation else { return false };
+        let t = (animation.started.elapsed().as_secs_f32() / SCROLL_ANIMATION.as_secs_f32()).min(1.0);
+        // Ease-out cubic, like the button ripple
+        self.offset = Easing::CubicOut.interpolate(animation.from, animation.to, t);
+        if t >= 1.0 {
+            self.animation = None;
+        }
+        self.animation.is_some()
+    }
+
+    fn nearest_item(&self) -> Option<f32> {
+        self.item_offsets.iter()
+            .map(|&item| self.clamp(item))
+            .min_by(|a, b| (a - self.offset).abs().total_cmp(&(b - self.offset).abs()))
+    }
+}
+
+/// A handle for reading and driving a `ScrollView`'s offset from outside it, e.g. from a
+/// "Next" button in a carousel. Clones share the same scroll position.
+#[derive(Debug, Clone, Default)]
+pub struct ScrollController {
+    state: Rc<RefCell<ScrollState>>,
+}
+
+impl ScrollController {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Current offset along the scroll axis, in pixels.
+    pub fn offset(&self) -> f32 {
+        self.state.borrow().offset
+    }
+
+    /// Jumps to `offset`, clamped to the scrollable range.
+    pub fn scroll_to(&self, offset: f32) {
+        self.state.borrow_mut().set(offset, false);
+    }
+
+    /// Scrolls smoothly to `offset`, clamped to the scrollable range.
+    pub fn animate_to(&self, offset: f32) {
+        self.state.borrow_mut().set(offset, true);
+    }
+
+    /// Brings the child at `index` to the start of the view. Does nothing before the first
+    /// frame, when children have no positions yet.
+    pub fn scroll_to_item(&self, index: usize, animated: bool) {
+        let mut state = self.state.borrow_mut();
+        if let Some(&offset) = state.item_offsets.get(index) {
+            state.set(offset, animated);
+        }
+    }
+
+    /// Index of the child closest to the start of the view.
+    pub fn current_item(&self) -> usize {
+        let state = self.state.borrow();
+        let target = state.animation.map_or(state.offset, |animation| animation.to);
+        state.item_offsets.iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| (state.clamp(**a) - target).abs().total_cmp(&(state.clamp(**b) - target).abs()))
+            .map_or(0, |(index, _)| index)
+    }
+
+    /// Number of children in the view, as of the last frame.
+    pub fn item_count(&self) -> usize {
+        self.state.borrow().item_offsets.len()
+    }
+}
+
+/// Lays out its children along one axis and scrolls them with the mouse wheel or trackpad
+/// when they don't fit. Content is clipped to the view.
+///
+/// Children keep their natural size along the scroll axis; `Column`s fill the view across
+/// it, so `ScrollView::new(..).horizontal()` of `Column`s makes full-width pages.
+///
+/// A scroll bar with a thumb sized to the visible part of the content is drawn over the
+/// edge of the view; see `scrollbar`. Dragging the thumb scrolls, clicking the track pages.
+pub struct ScrollView {
+    pub children: Vec<Box<dyn View>>,
+    pub z_index: Option<i32>,
+    horizontal: bool,
+    snap: bool,
+    scrollbar: ScrollbarMode,
+    scrollbar_draggable: bool,
+    controller: ScrollController,
+    node_id: Option<NodeId>,
+}
+
+impl ScrollView {
+    pub fn new(children: Vec<Box<dyn View>>) -> Self {
+        Self {
+            children,
+            z_index: None,
+            horizontal: false,
+            snap: false,
+            scrollbar: ScrollbarMode::default(),
+            scrollbar_draggable: true,
+            controller: ScrollController::new(),
+            node_id: None,
+        }
+    }
+
+    /// Adds `child` after the existing children.
+    pub fn child(mut self, child: impl IntoViews) -> Self {
+        self.children.extend(child.into_views());
+        self
+    }
+
+    /// Adds every view in `children` after the existing ones.
+    pub fn children<V: IntoViews>(mut self, children: impl IntoIterator<Item = V>) -> Self {
+        self.children.extend(children.into_iter().flat_map(IntoViews::into_views));
+        self
+    }
+
+    /// Scrolls left/right instead of up/down.
+    pub fn horizontal(mut self) -> Self {
+        self.horizontal = true;
+        self
+    }
+
+    /// Settles on the nearest child's start once scrolling pauses, e.g. for paged carousels.
+    pub fn snap(mut self, snap: bool) -> Self {
+        self.snap = snap;
+        self
+    }
+
+    /// Shares the scroll position with `controller`, so it can be read and set from outside.
+    pub fn controller(mut self, controller: ScrollController) -> Self {
+        self.controller = controller;
+        self
+    }
+
+    /// When to show the scroll bar. Defaults to `ScrollbarMode::AutoHide`.
+    pub fn scrollbar(mut self, mode: ScrollbarMode) -> Self {
+        self.scrollbar = mode;
+        self
+    }
+
+    /// Whether the scroll bar can be dragged and clicked, or only shows the position.
+    pub fn scrollbar_draggable(mut self, draggable: bool) -> Self {
+        self.scrollbar_draggable = draggable;
+        self
+    }
+
+    pub fn z_index(mut self, z_index: i32) -> Self {
+        self.z_index = Some(z_index);
+        self
+    }
+
+    /// The viewport geometry with the scroll offset applied, as the parent geometry for children.
+    fn content_geometry(&self, my_geo: Geometry) -> Geometry {
+        let offset = self.controller.offset();
+        if self.horizontal {
+            Geometry { x: my_geo.x - offset, ..my_geo }
+        } else {
+            Geometry { y: my_geo.y - offset, ..my_geo }
+        }
+    }
+
+    /// The scroll bar's track and thumb, or None when it's hidden or everything fits.
+    fn scrollbar_geometry(&self, my_geo: Geometry, state: &ScrollState) -> Option<(Geometry, Geometry)> {
+        if self.scrollbar == ScrollbarMode::Hidden || state.max_offset <= 0.0 {
+            return None;
+        }
+        let length = if self.horizontal { my_geo.width } else { my_geo.height } - 2.0 * SCROLLBAR_MARGIN;
+        let thumb_length = (length * state.viewport / (state.viewport + state.max_offset))
+            .max(SCROLLBAR_MIN_THUMB)
+            .min(length);
+        let thumb_start = (length - thumb_length) * state.offset / state.max_offset;
+        Some(if self.horizontal {
+            let track = Geometry {
+                x: my_geo.x + SCROLLBAR_MARGIN,
+                y: my_geo.y + my_geo.height - SCROLLBAR_THICKNESS - SCROLLBAR_MARGIN,
+                width: length,
+                height: SCROLLBAR_THICKNESS,
+            };
+            (track, Geometry { x: track.x + thumb_start, width: thumb_length, ..track })
+        } else {
+            let track = Geometry {
+                x: my_geo.x + my_geo.width - SCROLLBAR_THICKNESS - SCROLLBAR_MARGIN,
+                y: my_geo.y + SCROLLBAR_MARGIN,
+                width: SCROLLBAR_THICKNESS,
+                height: length,
+            };
+            (track, Geometry { y: track.y + thumb_start, height: thumb_length, ..track })
+        })
+    }
+
+    /// How opaque the scroll bar is this frame, scheduling the frames that fade it out.
+    fn scrollbar_opacity(&self, ctx: &mut RenderContext, state: &ScrollState) -> f32 {
+        match self.scrollbar {
+            ScrollbarMode::Always => 1.0,
+            ScrollbarMode::Hidden => 0.0,
+            ScrollbarMode::AutoHide if state.thumb_drag.is_some() => 1.0,
+            ScrollbarMode::AutoHide => {
+                let Some(shown) = state.scrollbar_shown else { return 0.0 };
+                let idle = shown.elapsed();
+                if idle < SCROLLBAR_IDLE {
+                    ctx.request_redraw_at(shown + SCROLLBAR_IDLE);
+                    1.0
+                } else if idle < SCROLLBAR_IDLE + SCROLLBAR_FADE {
+                    ctx.request_redraw();
+                    1.0 - (idle - SCROLLBAR_IDLE).as_secs_f32() / SCROLLBAR_FADE.as_secs_f32()
+                } else {
+                    0.0
+                }
+            }
+        }
+    }
+}
+
+impl Default for ScrollView {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl View for ScrollView {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<NodeId, LayoutError> {
+        let child_nodes: Vec<NodeId> = self.children.iter_mut()
+            .map(|child| child.layout(ctx))
+            .collect::<Result<_, _>>()?;
+        // Children overflow along the scroll axis instead of being squeezed into the view
+        for &node in &child_nodes {
+            let style = Style { flex_shrink: 0.0, ..ctx.taffy.style(node)?.clone() };
+            ctx.taffy.set_style(node, style)?;
+        }
+
+        let node = ctx.taffy.new_with_children(
+            Style {
+                display: Display::Flex,
+                flex_direction: if self.horizontal { FlexDirection::Row } else { FlexDirection::Column },
+                overflow: taffy::Point { x: taffy::Overflow::Scroll, y: taffy::Overflow::Scroll },
+                scrollbar_width: 0.0,
+                flex_grow: 1.0,
+                min_size: Size::zero(),
+                size: Size { width: Dimension::Percent(1.0), height: Dimension::Auto },
+                ..Default::default()
+            },
+            &child_nodes,
+        )?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        let Some(node) = self.node_id else { return };
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+
+        {
+            let mut state = self.controller.state.borrow_mut();
+            let layout = layout_ctx.taffy.layout(node).ok();
+            let (content, viewport) = match layout {
+                Some(layout) if self.horizontal => (layout.content_size.width, layout.size.width),
+                Some(layout) => (layout.content_size.height, layout.size.height),
+                None => (0.0, 0.0),
+            };
+            state.max_offset = (content - viewport).max(0.0);
+            state.viewport = viewport;
+            state.item_offsets = layout_ctx.taffy.children(node).unwrap_or_default().into_iter()
+                .filter_map(|child| layout_ctx.taffy.layout(child).ok())
+                .map(|layout| if self.horizontal { layout.location.x } else { layout.location.y })
+                .collect();
+
+            let mut animating = state.tick();
+            if self.snap
+                && state.animation.is_none()
+                && state.last_input.is_some_and(|last| last.elapsed() >= SNAP_DELAY)
+            {
+                state.last_input = None;
+                if let Some(target) = state.nearest_item()
+                    && target != state.offset
+                {
+                    state.set(target, true);
+                    animating = true;
+                }
+            }
+            // Keep frames coming while animating, and wake up again once it's time to snap
+            if animating {
+                ctx.request_redraw();
+            } else if self.snap && let Some(last) = state.last_input {
+                ctx.request_redraw_at(last + SNAP_DELAY);
+            }
+            state.offset = state.clamp(state.offset);
+            // Any scrolling, by wheel, thumb or controller, wakes the scroll bar up
+            if state.offset != state.scrollbar_offset {
+                state.scrollbar_offset = state.offset;
+                state.scrollbar_shown = Some(Instant::now());
+            }
+            let translate = if self.horizontal { [-state.offset, 0.0] } else { [0.0, -state.offset] };
+            layout_ctx.set_child_transform(node, Transform { translate, scale: 1.0 });
+        }
+
+        let content_geo = self.content_geometry(my_geo);
+        with_z_index(ctx, self.z_index, |ctx| {
+            let previous_clip = ctx.render_queue.set_clip(Some(Clip { geometry: my_geo, radius: 0.0 }));
+            for child in self.children.iter_mut() {
+                child.prepare(ctx, layout_ctx, content_geo);
+            }
+            ctx.render_queue.set_clip(previous_clip);
+
+            let state = self.controller.state.borrow();
+            if let Some((track, thumb)) = self.scrollbar_geometry(my_geo, &state) {
+                let opacity = self.scrollbar_opacity(ctx, &state);
+                if opacity > 0.0 {
+                    let radius = SCROLLBAR_THICKNESS / 2.0;
+                    ctx.render_queue.push_rounded_rect(track, [0.0, 0.0, 0.0, 0.08 * opacity], radius);
+                    ctx.render_queue.push_rounded_rect(thumb, [0.0, 0.0, 0.0, 0.45 * opacity], radius);
+                }
+            }
+
+            if ctx.debug {
+                render_outline_helper(ctx, my_geo, [0.0, 0.5, 1.0, 1.0]);
+            }
+        });
+    }
+
+    fn collect_text_areas<'a>(&'a self, layout_ctx: &LayoutContext, geometry: Geometry, areas: &mut Vec<glyphon::TextArea<'a>>) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        let content_geo = self.content_geometry(my_geo);
+        let start = areas.len();
+        for child in &self.children {
+            child.collect_text_areas(layout_ctx, content_geo, areas);
+        }
+        // Text isn't drawn through the render queue, so clip it to the view here
+        for area in &mut areas[start..] {
+            let bounds = &mut area.bounds;
+            bounds.left = bounds.left.max(my_geo.x as i32);
+            bounds.top = bounds.top.max(my_geo.y as i32);
+            bounds.right = bounds.right.min((my_geo.x + my_geo.width) as i32);
+            bounds.bottom = bounds.bottom.min((my_geo.y + my_geo.height) as i32);
+        }
+    }
+
+    fn render<'rp>(&'rp self, ctx: &'rp RenderContext, render_pass: &mut wgpu::RenderPass<'rp>, geometry: Geometry) {
+        for child in &self.children {
+            child.render(ctx, render_pass, geometry);
+        }
+    }
+
+    fn handle_event(&mut self, event: &Event, layout_ctx: &LayoutContext, geometry: Geometry) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+
+        match event {
+            Event::Scroll { dx, dy, x, y } if my_geo.contains(*x, *y) => {
+                let mut state = self.controller.state.borrow_mut();
+                let delta = if self.horizontal {
+                    // Plain wheels only scroll vertically, so let them drive horizontal views too
+                    if *dx != 0.0 { *dx } else { *dy }
+                } else {
+                    *dy
+                };
+                let offset = state.offset + delta;
+                state.set(offset, false);
+                state.last_input = Some(Instant::now());
+                return;
             }
-            Event::MouseDown { x, y } => {
-                if my_geo.contains(*x, *y) {
-                    self.pressed = true;
+            Event::MouseClick { x, y } if !my_geo.contains(*x, *y) => return,
+            _ => {}
+        }
+
+        let on_scrollbar = self.scrollbar_pointer(event, my_geo);
+        if on_scrollbar && matches!(event, Event::MouseClick { .. }) {
+            return;
+        }
+
+        // Presses outside the view must not land on content scrolled out of it, but children
+        // still need to hear about them, e.g. to drop focus. The scroll bar covers the content.
+        let outside;
+        let event = match event {
+            Event::MouseDown { x, y } if on_scrollbar || !my_geo.contains(*x, *y) => {
+                outside = Event::MouseDown { x: f32::NEG_INFINITY, y: f32::NEG_INFINITY };
+                &outside
+            }
+            _ => event,
+        };
+        let content_geo = self.content_geometry(my_geo);
+        for child in self.children.iter_mut() {
+            child.handle_event(event, layout_ctx, content_geo);
+        }
+    }
+
+    fn on_init(&mut self) { self.children.iter_mut().for_each(|child| child.on_init()); }
+    fn on_mount(&mut self) { self.children.iter_mut().for_each(|child| child.on_mount()); }
+    fn on_update(&mut self) { self.children.iter_mut().for_each(|child| child.on_update()); }
+    fn on_unmount(&mut self) { self.children.iter_mut().for_each(|child| child.on_unmount()); }
+    fn on_resize(&mut self, width: f32, height: f32) { self.children.iter_mut().for_each(|child| child.on_resize(width, height)); }
+
+    fn drawn_z_index(&self) -> Option<i32> {
+        self.z_index
+    }
+}
+
+impl ScrollView {
+    /// Drags the thumb, pages on track clicks and wakes the bar up on hover. Returns whether
+    /// the pointer event landed on the scroll bar rather than the content.
+    fn scrollbar_pointer(&mut self, event: &Event, my_geo: Geometry) -> bool {
+        let mut state = self.controller.state.borrow_mut();
+        if let Event::MouseUp { .. } = event {
+            state.thumb_drag = None;
+        }
+        let Some((x, y)) = event.position() else { return false };
+        let along = if self.horizontal { x } else { y };
+        let Some((track, thumb)) = self.scrollbar_geometry(my_geo, &state) else {
+            state.thumb_drag = None;
+            return false;
+        };
+
+        if let (Event::MouseMove { .. }, Some((start, offset))) = (event, state.thumb_drag) {
+            let (length, thumb_length) = if self.horizontal { (track.width, thumb.width) } else { (track.height, thumb.height) };
+            let travel = length - thumb_length;
+            if travel > 0.0 {
+                let offset = offset + (along - start) * state.max_offset / travel;
+                state.set(offset, false);
+            }
+            return true;
+        }
+
+        // The bar is thin, so accept the pointer a little around it
+        let grab = Geometry {
+            x: track.x - SCROLLBAR_MARGIN,
+            y: track.y - SCROLLBAR_MARGIN,
+            width: track.width + 2.0 * SCROLLBAR_MARGIN,
+            height: track.height + 2.0 * SCROLLBAR_MARGIN,
+        };
+        if !grab.contains(x, y) {
+            return false;
+        }
+        if self.scrollbar == ScrollbarMode::AutoHide {
+            state.scrollbar_shown = Some(Instant::now());
+        }
+        if !self.scrollbar_draggable {
+            return false;
+        }
+        if let Event::MouseDown { .. } = event {
+            let thumb_start = if self.horizontal { thumb.x } else { thumb.y };
+            let thumb_end = thumb_start + if self.horizontal { thumb.width } else { thumb.height };
+            if along < thumb_start {
+                let offset = state.offset - state.viewport;
+                state.set(offset, true);
+            } else if along > thumb_end {
+                let offset = state.offset + state.viewport;
+                state.set(offset, true);
+            } else {
+                state.thumb_drag = Some((along, state.offset));
+            }
+        }
+        matches!(event, Event::MouseDown { .. } | Event::MouseClick { .. })
+    }
+}
+
+/// A pannable, zoomable window onto its child, e.g. for a node editor or map. Dragging pans,
+/// the mouse wheel or a trackpad pinch zooms around the pointer. The child is clipped to the
+/// viewport and receives events in its own, unzoomed coordinates.
+pub struct Viewport {
+    pub child: Box<dyn View>,
+    /// How far the child is moved, in screen pixels.
+    pub pan: [f32; 2],
+    pub zoom: f32,
+    pub min_zoom: f32,
+    pub max_zoom: f32,
+    /// Last pointer position while dragging.
+    drag: Option<(f32, f32)>,
+    node_id: Option<NodeId>,
+}
+
+impl Viewport {
+    pub fn new(child: impl View + 'static) -> Self {
+        Self {
+            child: Box::new(child),
+            pan: [0.0, 0.0],
+            zoom: 1.0,
+            min_zoom: 0.1,
+            max_zoom: 10.0,
+            drag: None,
+            node_id: None,
+        }
+    }
+
+    pub fn zoom_range(mut self, min_zoom: f32, max_zoom: f32) -> Self {
+        self.min_zoom = min_zoom;
+        self.max_zoom = max_zoom;
+        self.zoom = self.zoom.clamp(min_zoom, max_zoom);
+        self
+    }
+
+    /// Maps child coordinates (laid out within `my_geo`) to screen coordinates.
+    fn transform(&self, my_geo: Geometry) -> Transform {
+        Transform {
+            translate: [my_geo.x * (1.0 - self.zoom) + self.pan[0], my_geo.y * (1.0 - self.zoom) + self.pan[1]],
+            scale: self.zoom,
+        }
+    }
+
+    /// Zooms by `factor`, keeping the child point under `(x, y)` where it is.
+    fn zoom_at(&mut self, factor: f32, x: f32, y: f32, my_geo: Geometry) {
+        let anchor = self.transform(my_geo).invert([x, y]);
+        self.zoom = (self.zoom * factor).clamp(self.min_zoom, self.max_zoom);
+        self.pan = [
+            x - anchor[0] * self.zoom - my_geo.x * (1.0 - self.zoom),
+            y - anchor[1] * self.zoom - my_geo.y * (1.0 - self.zoom),
+        ];
+    }
+}
+
+impl View for Viewport {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<NodeId, LayoutError> {
+        let child = self.child.layout(ctx)?;
+        // The child keeps its size; the viewport shows whatever part of it is in view
+        let style = Style { flex_shrink: 0.0, ..ctx.taffy.style(child)?.clone() };
+        ctx.taffy.set_style(child, style)?;
+
+        let node = ctx.taffy.new_with_children(
+            Style {
+                display: Display::Flex,
+                overflow: taffy::Point { x: taffy::Overflow::Hidden, y: taffy::Overflow::Hidden },
+                flex_grow: 1.0,
+                min_size: Size::zero(),
+                size: Size { width: Dimension::Percent(1.0), height: Dimension::Auto },
+                ..Default::default()
+            },
+            &[child],
+        )?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        let Some(node) = self.node_id else { return };
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        let transform = self.transform(my_geo);
+        layout_ctx.set_child_transform(node, transform);
+
+        let previous_clip = ctx.render_queue.set_clip(Some(Clip { geometry: my_geo, radius: 0.0 }));
+        let previous_transform = ctx.render_queue.set_transform(ctx.render_queue.transform().then(transform));
+        self.child.prepare(ctx, layout_ctx, my_geo);
+        ctx.render_queue.set_transform(previous_transform);
+        ctx.render_queue.set_clip(previous_clip);
+
+        if ctx.debug {
+            render_outline_helper(ctx, my_geo, [0.0, 0.5, 1.0, 1.0]);
+        }
+    }
+
+    fn collect_text_areas<'a>(&'a self, layout_ctx: &LayoutContext, geometry: Geometry, areas: &mut Vec<glyphon::TextArea<'a>>) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        let transform = self.transform(my_geo);
+        let start = areas.len();
+        self.child.collect_text_areas(layout_ctx, my_geo, areas);
+        // Text isn't drawn through the render queue, so move, scale and clip it here
+        for area in &mut areas[start..] {
+            [area.left, area.top] = transform.apply([area.left, area.top]);
+            area.scale *= transform.scale;
+            let bounds = &mut area.bounds;
+            let [left, top] = transform.apply([bounds.left as f32, bounds.top as f32]);
+            let [right, bottom] = transform.apply([bounds.right as f32, bounds.bottom as f32]);
+            bounds.left = (left.floor() as i32).max(my_geo.x as i32);
+            bounds.top = (top.floor() as i32).max(my_geo.y as i32);
+            bounds.right = (right.ceil() as i32).min((my_geo.x + my_geo.width) as i32);
+            bounds.bottom = (bottom.ceil() as i32).min((my_geo.y + my_geo.height) as i32);
+        }
+    }
+
+    fn render<'rp>(&'rp self, ctx: &'rp RenderContext, render_pass: &mut wgpu::RenderPass<'rp>, geometry: Geometry) {
+        self.child.render(ctx, render_pass, geometry);
+    }
+
+    fn handle_event(&mut self, event: &Event, layout_ctx: &LayoutContext, geometry: Geometry) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+
+        match *event {
+            Event::Scroll { dy, x, y, .. } if my_geo.contains(x, y) => {
+                // About 8% per wheel notch
+                self.zoom_at((-dy * 0.002).exp(), x, y, my_geo);
+                return;
+            }
+            Event::Pinch { scale, center: (x, y) } if my_geo.contains(x, y) => {
+                self.zoom_at(scale, x, y, my_geo);
+                return;
+            }
+            Event::MouseDown { x, y } if my_geo.contains(x, y) => self.drag = Some((x, y)),
+            Event::MouseMove { x, y } => {
+                if let Some((last_x, last_y)) = self.drag {
+                    self.pan[0] += x - last_x;
+                    self.pan[1] += y - last_y;
+                    self.drag = Some((x, y));
                 }
             }
-            Event::MouseUp { .. } => {
-                self.pressed = false;
+            Event::MouseUp { .. } => self.drag = None,
+            Event::MouseClick { x, y } if !my_geo.contains(x, y) => return,
+            _ => {}
+        }
+
+        // Presses outside the viewport must not land on parts of the child outside it
+        let transform = self.transform(my_geo);
+        let event = match *event {
+            Event::MouseDown { x, y } if !my_geo.contains(x, y) => Event::MouseDown { x: f32::NEG_INFINITY, y: f32::NEG_INFINITY },
+            _ => event.map_position(|x, y| {
+                let [x, y] = transform.invert([x, y]);
+                (x, y)
+            }),
+        };
+        self.child.handle_event(&event, layout_ctx, my_geo);
+    }
+
+    fn on_init(&mut self) { self.child.on_init(); }
+    fn on_mount(&mut self) { self.child.on_mount(); }
+    fn on_update(&mut self) { self.child.on_update(); }
+    fn on_unmount(&mut self) { self.child.on_unmount(); }
+    fn on_resize(&mut self, width: f32, height: f32) { self.child.on_resize(width, height); }
+}
+
+pub struct Rect {
+    pub color: [f32; 4],
+    color_binding: Option<Computed<[f32; 4]>>,
+    pub z_index: Option<i32>,
+    pub edge_softness: f32,
+    node_id: Option<NodeId>,
+}
+
+impl Rect {
+    pub fn new(color: [f32; 4]) -> Self { Self { color, color_binding: None, z_index: None, edge_softness: 0.0, node_id: None } }
+
+    pub fn z_index(mut self, z_index: i32) -> Self {
+        self.z_index = Some(z_index);
+        self
+    }
+
+    /// Softens the edges by `softness` pixels: 0 is crisp, larger values blur them out.
+    pub fn edge_softness(mut self, softness: f32) -> Self {
+        self.edge_softness = softness;
+        self
+    }
+
+    /// Takes the color from `color` on every frame, so it follows signals without the tree
+    /// being rebuilt, e.g. when switching themes.
+    pub fn bind_color(mut self, color: Computed<[f32; 4]>) -> Self {
+        self.color_binding = Some(redraw_on_change(color));
+        self
+    }
+}
+
+impl View for Rect {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<NodeId, LayoutError> {
+        let node = ctx.taffy.new_leaf(Style {
+            size: Size { width: length(100.0), height: length(100.0) },
+            ..Default::default()
+        })?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        if let Some(binding) = &self.color_binding {
+            self.color = binding.get();
+        }
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        with_z_index(ctx, self.z_index, |ctx| with_edge_softness(ctx, self.edge_softness, |ctx| ctx.render_queue.push_rect(my_geo, self.color)));
+    }
+
+    fn render<'rp>(&'rp self, _: &'rp RenderContext, _: &mut wgpu::RenderPass<'rp>, _: Geometry) {}
+    fn handle_event(&mut self, _: &Event, _: &LayoutContext, _: Geometry) {}
+
+    fn drawn_z_index(&self) -> Option<i32> {
+        self.z_index
+    }
+}
+
+pub struct Circle {
+    pub color: [f32; 4],
+    color_binding: Option<Computed<[f32; 4]>>,
+    pub z_index: Option<i32>,
+    pub edge_softness: f32,
+    node_id: Option<NodeId>,
+}
+
+impl Circle {
+    pub fn new(color: [f32; 4]) -> Self { Self { color, color_binding: None, z_index: None, edge_softness: 0.0, node_id: None } }
+
+    pub fn z_index(mut self, z_index: i32) -> Self {
+        self.z_index = Some(z_index);
+        self
+    }
+
+    /// Softens the edges by `softness` pixels: 0 is crisp, larger values blur them out.
+    pub fn edge_softness(mut self, softness: f32) -> Self {
+        self.edge_softness = softness;
+        self
+    }
+
+    /// Takes the color from `color` on every frame, so it follows signals without the tree
+    /// being rebuilt, e.g. when switching themes.
+    pub fn bind_color(mut self, color: Computed<[f32; 4]>) -> Self {
+        self.color_binding = Some(redraw_on_change(color));
+        self
+    }
+}
+
+impl View for Circle {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<NodeId, LayoutError> {
+        let node = ctx.taffy.new_leaf(Style {
+            size: Size { width: length(50.0), height: length(50.0) },
+            ..Default::default()
+        })?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        if let Some(binding) = &self.color_binding {
+            self.color = binding.get();
+        }
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        with_z_index(ctx, self.z_index, |ctx| with_edge_softness(ctx, self.edge_softness, |ctx| ctx.render_queue.push_circle(my_geo, self.color)));
+    }
+
+    fn render<'rp>(&'rp self, _: &'rp RenderContext, _: &mut wgpu::RenderPass<'rp>, _: Geometry) {}
+    fn handle_event(&mut self, _: &Event, _: &LayoutContext, _: Geometry) {}
+
+    fn drawn_z_index(&self) -> Option<i32> {
+        self.z_index
+    }
+}
+
+/// A filled vector shape, e.g. a logo, from path commands or SVG path data. The path is
+/// drawn in its own coordinates, its view box, scaled to fit the widget and centered
+/// without distorting it. The view box defaults to the path's bounds, and the widget's
+/// size to the view box's.
+///
+/// ```
+/// use noxkit::prelude::*;
+///
+/// let heart = Path::parse("M 12 21 C 5 15 2 12 2 8 C 2 5 4 3 7 3 C 9 3 11 4 12 6 C 13 4 15 3 17 3 C 20 3 22 5 22 8 C 22 12 19 15 12 21 Z", [0.9, 0.2, 0.3, 1.0])
+///     .unwrap()
+///     .view_box(0.0, 0.0, 24.0, 24.0)
+///     .size(48.0, 48.0);
+/// assert!(Path::parse("M 0 0 X", [1.0; 4]).is_err());
+/// ```
+pub struct Path {
+    pub commands: Vec<PathCommand>,
+    pub color: [f32; 4],
+    pub fill_rule: FillRule,
+    pub z_index: Option<i32>,
+    /// The part of the path's coordinates shown, as x, y, width and height.
+    view_box: [f32; 4],
+    /// Size in pixels, if not the view box's.
+    size: Option<(f32, f32)>,
+    node_id: Option<NodeId>,
+}
+
+impl Path {
+    pub fn new(commands: Vec<PathCommand>, color: [f32; 4]) -> Self {
+        let [min_x, min_y, max_x, max_y] = path::bounds(&commands).unwrap_or_default();
+        Self {
+            commands,
+            color,
+            fill_rule: FillRule::NonZero,
+            z_index: None,
+            view_box: [min_x, min_y, max_x - min_x, max_y - min_y],
+            size: None,
+            node_id: None,
+        }
+    }
+
+    /// A path from SVG path data (see `path::parse`), e.g. copied from an icon's `d`
+    /// attribute.
+    pub fn parse(d: &str, color: [f32; 4]) -> Result<Self, PathError> {
+        Ok(Self::new(path::parse(d)?, color))
+    }
+
+    pub fn fill_rule(mut self, fill_rule: FillRule) -> Self {
+        self.fill_rule = fill_rule;
+        self
+    }
+
+    /// Shows the `width` x `height` area at (`x`, `y`) of the path's coordinates, like
+    /// SVG's `viewBox`, e.g. `0, 0, 24, 24` for a 24px icon with some margin around it.
+    pub fn view_box(mut self, x: f32, y: f32, width: f32, height: f32) -> Self {
+        self.view_box = [x, y, width, height];
+        self
+    }
+
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        self.size = Some((width, height));
+        self
+    }
+
+    pub fn z_index(mut self, z_index: i32) -> Self {
+        self.z_index = Some(z_index);
+        self
+    }
+}
+
+impl View for Path {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<NodeId, LayoutError> {
+        let (width, height) = self.size.unwrap_or((self.view_box[2], self.view_box[3]));
+        let node = ctx.taffy.new_leaf(Style {
+            size: Size { width: length(width), height: length(height) },
+            flex_shrink: 0.0,
+            ..Default::default()
+        })?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        let [x, y, width, height] = self.view_box;
+        if width <= 0.0 || height <= 0.0 {
+            return;
+        }
+        let scale = (my_geo.width / width).min(my_geo.height / height);
+        let offset = [
+            my_geo.x + (my_geo.width - width * scale) * 0.5 - x * scale,
+            my_geo.y + (my_geo.height - height * scale) * 0.5 - y * scale,
+        ];
+        let commands: Vec<PathCommand> = self.commands.iter()
+            .map(|command| command.map_points(|[px, py]| [offset[0] + px * scale, offset[1] + py * scale]))
+            .collect();
+        with_z_index(ctx, self.z_index, |ctx| ctx.render_queue.push_path(&commands, self.fill_rule, self.color));
+    }
+
+    fn render<'rp>(&'rp self, _: &'rp RenderContext, _: &mut wgpu::RenderPass<'rp>, _: Geometry) {}
+    fn handle_event(&mut self, _: &Event, _: &LayoutContext, _: Geometry) {}
+
+    fn drawn_z_index(&self) -> Option<i32> {
+        self.z_index
+    }
+}
+
+/// A ring that fills clockwise from 12 o'clock as `value` goes from 0 to 1, over a
+/// fainter track showing the rest, e.g. a download or a dashboard gauge.
+pub struct CircularProgress {
+    /// How much of the ring is filled, from 0 to 1.
+    pub value: f32,
+    pub color: [f32; 4],
+    pub track_color: [f32; 4],
+    /// Outer diameter in pixels.
+    pub size: f32,
+    pub thickness: f32,
+    node_id: Option<NodeId>,
+}
+
+impl CircularProgress {
+    pub fn new(value: f32) -> Self {
+        Self {
+            value: value.clamp(0.0, 1.0),
+            // Material primary (indigo), and the same at low opacity for the track
+            color: [0.247, 0.317, 0.709, 1.0],
+            track_color: [0.247, 0.317, 0.709, 0.2],
+            size: 48.0,
+            thickness: 4.0,
+            node_id: None,
+        }
+    }
+
+    pub fn color(mut self, color: [f32; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn track_color(mut self, color: [f32; 4]) -> Self {
+        self.track_color = color;
+        self
+    }
+
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn thickness(mut self, thickness: f32) -> Self {
+        self.thickness = thickness;
+        self
+    }
+}
+
+impl View for CircularProgress {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<NodeId, LayoutError> {
+        let node = ctx.taffy.new_leaf(Style {
+            size: Size { width: length(self.size), height: length(self.size) },
+            ..Default::default()
+        })?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        let queue = &mut ctx.render_queue;
+        queue.push_arc(my_geo, self.track_color, self.thickness, 0.0, 360.0);
+        let value = self.value.clamp(0.0, 1.0);
+        if value > 0.0 {
+            queue.push_arc(my_geo, self.color, self.thickness, 0.0, value * 360.0);
+        }
+    }
+
+    fn render<'rp>(&'rp self, _: &'rp RenderContext, _: &mut wgpu::RenderPass<'rp>, _: Geometry) {}
+    fn handle_event(&mut self, _: &Event, _: &LayoutContext, _: Geometry) {}
+}
+
+pub struct RoundedRect {
+    pub color: [f32; 4],
+    color_binding: Option<Computed<[f32; 4]>>,
+    pub radius: f32,
+    pub z_index: Option<i32>,
+    pub edge_softness: f32,
+    node_id: Option<NodeId>,
+}
+
+impl RoundedRect {
+    pub fn new(color: [f32; 4], radius: f32) -> Self { Self { color, radius, color_binding: None, z_index: None, edge_softness: 0.0, node_id: None } }
+
+    pub fn z_index(mut self, z_index: i32) -> Self {
+        self.z_index = Some(z_index);
+        self
+    }
+
+    /// Softens the edges by `softness` pixels: 0 is crisp, larger values blur them out.
+    pub fn edge_softness(mut self, softness: f32) -> Self {
+        self.edge_softness = softness;
+        self
+    }
+
+    /// Takes the color from `color` on every frame, so it follows signals without the tree
+    /// being rebuilt, e.g. when switching themes.
+    pub fn bind_color(mut self, color: Computed<[f32; 4]>) -> Self {
+        self.color_binding = Some(redraw_on_change(color));
+        self
+    }
+}
+
+impl View for RoundedRect {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<NodeId, LayoutError> {
+        let node = ctx.taffy.new_leaf(Style {
+            size: Size { width: length(100.0), height: length(50.0) },
+            ..Default::default()
+        })?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        if let Some(binding) = &self.color_binding {
+            self.color = binding.get();
+        }
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        with_z_index(ctx, self.z_index, |ctx| with_edge_softness(ctx, self.edge_softness, |ctx| ctx.render_queue.push_rounded_rect(my_geo, self.color, self.radius)));
+    }
+
+    fn render<'rp>(&'rp self, _: &'rp RenderContext, _: &mut wgpu::RenderPass<'rp>, _: Geometry) {}
+    fn handle_event(&mut self, _: &Event, _: &LayoutContext, _: Geometry) {}
+
+    fn drawn_z_index(&self) -> Option<i32> {
+        self.z_index
+    }
+}
+
+/// A round picture of a person: an image cropped to a circle, or their initials on a
+/// colored disc when there is no image (or it couldn't be loaded).
+pub struct Avatar {
+    pub size: f32,
+    pub color: [f32; 4],
+    pub z_index: Option<i32>,
+    initials: Text,
+    /// Pixels waiting to be uploaded on the first `prepare`.
+    pixels: Option<(u32, u32, Vec<u8>)>,
+    texture: Option<Texture>,
+    node_id: Option<NodeId>,
+}
+
+impl Avatar {
+    /// An avatar showing `initials`, e.g. "JD".
+    pub fn initials(initials: impl Into<String>) -> Self {
+        Self {
+            size: 40.0,
+            color: [0.361, 0.420, 0.753, 1.0],
+            z_index: None,
+            initials: Text::new(initials).size(16.0),
+            pixels: None,
+            texture: None,
+            node_id: None,
+        }
+    }
+
+    /// An avatar showing an image given as RGBA8 pixels (sRGB, row by row). Non-square
+    /// images are cropped to their center.
+    ///
+    /// Like `Image`, this takes decoded pixels rather than PNG or JPEG bytes: NoxKit has no
+    /// image decoder, so decode files with one of the app's choosing, e.g. the `image` crate.
+    pub fn from_image(width: u32, height: u32, rgba: Vec<u8>) -> Self {
+        Self::initials("").image(width, height, rgba)
+    }
+
+    /// Shows an image instead of the initials, falling back to them if the pixels don't
+    /// add up to `width` x `height`.
+    pub fn image(mut self, width: u32, height: u32, rgba: Vec<u8>) -> Self {
+        self.pixels = Some((width, height, rgba));
+        self
+    }
+
+    /// Diameter in pixels. Defaults to 40; the initials scale along.
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self.initials.font_size = size * 0.4;
+        self
+    }
+
+    /// Background color behind the initials.
+    pub fn color(mut self, color: [f32; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn z_index(mut self, z_index: i32) -> Self {
+        self.z_index = Some(z_index);
+        self
+    }
+}
+
+impl View for Avatar {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<NodeId, LayoutError> {
+        let text_node = self.initials.layout(ctx)?;
+        let node = ctx.taffy.new_with_children(
+            Style {
+                size: Size { width: length(self.size), height: length(self.size) },
+                flex_shrink: 0.0,
+                justify_content: Some(JustifyContent::Center),
+                align_items: Some(AlignItems::Center),
+                ..Default::default()
+            },
+            &[text_node],
+        )?;
+        self.node_id = Some(node);
+        Ok(node)
+    }
+
+    fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        if let Some((width, height, rgba)) = self.pixels.take() {
+            self.texture = ctx.create_texture(width, height, &rgba);
+        }
+
+        match &self.texture {
+            Some(texture) => ctx.place_texture(texture, my_geo, my_geo.width * 0.5),
+            None => {
+                with_z_index(ctx, self.z_index, |ctx| ctx.render_queue.push_circle(my_geo, self.color));
+                self.initials.prepare(ctx, layout_ctx, my_geo);
             }
         }
     }
 
-    fn on_init(&mut self) { self.text_view.on_init(); }
-    fn on_mount(&mut self) { self.text_view.on_mount(); }
-    fn on_update(&mut self) { self.text_view.on_update(); }
-    fn on_unmount(&mut self) { self.text_view.on_unmount(); }
+    fn collect_text_areas<'a>(&'a self, layout_ctx: &LayoutContext, geometry: Geometry, areas: &mut Vec<glyphon::TextArea<'a>>) {
+        if self.texture.is_some() {
+            return;
+        }
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        self.initials.collect_text_areas(layout_ctx, my_geo, areas);
+    }
+
+    fn render<'rp>(&'rp self, ctx: &'rp RenderContext, render_pass: &mut wgpu::RenderPass<'rp>, _: Geometry) {
+        if let Some(texture) = &self.texture {
+            ctx.draw_texture(texture, render_pass);
+        }
+    }
+
+    fn handle_event(&mut self, _: &Event, _: &LayoutContext, _: Geometry) {}
+
+    fn drawn_z_index(&self) -> Option<i32> {
+        self.z_index
+    }
 }
 
-pub struct Rect {
-    pub color: [f32; 4],
+/// An image given as RGBA8 pixels (sRGB, row by row), shown at its pixel size unless given
+/// a `size`, e.g. a toolbar icon. A box of another aspect ratio shows the image's center.
+///
+/// A `tint` recolors it without new pixels, e.g. graying an icon out while its action is
+/// unavailable or highlighting a selected one (see `TintMode`).
+pub struct Image {
+    pub size: (f32, f32),
+    pub radius: f32,
+    pub tint: [f32; 4],
+    pub tint_mode: TintMode,
+    /// Pixels waiting to be uploaded on the first `prepare`.
+    pixels: Option<(u32, u32, Vec<u8>)>,
+    texture: Option<Texture>,
     node_id: Option<NodeId>,
 }
 
-impl Rect {
-    pub fn new(color: [f32; 4]) -> Self { Self { color, node_id: None } }
+impl Image {
+    pub fn new(width: u32, height: u32, rgba: Vec<u8>) -> Self {
+        Self {
+            size: (width as f32, height as f32),
+            radius: 0.0,
+            tint: [1.0; 4],
+            tint_mode: TintMode::Multiply,
+            pixels: Some((width, height, rgba)),
+            texture: None,
+            node_id: None,
+        }
+    }
+
+    /// Draws the image `width` x `height` pixels large instead of at its own size.
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        self.size = (width, height);
+        self
+    }
+
+    /// Rounds the corners; half the size gives a circle.
+    pub fn radius(mut self, radius: f32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// Combines `color` with the image's pixels, multiplying them by default. White leaves
+    /// the image as is.
+    pub fn tint(mut self, color: [f32; 4]) -> Self {
+        self.tint = color;
+        self
+    }
+
+    pub fn tint_mode(mut self, mode: TintMode) -> Self {
+        self.tint_mode = mode;
+        self
+    }
 }
 
-impl View for Rect {
-    fn layout(&mut self, ctx: &mut LayoutContext) -> NodeId {
+impl View for Image {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<NodeId, LayoutError> {
+        let (width, height) = self.size;
         let node = ctx.taffy.new_leaf(Style {
-            size: Size { width: length(100.0), height: length(100.0) },
+            size: Size { width: length(width), height: length(height) },
+            flex_shrink: 0.0,
             ..Default::default()
-        }).unwrap();
+        })?;
         self.node_id = Some(node);
-        node
+        Ok(node)
     }
 
     fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
-        let node_layout = layout_ctx.taffy.layout(self.node_id.unwrap()).unwrap();
-        let my_geo = Geometry {
-            x: geometry.x + node_layout.location.x,
-            y: geometry.y + node_layout.location.y,
-            width: node_layout.size.width,
-            height: node_layout.size.height,
-        };
-        ctx.render_queue.push_rect(my_geo, self.color);
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        if let Some((width, height, rgba)) = self.pixels.take() {
+            self.texture = ctx.create_texture(width, height, &rgba);
+        }
+        if let Some(texture) = &self.texture {
+            texture.set_tint(self.tint, self.tint_mode);
+            ctx.place_texture(texture, my_geo, self.radius);
+        }
+    }
+
+    fn render<'rp>(&'rp self, ctx: &'rp RenderContext, render_pass: &mut wgpu::RenderPass<'rp>, _: Geometry) {
+        if let Some(texture) = &self.texture {
+            ctx.draw_texture(texture, render_pass);
+        }
     }
 
-    fn render<'rp>(&'rp self, _: &'rp RenderContext, _: &mut wgpu::RenderPass<'rp>, _: Geometry) {}
     fn handle_event(&mut self, _: &Event, _: &LayoutContext, _: Geometry) {}
 }
 
-pub struct Circle {
-    pub color: [f32; 4],
+/// An image stretched to any size without distorting its border, e.g. a decorative panel
+/// frame: the corners keep their size, the edges stretch along the border and the center
+/// stretches both ways. `insets` are the border widths in image pixels and are drawn at that
+/// size; a box smaller than two corners shrinks them to fit.
+///
+/// The box fills its parent's width and fits its children's height unless given a `size`.
+/// Children are laid out inside the border (`padding` changes that). Like other textures,
+/// the image draws above shapes, so children should be text or textures themselves.
+pub struct NinePatch {
+    pub insets: EdgeInsets,
+    pub padding: EdgeInsets,
+    pub size: Option<(f32, f32)>,
+    pub children: Vec<Box<dyn View>>,
+    /// Size of the source image in pixels.
+    image_size: (u32, u32),
+    /// Pixels waiting to be uploaded on the first `prepare`.
+    pixels: Option<Vec<u8>>,
+    texture: Option<Texture>,
     node_id: Option<NodeId>,
+    content_node: Option<NodeId>,
 }
 
-impl Circle {
-    pub fn new(color: [f32; 4]) -> Self { Self { color, node_id: None } }
+impl NinePatch {
+    /// A nine-patch of an image given as RGBA8 pixels (sRGB, row by row), sliced `insets`
+    /// pixels in from each edge. Encoded images need decoding first, as for
+    /// `Avatar::from_image`. Add children with `child`.
+    pub fn new(width: u32, height: u32, rgba: Vec<u8>, insets: EdgeInsets) -> Self {
+        Self {
+            insets,
+            padding: insets,
+            size: None,
+            children: Vec::new(),
+            image_size: (width, height),
+            pixels: Some(rgba),
+            texture: None,
+            node_id: None,
+            content_node: None,
+        }
+    }
+
+    /// Adds `child` after the existing children.
+    pub fn child(mut self, child: impl IntoViews) -> Self {
+        self.children.extend(child.into_views());
+        self
+    }
+
+    /// Adds every view in `children` after the existing ones.
+    pub fn children<V: IntoViews>(mut self, children: impl IntoIterator<Item = V>) -> Self {
+        self.children.extend(children.into_iter().flat_map(IntoViews::into_views));
+        self
+    }
+
+    /// Space between the box's edges and its children. Defaults to the insets.
+    pub fn padding(mut self, padding: EdgeInsets) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// A fixed size in pixels instead of filling the parent's width.
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        self.size = Some((width, height));
+        self
+    }
 }
 
-impl View for Circle {
-    fn layout(&mut self, ctx: &mut LayoutContext) -> NodeId {
-        let node = ctx.taffy.new_leaf(Style {
-            size: Size { width: length(50.0), height: length(50.0) },
-            ..Default::default()
-        }).unwrap();
+/// The nine regions of a nine-patch image drawn at `geometry`: where each goes and the part
+/// of the image (as `[u0, v0, u1, v1]`) it shows. Empty regions are left out.
+fn nine_patch_slices(geometry: Geometry, (width, height): (u32, u32), insets: EdgeInsets) -> Vec<(Geometry, [f32; 4])> {
+    let (width, height) = (width as f32, height as f32);
+    // Borders wider than the image leave no room for a center
+    let fit = |start: f32, end: f32, size: f32| {
+        let scale = if start + end > size { size / (start + end) } else { 1.0 };
+        (start.max(0.0) * scale, end.max(0.0) * scale)
+    };
+    let (left, right) = fit(insets.left, insets.right, width);
+    let (top, bottom) = fit(insets.top, insets.bottom, height);
+    // ...and boxes smaller than two corners shrink them
+    let (dest_left, dest_right) = fit(left, right, geometry.width);
+    let (dest_top, dest_bottom) = fit(top, bottom, geometry.height);
+
+    let xs = [geometry.x, geometry.x + dest_left, geometry.x + geometry.width - dest_right, geometry.x + geometry.width];
+    let ys = [geometry.y, geometry.y + dest_top, geometry.y + geometry.height - dest_bottom, geometry.y + geometry.height];
+    let us = [0.0, left / width, 1.0 - right / width, 1.0];
+    let vs = [0.0, top / height, 1.0 - bottom / height, 1.0];
+
+    let mut slices = Vec::with_capacity(9);
+    for row in 0..3 {
+        for column in 0..3 {
+            let slice = Geometry { x: xs[column], y: ys[row], width: xs[column + 1] - xs[column], height: ys[row + 1] - ys[row] };
+            if slice.width > 0.0 && slice.height > 0.0 {
+                slices.push((slice, [us[column], vs[row], us[column + 1], vs[row + 1]]));
+            }
+        }
+    }
+    slices
+}
+
+impl View for NinePatch {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<NodeId, LayoutError> {
+        let child_nodes: Vec<NodeId> = self.children.iter_mut()
+            .map(|child| child.layout(ctx))
+            .collect::<Result<_, _>>()?;
+        // Taffy never shrinks a node below its padding, so the padding goes on an inner node
+        // and the image can still shrink below its corners
+        let content = ctx.taffy.new_with_children(
+            Style {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                flex_grow: 1.0,
+                padding: self.padding.to_taffy(),
+                ..Default::default()
+            },
+            &child_nodes,
+        )?;
+        let node = ctx.taffy.new_with_children(
+            Style {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                size: match self.size {
+                    Some((width, height)) => Size { width: length(width), height: length(height) },
+                    None => Size { width: Dimension::Percent(1.0), height: Dimension::Auto },
+                },
+                flex_shrink: 0.0,
+                ..Default::default()
+            },
+            &[content],
+        )?;
+        self.content_node = Some(content);
         self.node_id = Some(node);
-        node
+        Ok(node)
     }
 
     fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
-        let node_layout = layout_ctx.taffy.layout(self.node_id.unwrap()).unwrap();
-        let my_geo = Geometry {
-            x: geometry.x + node_layout.location.x,
-            y: geometry.y + node_layout.location.y,
-            width: node_layout.size.width,
-            height: node_layout.size.height,
-        };
-        ctx.render_queue.push_circle(my_geo, self.color);
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        let Some(content_geo) = layout_ctx.geometry(self.content_node, my_geo) else { return };
+        if let Some(rgba) = self.pixels.take() {
+            let (width, height) = self.image_size;
+            self.texture = ctx.create_texture(width, height, &rgba);
+        }
+        if let Some(texture) = &self.texture {
+            ctx.place_texture_slices(texture, &nine_patch_slices(my_geo, self.image_size, self.insets));
+        }
+        for child in self.children.iter_mut() {
+            child.prepare(ctx, layout_ctx, content_geo);
+        }
     }
 
-    fn render<'rp>(&'rp self, _: &'rp RenderContext, _: &mut wgpu::RenderPass<'rp>, _: Geometry) {}
-    fn handle_event(&mut self, _: &Event, _: &LayoutContext, _: Geometry) {}
+    fn collect_text_areas<'a>(&'a self, layout_ctx: &LayoutContext, geometry: Geometry, areas: &mut Vec<glyphon::TextArea<'a>>) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        let Some(content_geo) = layout_ctx.geometry(self.content_node, my_geo) else { return };
+        for child in &self.children {
+            child.collect_text_areas(layout_ctx, content_geo, areas);
+        }
+    }
+
+    fn render<'rp>(&'rp self, ctx: &'rp RenderContext, render_pass: &mut wgpu::RenderPass<'rp>, geometry: Geometry) {
+        if let Some(texture) = &self.texture {
+            ctx.draw_texture(texture, render_pass);
+        }
+        for child in &self.children {
+            child.render(ctx, render_pass, geometry);
+        }
+    }
+
+    fn handle_event(&mut self, event: &Event, layout_ctx: &LayoutContext, geometry: Geometry) {
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+        let Some(content_geo) = layout_ctx.geometry(self.content_node, my_geo) else { return };
+        for child in self.children.iter_mut() {
+            child.handle_event(event, layout_ctx, content_geo);
+        }
+    }
+
+    fn on_init(&mut self) { self.children.iter_mut().for_each(|child| child.on_init()); }
+    fn on_mount(&mut self) { self.children.iter_mut().for_each(|child| child.on_mount()); }
+    fn on_update(&mut self) { self.children.iter_mut().for_each(|child| child.on_update()); }
+    fn on_unmount(&mut self) { self.children.iter_mut().for_each(|child| child.on_unmount()); }
+    fn on_resize(&mut self, width: f32, height: f32) { self.children.iter_mut().for_each(|child| child.on_resize(width, height)); }
 }
 
-pub struct RoundedRect {
-    pub color: [f32; 4],
-    pub radius: f32,
+const STATS_WIDTH: f32 = 200.0;
+const STATS_HEIGHT: f32 = 112.0;
+const STATS_GRAPH_HEIGHT: f32 = 40.0;
+const STATS_FONT_SIZE: f32 = 12.0;
+/// Frame times kept for the graph, one bar each.
+const STATS_HISTORY: usize = 60;
+/// How often the numbers are re-shaped, so they stay readable and cheap.
+const STATS_TEXT_INTERVAL: Duration = Duration::from_millis(250);
+
+/// A developer panel in the top-right corner of its parent showing FPS, a frame-time graph
+/// and the size of the last frame, read from `FrameStats`.
+///
+/// The panel redraws every frame while visible so the numbers stay live. Its own primitives
+/// are subtracted from the counts it shows.
+pub struct StatsOverlay {
+    visible: bool,
+    toggle_key: Option<Key>,
+    history: VecDeque<f32>,
+    /// Vertices, indices and primitives the panel itself pushed last frame.
+    own: (usize, usize, usize),
+    buffer: Option<glyphon::Buffer>,
+    text_updated: Option<Instant>,
     node_id: Option<NodeId>,
 }
 
-impl RoundedRect {
-    pub fn new(color: [f32; 4], radius: f32) -> Self { Self { color, radius, node_id: None } }
+impl Default for StatsOverlay {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
-impl View for RoundedRect {
-    fn layout(&mut self, ctx: &mut LayoutContext) -> NodeId {
+impl StatsOverlay {
+    pub fn new() -> Self {
+        Self {
+            visible: true,
+            toggle_key: None,
+            history: VecDeque::with_capacity(STATS_HISTORY),
+            own: (0, 0, 0),
+            buffer: None,
+            text_updated: None,
+            node_id: None,
+        }
+    }
+
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Shows or hides the panel whenever `key` is pressed.
+    pub fn toggle_key(mut self, key: Key) -> Self {
+        self.toggle_key = Some(key);
+        self
+    }
+
+    fn summary(&self, ctx: &RenderContext) -> String {
+        let stats = &ctx.stats;
+        let (vertices, indices, primitives) = self.own;
+        let worst = self.history.iter().copied().fold(0.0f32, f32::max);
+        format!(
+            "{:.0} fps  {:.1} ms (max {:.1})\n{} prims  {} text areas\n{} verts  {} indices",
+            stats.fps,
+            stats.frame_time_ms,
+            worst,
+            stats.primitives.saturating_sub(primitives),
+            stats.text_areas.saturating_sub(1),
+            stats.vertices.saturating_sub(vertices),
+            stats.indices.saturating_sub(indices),
+        )
+    }
+}
+
+impl View for StatsOverlay {
+    fn layout(&mut self, ctx: &mut LayoutContext) -> Result<NodeId, LayoutError> {
         let node = ctx.taffy.new_leaf(Style {
-            size: Size { width: length(100.0), height: length(50.0) },
+            position: Position::Absolute,
+            inset: taffy::Rect { left: auto(), right: length(8.0), top: length(8.0), bottom: auto() },
+            size: Size { width: length(STATS_WIDTH), height: length(STATS_HEIGHT) },
             ..Default::default()
-        }).unwrap();
+        })?;
         self.node_id = Some(node);
-        node
+        Ok(node)
     }
 
     fn prepare(&mut self, ctx: &mut RenderContext, layout_ctx: &LayoutContext, geometry: Geometry) {
-        let node_layout = layout_ctx.taffy.layout(self.node_id.unwrap()).unwrap();
-        let my_geo = Geometry {
-            x: geometry.x + node_layout.location.x,
-            y: geometry.y + node_layout.location.y,
-            width: node_layout.size.width,
-            height: node_layout.size.height,
-        };
-        ctx.render_queue.push_rounded_rect(my_geo, self.color, self.radius);
+        if !self.visible {
+            return;
+        }
+        let Some(my_geo) = layout_ctx.geometry(self.node_id, geometry) else { return };
+
+        if self.history.len() == STATS_HISTORY {
+            self.history.pop_front();
+        }
+        self.history.push_back(ctx.stats.frame_time_ms);
+
+        if self.text_updated.is_none_or(|updated| updated.elapsed() >= STATS_TEXT_INTERVAL) {
+            let text = self.summary(ctx);
+            let buffer = self.buffer.get_or_insert_with(|| {
+                glyphon::Buffer::new(&mut ctx.font_system, glyphon::Metrics::new(STATS_FONT_SIZE, STATS_FONT_SIZE * 1.4))
+            });
+            buffer.set_size(&mut ctx.font_system, Some(STATS_WIDTH - 16.0), None);
+            buffer.set_text(&mut ctx.font_system, &text, &glyphon::Attrs::new().family(glyphon::Family::Monospace), glyphon::Shaping::Basic);
+            buffer.shape_until_scroll(&mut ctx.font_system, false);
+            self.text_updated = Some(Instant::now());
+        }
+
+        let queue = &ctx.render_queue;
+        let before = (queue.vertices.len(), queue.indices.len(), queue.len());
+        let previous_z = ctx.render_queue.set_z_index(i32::MAX - 1);
+        ctx.render_queue.push_rounded_rect(my_geo, [0.0, 0.0, 0.0, 0.75], 6.0);
+
+        // One bar per frame, scaled so the graph's top is 33ms (30 fps)
+        let graph = Geometry { x: my_geo.x + 8.0, y: my_geo.y + my_geo.height - 8.0 - STATS_GRAPH_HEIGHT, width: my_geo.width - 16.0, height: STATS_GRAPH_HEIGHT };
+        let bar_width = graph.width / STATS_HISTORY as f32;
+        for (i, &ms) in self.history.iter().enumerate() {
+            let height = (ms / 33.3).min(1.0) * graph.height;
+            let color = if ms <= 17.0 {
+                [0.3, 0.8, 0.4, 0.9]
+            } else if ms <= 34.0 {
+                [0.95, 0.75, 0.2, 0.9]
+            } else {
+                [0.9, 0.3, 0.3, 0.9]
+            };
+            let bar = Geometry { x: graph.x + i as f32 * bar_width, y: graph.y + graph.height - height, width: (bar_width - 1.0).max(1.0), height };
+            ctx.render_queue.push_rect(bar, color);
+        }
+        // 60 fps budget line
+        ctx.render_queue.push_rect(Geometry { x: graph.x, y: graph.y + graph.height * 0.5, width: graph.width, height: 1.0 }, [1.0, 1.0, 1.0, 0.3]);
+        ctx.render_queue.set_z_index(previous_z);
+
+        let queue = &ctx.render_queue;
+        self.own = (queue.vertices.len() - before.0, queue.indices.len() - before.1, queue.len() - before.2);
+        ctx.request_redraw();
+    }
+
+    fn collect_text_areas<'a>(&'a self, layout_ctx: &LayoutContext, geometry: Geometry, areas: &mut Vec<glyphon::TextArea<'a>>) {
+        if !self.visible {
+            return;
+        }
+        let (Some(my_geo), Some(buffer)) = (layout_ctx.geometry(self.node_id, geometry), &self.buffer) else { return };
+        areas.push(glyphon::TextArea {
+            buffer,
+            left: my_geo.x + 8.0,
+            top: my_geo.y + 6.0,
+            scale: 1.0,
+            bounds: glyphon::TextBounds {
+                left: my_geo.x as i32,
+                top: my_geo.y as i32,
+                right: (my_geo.x + my_geo.width) as i32,
+                bottom: (my_geo.y + my_geo.height) as i32,
+            },
+            default_color: glyphon::Color::rgb(220, 220, 220),
+            custom_glyphs: &[],
+        });
     }
 
     fn render<'rp>(&'rp self, _: &'rp RenderContext, _: &mut wgpu::RenderPass<'rp>, _: Geometry) {}
-    fn handle_event(&mut self, _: &Event, _: &LayoutContext, _: Geometry) {}
+
+    fn handle_event(&mut self, event: &Event, _: &LayoutContext, _: Geometry) {
+        if let Event::KeyDown { key, .. } = event
+            && Some(*key) == self.toggle_key
+        {
+            self.visible = !self.visible;
+            self.history.clear();
+            self.text_updated = None;
+        }
+    }
+}
+
+const SNACKBAR_ANIMATION: Duration = Duration::from_millis(200);
+const SNACKBAR_HEIGHT: f32 = 48.0;
+const SNACKBAR_MAX_WIDTH: f32 = 560.0;
+const SNACKBAR_MARGIN: f32 = 16.0;
+const SNACKBAR_FONT_SIZE: f32 = 14.0;
+
+thread_local! {
+    static SNACKBAR_QUEUE: RefCell<VecDeque<Snackbar>> = const { RefCell::new(VecDeque::new()) };
+}
+
+/// A short message shown at the bottom of the window, with an optional action.
+///
+/// Snackbars are queued and shown one at a time: each slides up, stays for its duration
+/// (or until its action is clicked), then fades out before the next one appears.
+pub struct Snackbar {
+    pub message: String,
+    pub duration: Duration,
+    action: Option<(String, Box<dyn FnMut()>)>,
+}
+
+impl Snackbar {
+    pub fn new(message: impl Into<String>, duration: Duration) -> Self {
+        Self { message: message.into(), duration, action: None }
+    }
+
+    /// Adds a button (e.g. "Undo") that runs `f` and dismisses the snackbar.
+    pub fn action(mut self, label: impl Into<String>, f: impl FnMut() + 'static) -> Self {
+        self.action = Some((label.into(), Box::new(f)));
+        self
+    }
+
+    /// Queues the snackbar behind any that are already showing or waiting.
+    pub fn show(self) {
+        SNACKBAR_QUEUE.with(|queue| queue.borrow_mut().push_back(self));
+    }
+}
+
+/// Shows `message` at the bottom of the window for `duration`, e.g. from a click handler.
+pub fn show_snackbar(message: impl Into<String>, duration: Duration) {
+    Snackbar::new(message, duration).show();
+}
+
+struct ActiveSnackbar {
+    snackbar: Snackbar,
+    shown: Instant,
+    dismissed: Option<Instant>,
+}
+
+/// Draws queued snackbars above the view tree. Owned and driven by `App`.
+#[derive(Default)]
+pub(crate) struct SnackbarOverlay {
+    active: Option<ActiveSnackbar>,
+    message_buffer: Option<glyphon::Buffer>,
+    action_buffer: Option<glyphon::Buffer>,
+    geometry: Geometry,
+    action_geometry: Option<Geometry>,
+    opacity: f32,
+}
+
+impl SnackbarOverlay {
+    pub(crate) fn prepare(&mut self, ctx: &mut RenderContext, window: Geometry) {
+        if let Some(active) = &mut self.active {
+            match active.dismissed {
+                Some(dismissed) if dismissed.elapsed() >= SNACKBAR_ANIMATION => self.active = None,
+                None if active.shown.elapsed() >= SNACKBAR_ANIMATION + active.snackbar.duration => {
+                    active.dismissed = Some(Instant::now());
+                }
+                _ => {}
+            }
+        }
+        if self.active.is_none() {
+            let Some(snackbar) = SNACKBAR_QUEUE.with(|queue| queue.borrow_mut().pop_front()) else {
+                return;
+            };
+            let metrics = glyphon::Metrics::new(SNACKBAR_FONT_SIZE, SNACKBAR_FONT_SIZE * 1.5);
+            let attrs = glyphon::Attrs::new().family(glyphon::Family::SansSerif);
+            let mut message_buffer = glyphon::Buffer::new(&mut ctx.font_system, metrics);
+            message_buffer.set_wrap(&mut ctx.font_system, glyphon::Wrap::None);
+            message_buffer.set_text(&mut ctx.font_system, &snackbar.message, &attrs, glyphon::Shaping::Advanced);
+            self.message_buffer = Some(message_buffer);
+            self.action_buffer = snackbar.action.as_ref().map(|(label, _)| {
+                let mut buffer = glyphon::Buffer::new(&mut ctx.font_system, metrics);
+                buffer.set_text(&mut ctx.font_system, &label.to_uppercase(), &attrs.weight(glyphon::Weight::MEDIUM), glyphon::Shaping::Advanced);
+                buffer.shape_until_scroll(&mut ctx.font_system, false);
+                buffer
+            });
+            self.active = Some(ActiveSnackbar { snackbar, shown: Instant::now(), dismissed: None });
+        }
+        let Some(active) = &self.active else { return };
+
+        // Slide up on entry, fade out on exit
+        let appear = (active.shown.elapsed().as_secs_f32() / SNACKBAR_ANIMATION.as_secs_f32()).min(1.0);
+        let slide = Easing::CubicOut.apply(appear);
+        self.opacity = match active.dismissed {
+            Some(dismissed) => 1.0 - (dismissed.elapsed().as_secs_f32() / SNACKBAR_ANIMATION.as_secs_f32()).min(1.0),
+            None => 1.0,
+        };
+
+        let width = (window.width - 2.0 * SNACKBAR_MARGIN).clamp(0.0, SNACKBAR_MAX_WIDTH);
+        let rest_y = window.y + window.height - SNACKBAR_MARGIN - SNACKBAR_HEIGHT;
+        self.geometry = Geometry {
+            x: window.x + (window.width - width) / 2.0,
+            y: rest_y + (1.0 - slide) * (SNACKBAR_HEIGHT + SNACKBAR_MARGIN),
+            width,
+            height: SNACKBAR_HEIGHT,
+        };
+
+        let line_height = SNACKBAR_FONT_SIZE * 1.5;
+        self.action_geometry = self.action_buffer.as_ref().map(|buffer| {
+            let text_width = buffer.layout_runs().map(|run| run.line_w).fold(0.0f32, f32::max);
+            let width = text_width + 16.0;
+            Geometry {
+                x: self.geometry.x + self.geometry.width - 8.0 - width,
+                y: self.geometry.y + (SNACKBAR_HEIGHT - 36.0) / 2.0,
+                width,
+                height: 36.0,
+            }
+        });
+        let message_width = match self.action_geometry {
+            Some(action) => action.x - self.geometry.x - 24.0,
+            None => self.geometry.width - 32.0,
+        };
+        if let Some(buffer) = &mut self.message_buffer {
+            buffer.set_size(&mut ctx.font_system, Some(message_width.max(0.0)), Some(line_height));
+            buffer.shape_until_scroll(&mut ctx.font_system, false);
+        }
+
+        ctx.render_queue.push_rounded_rect(self.geometry, [0.196, 0.196, 0.216, self.opacity], 4.0);
+        // Animate in and out, but sit idle while the snackbar is just showing
+        if appear < 1.0 || active.dismissed.is_some() {
+            ctx.request_redraw();
+        } else {
+            ctx.request_redraw_at(active.shown + SNACKBAR_ANIMATION + active.snackbar.duration);
+        }
+    }
+
+    pub(crate) fn collect_text_areas<'a>(&'a self, areas: &mut Vec<glyphon::TextArea<'a>>) {
+        if self.active.is_none() {
+            return;
+        }
+        let alpha = (self.opacity * 255.0) as u8;
+        let top = self.geometry.y + (SNACKBAR_HEIGHT - SNACKBAR_FONT_SIZE * 1.5) / 2.0;
+        let bounds = glyphon::TextBounds {
+            left: self.geometry.x as i32,
+            top: self.geometry.y as i32,
+            right: (self.geometry.x + self.geometry.width) as i32,
+            bottom: (self.geometry.y + self.geometry.height) as i32,
+        };
+        if let Some(buffer) = &self.message_buffer {
+            areas.push(glyphon::TextArea {
+                buffer,
+                left: self.geometry.x + 16.0,
+                top,
+                scale: 1.0,
+                bounds,
+                default_color: glyphon::Color::rgba(255, 255, 255, alpha),
+                custom_glyphs: &[],
+            });
+        }
+        if let (Some(buffer), Some(action)) = (&self.action_buffer, self.action_geometry) {
+            areas.push(glyphon::TextArea {
+                buffer,
+                left: action.x + 8.0,
+                top,
+                scale: 1.0,
+                bounds,
+                default_color: glyphon::Color::rgba(159, 168, 218, alpha),
+                custom_glyphs: &[],
+            });
+        }
+    }
+
+    /// Handles clicks on the snackbar. Returns `true` if the event landed on it, so it
+    /// shouldn't reach the views underneath.
+    pub(crate) fn handle_event(&mut self, event: &Event) -> bool {
+        let Some(active) = &mut self.active else { return false };
+        let (x, y) = match event {
+            Event::MouseDown { x, y } | Event::MouseUp { x, y } | Event::MouseClick { x, y } => (*x, *y),
+            _ => return false,
+        };
+        if !self.geometry.contains(x, y) {
+            return false;
+        }
+        if let Event::MouseClick { .. } = event
+            && active.dismissed.is_none()
+            && self.action_geometry.is_some_and(|action| action.contains(x, y))
+            && let Some((_, f)) = &mut active.snackbar.action
+        {
+            f();
+            active.dismissed = Some(Instant::now());
+        }
+        true
+    }
 }
 
 #[allow(non_snake_case)] pub fn Text(text: impl Into<String>) -> Text { Text::new(text) }
+#[allow(non_snake_case)] pub fn Chip(label: impl Into<String>) -> Chip { Chip::new(label) }
 #[allow(non_snake_case)] pub fn Button(text: impl Into<String>, on_click: impl FnMut() + 'static) -> Button { Button::new(text, on_click) }
+#[allow(non_snake_case)] pub fn TextInput(value: impl Into<String>, on_change: impl FnMut(&str) + 'static) -> TextInput { TextInput::new(value, on_change) }
+#[allow(non_snake_case)] pub fn Responsive(mobile: impl View + 'static) -> Responsive { Responsive::new(mobile) }
+#[allow(non_snake_case)] pub fn AspectRatio(ratio: f32) -> AspectRatio { AspectRatio::new(ratio) }
+#[allow(non_snake_case)] pub fn Viewport(child: impl View + 'static) -> Viewport { Viewport::new(child) }
+#[allow(non_snake_case)] pub fn Path(commands: Vec<PathCommand>, color: [f32; 4]) -> Path { Path::new(commands, color) }
+#[allow(non_snake_case)] pub fn Image(width: u32, height: u32, rgba: Vec<u8>) -> Image { Image::new(width, height, rgba) }
+#[allow(non_snake_case)] pub fn Avatar(initials: impl Into<String>) -> Avatar { Avatar::initials(initials) }
+#[allow(non_snake_case)] pub fn NinePatch(width: u32, height: u32, rgba: Vec<u8>, insets: EdgeInsets) -> NinePatch { NinePatch::new(width, height, rgba, insets) }
 #[allow(non_snake_case)] pub fn Rect(color: [f32; 4]) -> Rect { Rect::new(color) }
 #[allow(non_snake_case)] pub fn Circle(color: [f32; 4]) -> Circle { Circle::new(color) }
+#[allow(non_snake_case)] pub fn CircularProgress(value: f32) -> CircularProgress { CircularProgress::new(value) }
 #[allow(non_snake_case)] pub fn RoundedRect(color: [f32; 4], radius: f32) -> RoundedRect { RoundedRect::new(color, radius) }
+#[allow(non_snake_case)] pub fn DropZone(child: impl View + 'static, on_drop: impl FnMut(Vec<PathBuf>) + 'static) -> DropZone { DropZone::new(child, on_drop) }
+#[allow(non_snake_case)] pub fn AppBar(title: impl Into<String>) -> AppBar { AppBar::new(title) }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestHarness;
+
+    /// Lets a test reach into a widget after handing it to a `TestHarness`.
+    struct Shared<V>(Rc<RefCell<V>>);
+
+    impl<V: View> View for Shared<V> {
+        fn layout(&mut self, ctx: &mut LayoutContext) -> Result<NodeId, LayoutError> {
+            self.0.borrow_mut().layout(ctx)
+        }
+
+        fn render<'rp>(&'rp self, _ctx: &'rp RenderContext, _render_pass: &mut wgpu::RenderPass<'rp>, _geometry: Geometry) {
+        }
+
+        fn handle_event(&mut self, event: &Event, layout_ctx: &LayoutContext, geometry: Geometry) {
+            self.0.borrow_mut().handle_event(event, layout_ctx, geometry);
+        }
+    }
+
+    #[test]
+    fn masking_and_caret_steps_follow_grapheme_clusters() {
+        // "e" with a combining accent, then a thumbs up with a skin tone modifier
+        let value = "e\u{301}\u{1F44D}\u{1F3FD}";
+        let input = TextInput::new(value, |_| {}).secure(true);
+        assert_eq!(input.display_index(value, value.len()), 2 * MASK_CHAR.len_utf8());
+
+        let accent_end = "e\u{301}".len();
+        assert_eq!(next_boundary(value, 0), accent_end);
+        assert_eq!(next_boundary(value, accent_end), value.len());
+        assert_eq!(prev_boundary(value, value.len()), accent_end);
+    }
+
+    #[test]
+    fn line_height_sets_the_measured_line_box() {
+        let mut font_system = glyphon::FontSystem::new();
+        let mut measure = |text: Text| {
+            let mut text = text.size(16.0);
+            let mut ctx = LayoutContext::new();
+            let node = text.layout(&mut ctx).unwrap();
+            ctx.compute_layout(node, Size::MAX_CONTENT, &mut font_system).unwrap();
+            (ctx.taffy.layout(node).unwrap().size.height, ctx.baseline(node).unwrap())
+        };
+
+        let (default_height, default_baseline) = measure(Text::new("One\nTwo"));
+        let (height, baseline) = measure(Text::new("One\nTwo").line_height(40.0));
+        assert_eq!(default_height, 2.0 * 24.0);
+        assert_eq!(height, 2.0 * 40.0);
+        // The glyphs stay centered in the taller line box
+        assert!((baseline - default_baseline - (40.0 - 24.0) / 2.0).abs() < 0.01);
+
+        let (height, _) = measure(Text::new("One\nTwo").line_height_factor(2.0));
+        assert_eq!(height, 2.0 * 32.0);
+    }
+
+    #[test]
+    fn index_at_finds_the_caret_under_a_click() {
+        let text = Rc::new(RefCell::new(Text::new("Hello world").size(16.0).debug_name("greeting")));
+        let harness = TestHarness::new(Box::new(Column::default().child(Shared(text.clone()))), 400.0, 300.0).unwrap();
+        let greeting = harness.find_named("greeting").unwrap();
+        // The harness doesn't draw, so shape the text the way `prepare` would
+        text.borrow_mut().shape(&mut glyphon::FontSystem::new(), greeting, &mut FrameStats::default());
+        let text = text.borrow();
+
+        // Where the "w" was shaped
+        let (w_x, w_width) = text.buffer.as_ref().unwrap().layout_runs().next().unwrap()
+            .glyphs.iter().find(|glyph| glyph.start == "Hello ".len()).map(|glyph| (glyph.x, glyph.w)).unwrap();
+        let y = greeting.y + greeting.height / 2.0;
+        let index_at = |x: f32| {
+            let (x, y) = greeting.to_local(x, y);
+            text.index_at(x, y)
+        };
+        assert_eq!(index_at(greeting.x + w_x + 1.0), Some("Hello ".len()));
+        assert_eq!(index_at(greeting.x + w_x + w_width - 1.0), Some("Hello w".len()));
+        assert_eq!(index_at(greeting.x - 10.0), Some(0));
+        assert_eq!(index_at(greeting.x + greeting.width + 10.0), Some("Hello world".len()));
+    }
+
+    #[test]
+    fn updating_text_reuses_its_font_attributes() {
+        let mut text = Text::new("0");
+        let geometry = Geometry { x: 0.0, y: 0.0, width: 400.0, height: 24.0 };
+        let mut font_system = glyphon::FontSystem::new();
+        let mut stats = FrameStats::default();
+        for n in 1..=1000 {
+            text.text = n.to_string();
+            text.shape(&mut font_system, geometry, &mut stats);
+        }
+        assert_eq!(stats.shaped_texts, 1000);
+        assert_eq!(stats.built_attrs, 1);
+
+        text.letter_spacing = 0.05;
+        text.shape(&mut font_system, geometry, &mut stats);
+        assert_eq!(stats.built_attrs, 2);
+    }
+
+    #[test]
+    fn static_text_is_only_shaped_on_the_first_frame() {
+        let texts: Vec<_> = (0..500)
+            .map(|i| Rc::new(RefCell::new(Text::new(format!("Label {i}")).size(10.0).debug_name(format!("label {i}")))))
+            .collect();
+        let column = Column::default().children(texts.iter().map(|text| Shared(text.clone())));
+        let harness = TestHarness::new(Box::new(column), 1600.0, 8000.0).unwrap();
+        let geometries: Vec<_> = (0..texts.len()).map(|i| harness.find_named(&format!("label {i}")).unwrap()).collect();
+
+        let mut font_system = glyphon::FontSystem::new();
+        let mut shaped_per_frame = Vec::new();
+        for _ in 0..5 {
+            // The harness doesn't draw, so shape every text the way each frame's `prepare` would
+            let mut stats = FrameStats::default();
+            for (text, geometry) in texts.iter().zip(&geometries) {
+                text.borrow_mut().shape(&mut font_system, *geometry, &mut stats);
+            }
+            shaped_per_frame.push(stats.shaped_texts);
+        }
+        assert_eq!(shaped_per_frame, [500, 0, 0, 0, 0]);
+    }
+}